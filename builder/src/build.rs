@@ -28,6 +28,8 @@ fn run_command(command: &mut std::process::Command) -> Result<(), BuildError> {
     let output = command.output()?;
 
     if output.status.success() {
+        std::io::stderr().write_all(&output.stderr)?;
+
         Ok(())
     } else {
         std::io::stderr().write_all(&output.stdout)?;
@@ -0,0 +1,73 @@
+use super::built_in_function::BuiltInFunction;
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuiltInCall {
+    function: BuiltInFunction,
+    arguments: Vec<Expression>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl BuiltInCall {
+    pub fn new(
+        function: BuiltInFunction,
+        arguments: Vec<Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            function,
+            arguments,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn function(&self) -> BuiltInFunction {
+        self.function
+    }
+
+    pub fn arguments(&self) -> &[Expression] {
+        &self.arguments
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.function,
+            self.arguments
+                .iter()
+                .map(|argument| argument.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.function,
+            self.arguments
+                .iter()
+                .map(|argument| argument.convert_definitions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.function,
+            self.arguments
+                .iter()
+                .map(|argument| argument.convert_expressions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
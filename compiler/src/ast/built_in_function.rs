@@ -0,0 +1,32 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BuiltInFunction {
+    Absolute,
+    Ceiling,
+    Cosine,
+    Exponential,
+    Floor,
+    Logarithm,
+    PopCount,
+    Sine,
+    SquareRoot,
+    StringLength,
+    StringSlice,
+}
+
+impl From<BuiltInFunction> for core::ast::BuiltInFunction {
+    fn from(function: BuiltInFunction) -> Self {
+        match function {
+            BuiltInFunction::Absolute => core::ast::BuiltInFunction::Absolute,
+            BuiltInFunction::Ceiling => core::ast::BuiltInFunction::Ceiling,
+            BuiltInFunction::Cosine => core::ast::BuiltInFunction::Cosine,
+            BuiltInFunction::Exponential => core::ast::BuiltInFunction::Exponential,
+            BuiltInFunction::Floor => core::ast::BuiltInFunction::Floor,
+            BuiltInFunction::Logarithm => core::ast::BuiltInFunction::Logarithm,
+            BuiltInFunction::PopCount => core::ast::BuiltInFunction::PopCount,
+            BuiltInFunction::Sine => core::ast::BuiltInFunction::Sine,
+            BuiltInFunction::SquareRoot => core::ast::BuiltInFunction::SquareRoot,
+            BuiltInFunction::StringLength => core::ast::BuiltInFunction::StringLength,
+            BuiltInFunction::StringSlice => core::ast::BuiltInFunction::StringSlice,
+        }
+    }
+}
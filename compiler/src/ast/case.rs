@@ -0,0 +1,98 @@
+use super::alternative::Alternative;
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+    type_: Type,
+    argument: Rc<Expression>,
+    alternatives: Vec<Alternative>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Case {
+    pub fn new(
+        argument: impl Into<Expression>,
+        alternatives: Vec<Alternative>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        let source_information = source_information.into();
+
+        Self::with_type(
+            types::Variable::new(source_information.clone()),
+            argument,
+            alternatives,
+            source_information,
+        )
+    }
+
+    pub fn with_type(
+        type_: impl Into<Type>,
+        argument: impl Into<Expression>,
+        alternatives: Vec<Alternative>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            argument: Rc::new(argument.into()),
+            alternatives,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn argument(&self) -> &Expression {
+        &self.argument
+    }
+
+    pub fn alternatives(&self) -> &[Alternative] {
+        &self.alternatives
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::with_type(
+            self.type_.substitute_variables(substitutions),
+            self.argument.substitute_type_variables(substitutions),
+            self.alternatives
+                .iter()
+                .map(|alternative| alternative.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::with_type(
+            self.type_.clone(),
+            self.argument.convert_definitions(convert),
+            self.alternatives
+                .iter()
+                .map(|alternative| alternative.convert_definitions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::with_type(
+            self.type_.clone(),
+            self.argument.convert_expressions(convert),
+            self.alternatives
+                .iter()
+                .map(|alternative| alternative.convert_expressions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
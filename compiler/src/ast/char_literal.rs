@@ -0,0 +1,25 @@
+use crate::debug::SourceInformation;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharLiteral {
+    value: char,
+    source_information: Rc<SourceInformation>,
+}
+
+impl CharLiteral {
+    pub fn new(value: char, source_information: impl Into<Rc<SourceInformation>>) -> Self {
+        Self {
+            value,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn value(&self) -> char {
+        self.value
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+}
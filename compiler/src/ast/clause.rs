@@ -0,0 +1,100 @@
+use super::definition::Definition;
+use super::expression::Expression;
+use super::guard::Guard;
+use super::pattern::Pattern;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clause {
+    patterns: Vec<Pattern>,
+    guards: Vec<Guard>,
+    definitions: Vec<Definition>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Clause {
+    pub fn new(
+        patterns: Vec<Pattern>,
+        guards: Vec<Guard>,
+        definitions: Vec<Definition>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            patterns,
+            guards,
+            definitions,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    pub fn guards(&self) -> &[Guard] {
+        &self.guards
+    }
+
+    pub fn definitions(&self) -> &[Definition] {
+        &self.definitions
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn is_total(&self) -> bool {
+        self.guards.iter().any(Guard::is_otherwise)
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.patterns
+                .iter()
+                .map(|pattern| pattern.substitute_type_variables(substitutions))
+                .collect(),
+            self.guards
+                .iter()
+                .map(|guard| guard.substitute_type_variables(substitutions))
+                .collect(),
+            self.definitions
+                .iter()
+                .map(|definition| definition.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.patterns.clone(),
+            self.guards
+                .iter()
+                .map(|guard| guard.convert_definitions(convert))
+                .collect(),
+            self.definitions
+                .iter()
+                .map(|definition| definition.convert_definitions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.patterns.clone(),
+            self.guards
+                .iter()
+                .map(|guard| guard.convert_expressions(convert))
+                .collect(),
+            self.definitions
+                .iter()
+                .map(|definition| definition.convert_expressions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub const NIL_CONSTRUCTOR_NAME: &str = "[]";
+pub const CONS_CONSTRUCTOR_NAME: &str = "::";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constructor {
+    name: String,
+    type_: Option<Type>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Constructor {
+    pub fn new(
+        name: impl Into<String>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            type_: None,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn with_type(
+        name: impl Into<String>,
+        type_: impl Into<Type>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: Some(type_.into()),
+            ..Self::new(name, source_information)
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> Option<&Type> {
+        self.type_.as_ref()
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self {
+            type_: self
+                .type_
+                .as_ref()
+                .map(|type_| type_.substitute_variables(substitutions)),
+            ..self.clone()
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorDefinition {
+    name: String,
+    elements: Vec<Type>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl ConstructorDefinition {
+    pub fn new(
+        name: impl Into<String>,
+        elements: Vec<Type>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn elements(&self) -> &[Type] {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+}
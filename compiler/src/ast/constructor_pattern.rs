@@ -0,0 +1,49 @@
+use super::pattern::Pattern;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorPattern {
+    name: String,
+    arguments: Vec<Pattern>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl ConstructorPattern {
+    pub fn new(
+        name: impl Into<String>,
+        arguments: Vec<Pattern>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            arguments,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn arguments(&self) -> &[Pattern] {
+        &self.arguments
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.name.clone(),
+            self.arguments
+                .iter()
+                .map(|pattern| pattern.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conversion {
+    type_: Type,
+    expression: Rc<Expression>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Conversion {
+    pub fn new(
+        type_: impl Into<Type>,
+        expression: impl Into<Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            expression: Rc::new(expression.into()),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.type_.substitute_variables(substitutions),
+            self.expression.substitute_type_variables(substitutions),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.type_.clone(),
+            self.expression.convert_definitions(convert),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.type_.clone(),
+            self.expression.convert_expressions(convert),
+            self.source_information.clone(),
+        )
+    }
+}
@@ -1,8 +1,10 @@
 use super::expression::Expression;
 use super::function_definition::*;
 use super::value_definition::*;
+use crate::debug::SourceInformation;
 use crate::types::Type;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Definition {
@@ -11,6 +13,29 @@ pub enum Definition {
 }
 
 impl Definition {
+    pub fn name(&self) -> &str {
+        match self {
+            Definition::FunctionDefinition(function_definition) => function_definition.name(),
+            Definition::ValueDefinition(value_definition) => value_definition.name(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        match self {
+            Definition::FunctionDefinition(function_definition) => function_definition.type_(),
+            Definition::ValueDefinition(value_definition) => value_definition.type_(),
+        }
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        match self {
+            Definition::FunctionDefinition(function_definition) => {
+                function_definition.source_information()
+            }
+            Definition::ValueDefinition(value_definition) => value_definition.source_information(),
+        }
+    }
+
     pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
         match self {
             Definition::FunctionDefinition(function_definition) => function_definition
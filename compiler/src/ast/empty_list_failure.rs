@@ -0,0 +1,29 @@
+use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmptyListFailure {
+    type_: Type,
+}
+
+impl EmptyListFailure {
+    pub fn new(source_information: impl Into<Rc<SourceInformation>>) -> Self {
+        Self::with_type(types::Variable::new(source_information))
+    }
+
+    pub fn with_type(type_: impl Into<Type>) -> Self {
+        Self {
+            type_: type_.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::with_type(self.type_.substitute_variables(substitutions))
+    }
+}
@@ -1,8 +1,25 @@
 use super::application::Application;
+use super::boolean::Boolean;
+use super::built_in_call::BuiltInCall;
+use super::case::Case;
+use super::char_literal::CharLiteral;
+use super::constructor::Constructor;
+use super::conversion::Conversion;
 use super::definition::Definition;
+use super::empty_list_failure::EmptyListFailure;
+use super::if_::If;
+use super::lambda::Lambda;
 use super::let_::Let;
+use super::match_::Match;
+use super::match_failure::MatchFailure;
+use super::negation::Negation;
 use super::number::Number;
 use super::operation::Operation;
+use super::record::Record;
+use super::record_element::RecordElement;
+use super::record_update::RecordUpdate;
+use super::string_literal::StringLiteral;
+use super::tuple::Tuple;
 use super::variable::Variable;
 use crate::types::Type;
 use std::collections::HashMap;
@@ -10,9 +27,26 @@ use std::collections::HashMap;
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
     Application(Application),
+    Boolean(Boolean),
+    BuiltInCall(BuiltInCall),
+    Case(Case),
+    CharLiteral(CharLiteral),
+    Constructor(Constructor),
+    Conversion(Conversion),
+    EmptyListFailure(EmptyListFailure),
+    If(If),
+    Lambda(Lambda),
     Let(Let),
+    Match(Match),
+    MatchFailure(MatchFailure),
+    Negation(Negation),
     Number(Number),
     Operation(Operation),
+    Record(Record),
+    RecordElement(RecordElement),
+    RecordUpdate(RecordUpdate),
+    StringLiteral(StringLiteral),
+    Tuple(Tuple),
     Variable(Variable),
 }
 
@@ -22,19 +56,70 @@ impl Expression {
             Expression::Application(application) => {
                 application.substitute_type_variables(substitutions).into()
             }
+            Expression::Boolean(boolean) => Expression::Boolean(boolean.clone()),
+            Expression::BuiltInCall(built_in_call) => built_in_call
+                .substitute_type_variables(substitutions)
+                .into(),
+            Expression::Case(case) => case.substitute_type_variables(substitutions).into(),
+            Expression::CharLiteral(char_literal) => Expression::CharLiteral(char_literal.clone()),
+            Expression::Constructor(constructor) => {
+                constructor.substitute_type_variables(substitutions).into()
+            }
+            Expression::Conversion(conversion) => {
+                conversion.substitute_type_variables(substitutions).into()
+            }
+            Expression::EmptyListFailure(empty_list_failure) => empty_list_failure
+                .substitute_type_variables(substitutions)
+                .into(),
+            Expression::If(if_) => if_.substitute_type_variables(substitutions).into(),
+            Expression::Lambda(lambda) => lambda.substitute_type_variables(substitutions).into(),
             Expression::Let(let_) => let_.substitute_type_variables(substitutions).into(),
-            Expression::Number(number) => Expression::Number(number.clone()),
+            Expression::Match(match_) => match_.substitute_type_variables(substitutions).into(),
+            Expression::MatchFailure(match_failure) => match_failure
+                .substitute_type_variables(substitutions)
+                .into(),
+            Expression::Negation(negation) => {
+                negation.substitute_type_variables(substitutions).into()
+            }
+            Expression::Number(number) => number.substitute_type_variables(substitutions).into(),
             Expression::Operation(operation) => {
                 operation.substitute_type_variables(substitutions).into()
             }
-            Expression::Variable(variable) => Expression::Variable(variable.clone()),
+            Expression::Record(record) => record.substitute_type_variables(substitutions).into(),
+            Expression::RecordElement(record_element) => record_element
+                .substitute_type_variables(substitutions)
+                .into(),
+            Expression::RecordUpdate(record_update) => record_update
+                .substitute_type_variables(substitutions)
+                .into(),
+            Expression::StringLiteral(string_literal) => {
+                Expression::StringLiteral(string_literal.clone())
+            }
+            Expression::Tuple(tuple) => tuple.substitute_type_variables(substitutions).into(),
+            Expression::Variable(variable) => {
+                variable.substitute_type_variables(substitutions).into()
+            }
         }
     }
 
     pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
         match self {
             Self::Application(application) => application.convert_definitions(convert).into(),
+            Self::BuiltInCall(built_in_call) => built_in_call.convert_definitions(convert).into(),
+            Self::Case(case) => case.convert_definitions(convert).into(),
+            Self::Conversion(conversion) => conversion.convert_definitions(convert).into(),
+            Self::If(if_) => if_.convert_definitions(convert).into(),
+            Self::Lambda(lambda) => lambda.convert_definitions(convert).into(),
             Self::Let(let_) => let_.convert_definitions(convert).into(),
+            Self::Match(match_) => match_.convert_definitions(convert).into(),
+            Self::Negation(negation) => negation.convert_definitions(convert).into(),
+            Self::Operation(operation) => operation.convert_definitions(convert).into(),
+            Self::Record(record) => record.convert_definitions(convert).into(),
+            Self::RecordElement(record_element) => {
+                record_element.convert_definitions(convert).into()
+            }
+            Self::RecordUpdate(record_update) => record_update.convert_definitions(convert).into(),
+            Self::Tuple(tuple) => tuple.convert_definitions(convert).into(),
             _ => self.clone(),
         }
     }
@@ -42,7 +127,21 @@ impl Expression {
     pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
         let expression = match self {
             Self::Application(application) => application.convert_expressions(convert).into(),
+            Self::BuiltInCall(built_in_call) => built_in_call.convert_expressions(convert).into(),
+            Self::Case(case) => case.convert_expressions(convert).into(),
+            Self::Conversion(conversion) => conversion.convert_expressions(convert).into(),
+            Self::If(if_) => if_.convert_expressions(convert).into(),
+            Self::Lambda(lambda) => lambda.convert_expressions(convert).into(),
             Self::Let(let_) => let_.convert_expressions(convert).into(),
+            Self::Match(match_) => match_.convert_expressions(convert).into(),
+            Self::Negation(negation) => negation.convert_expressions(convert).into(),
+            Self::Operation(operation) => operation.convert_expressions(convert).into(),
+            Self::Record(record) => record.convert_expressions(convert).into(),
+            Self::RecordElement(record_element) => {
+                record_element.convert_expressions(convert).into()
+            }
+            Self::RecordUpdate(record_update) => record_update.convert_expressions(convert).into(),
+            Self::Tuple(tuple) => tuple.convert_expressions(convert).into(),
             _ => self.clone(),
         };
 
@@ -56,12 +155,84 @@ impl From<Application> for Expression {
     }
 }
 
+impl From<Boolean> for Expression {
+    fn from(boolean: Boolean) -> Expression {
+        Expression::Boolean(boolean)
+    }
+}
+
+impl From<Case> for Expression {
+    fn from(case: Case) -> Expression {
+        Expression::Case(case)
+    }
+}
+
+impl From<CharLiteral> for Expression {
+    fn from(char_literal: CharLiteral) -> Expression {
+        Expression::CharLiteral(char_literal)
+    }
+}
+
+impl From<Constructor> for Expression {
+    fn from(constructor: Constructor) -> Expression {
+        Expression::Constructor(constructor)
+    }
+}
+
+impl From<If> for Expression {
+    fn from(if_: If) -> Expression {
+        Expression::If(if_)
+    }
+}
+
+impl From<Lambda> for Expression {
+    fn from(lambda: Lambda) -> Expression {
+        Expression::Lambda(lambda)
+    }
+}
+
 impl From<Let> for Expression {
     fn from(let_: Let) -> Expression {
         Expression::Let(let_)
     }
 }
 
+impl From<Match> for Expression {
+    fn from(match_: Match) -> Expression {
+        Expression::Match(match_)
+    }
+}
+
+impl From<MatchFailure> for Expression {
+    fn from(match_failure: MatchFailure) -> Expression {
+        Expression::MatchFailure(match_failure)
+    }
+}
+
+impl From<BuiltInCall> for Expression {
+    fn from(built_in_call: BuiltInCall) -> Expression {
+        Expression::BuiltInCall(built_in_call)
+    }
+}
+
+impl From<Conversion> for Expression {
+    fn from(conversion: Conversion) -> Expression {
+        Expression::Conversion(conversion)
+    }
+}
+
+impl From<EmptyListFailure> for Expression {
+    fn from(empty_list_failure: EmptyListFailure) -> Expression {
+        Expression::EmptyListFailure(empty_list_failure)
+    }
+}
+
+impl From<Negation> for Expression {
+    fn from(negation: Negation) -> Expression {
+        Expression::Negation(negation)
+    }
+}
+
 impl From<Number> for Expression {
     fn from(number: Number) -> Expression {
         Expression::Number(number)
@@ -74,6 +245,36 @@ impl From<Operation> for Expression {
     }
 }
 
+impl From<Record> for Expression {
+    fn from(record: Record) -> Expression {
+        Expression::Record(record)
+    }
+}
+
+impl From<RecordElement> for Expression {
+    fn from(record_element: RecordElement) -> Expression {
+        Expression::RecordElement(record_element)
+    }
+}
+
+impl From<RecordUpdate> for Expression {
+    fn from(record_update: RecordUpdate) -> Expression {
+        Expression::RecordUpdate(record_update)
+    }
+}
+
+impl From<StringLiteral> for Expression {
+    fn from(string_literal: StringLiteral) -> Expression {
+        Expression::StringLiteral(string_literal)
+    }
+}
+
+impl From<Tuple> for Expression {
+    fn from(tuple: Tuple) -> Expression {
+        Expression::Tuple(tuple)
+    }
+}
+
 impl From<Variable> for Expression {
     fn from(variable: Variable) -> Expression {
         Expression::Variable(variable)
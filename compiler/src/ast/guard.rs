@@ -0,0 +1,55 @@
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::types::Type;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Guard {
+    condition: Expression,
+    expression: Expression,
+}
+
+impl Guard {
+    pub fn new(condition: impl Into<Expression>, expression: impl Into<Expression>) -> Self {
+        Self {
+            condition: condition.into(),
+            expression: expression.into(),
+        }
+    }
+
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    pub fn is_otherwise(&self) -> bool {
+        match &self.condition {
+            Expression::Boolean(boolean) => boolean.value(),
+            _ => false,
+        }
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.condition.substitute_type_variables(substitutions),
+            self.expression.substitute_type_variables(substitutions),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.condition.convert_definitions(convert),
+            self.expression.convert_definitions(convert),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.condition.convert_expressions(convert),
+            self.expression.convert_expressions(convert),
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lambda {
+    type_: Type,
+    arguments: Vec<String>,
+    body: Rc<Expression>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Lambda {
+    pub fn new(
+        arguments: Vec<String>,
+        body: impl Into<Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        let source_information = source_information.into();
+
+        Self::with_type(
+            types::Variable::new(source_information.clone()),
+            arguments,
+            body,
+            source_information,
+        )
+    }
+
+    pub fn with_type(
+        type_: impl Into<Type>,
+        arguments: Vec<String>,
+        body: impl Into<Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            arguments,
+            body: Rc::new(body.into()),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    pub fn body(&self) -> &Expression {
+        &self.body
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::with_type(
+            self.type_.substitute_variables(substitutions),
+            self.arguments.clone(),
+            self.body.substitute_type_variables(substitutions),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::with_type(
+            self.type_.clone(),
+            self.arguments.clone(),
+            self.body.convert_definitions(convert),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::with_type(
+            self.type_.clone(),
+            self.arguments.clone(),
+            self.body.convert_expressions(convert),
+            self.source_information.clone(),
+        )
+    }
+}
@@ -1,4 +1,5 @@
 use super::definition::*;
+use super::destructuring::*;
 use super::expression::*;
 use crate::types::Type;
 use std::collections::HashMap;
@@ -7,13 +8,23 @@ use std::rc::Rc;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Let {
     definitions: Vec<Definition>,
+    destructurings: Vec<Destructuring>,
     expression: Rc<Expression>,
 }
 
 impl Let {
     pub fn new(definitions: Vec<Definition>, expression: impl Into<Expression>) -> Self {
+        Self::with_destructurings(definitions, vec![], expression)
+    }
+
+    pub fn with_destructurings(
+        definitions: Vec<Definition>,
+        destructurings: Vec<Destructuring>,
+        expression: impl Into<Expression>,
+    ) -> Self {
         Self {
             definitions,
+            destructurings,
             expression: Rc::new(expression.into()),
         }
     }
@@ -22,36 +33,52 @@ impl Let {
         &self.definitions
     }
 
+    pub fn destructurings(&self) -> &[Destructuring] {
+        &self.destructurings
+    }
+
     pub fn expression(&self) -> &Expression {
         &self.expression
     }
 
     pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
-        Self::new(
+        Self::with_destructurings(
             self.definitions
                 .iter()
                 .map(|definition| definition.substitute_type_variables(substitutions))
                 .collect::<Vec<_>>(),
+            self.destructurings
+                .iter()
+                .map(|destructuring| destructuring.substitute_type_variables(substitutions))
+                .collect(),
             self.expression.substitute_type_variables(substitutions),
         )
     }
 
     pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
-        Self::new(
+        Self::with_destructurings(
             self.definitions
                 .iter()
                 .map(|definition| definition.convert_definitions(convert))
                 .collect(),
+            self.destructurings
+                .iter()
+                .map(|destructuring| destructuring.convert_definitions(convert))
+                .collect(),
             self.expression.convert_definitions(convert),
         )
     }
 
     pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
-        Self::new(
+        Self::with_destructurings(
             self.definitions
                 .iter()
                 .map(|definition| definition.convert_expressions(convert))
                 .collect(),
+            self.destructurings
+                .iter()
+                .map(|destructuring| destructuring.convert_expressions(convert))
+                .collect(),
             self.expression.convert_expressions(convert),
         )
     }
@@ -0,0 +1,73 @@
+use super::clause::Clause;
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match {
+    arguments: Vec<String>,
+    clauses: Vec<Clause>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Match {
+    pub fn new(
+        arguments: Vec<String>,
+        clauses: Vec<Clause>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            arguments,
+            clauses,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    pub fn clauses(&self) -> &[Clause] {
+        &self.clauses
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.arguments.clone(),
+            self.clauses
+                .iter()
+                .map(|clause| clause.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.arguments.clone(),
+            self.clauses
+                .iter()
+                .map(|clause| clause.convert_definitions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.arguments.clone(),
+            self.clauses
+                .iter()
+                .map(|clause| clause.convert_expressions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
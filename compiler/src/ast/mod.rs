@@ -1,23 +1,81 @@
+mod alternative;
 mod application;
+mod boolean;
+mod built_in_call;
+mod built_in_function;
+mod case;
+mod char_literal;
+mod clause;
+mod constructor;
+mod constructor_definition;
+mod constructor_pattern;
+mod conversion;
 mod definition;
+mod destructuring;
+mod empty_list_failure;
 mod expression;
 mod function_definition;
+mod guard;
+mod if_;
+mod lambda;
 mod let_;
+mod match_;
+mod match_failure;
 mod module;
+mod negation;
 mod number;
 mod operation;
 mod operator;
+mod pattern;
+mod record;
+mod record_element;
+mod record_update;
+mod string_literal;
+mod tuple;
+mod tuple_pattern;
+mod type_alias;
+mod type_definition;
 mod value_definition;
 mod variable;
+mod wildcard;
 
+pub use alternative::*;
 pub use application::*;
+pub use boolean::*;
+pub use built_in_call::*;
+pub use built_in_function::*;
+pub use case::*;
+pub use char_literal::*;
+pub use clause::*;
+pub use constructor::*;
+pub use constructor_definition::*;
+pub use constructor_pattern::*;
+pub use conversion::*;
 pub use definition::*;
+pub use destructuring::*;
+pub use empty_list_failure::*;
 pub use expression::*;
 pub use function_definition::*;
+pub use guard::*;
+pub use if_::*;
+pub use lambda::*;
 pub use let_::*;
+pub use match_::*;
+pub use match_failure::*;
 pub use module::*;
+pub use negation::*;
 pub use number::*;
 pub use operation::*;
 pub use operator::*;
+pub use pattern::*;
+pub use record::*;
+pub use record_element::*;
+pub use record_update::*;
+pub use string_literal::*;
+pub use tuple::*;
+pub use tuple_pattern::*;
+pub use type_alias::*;
+pub use type_definition::*;
 pub use value_definition::*;
 pub use variable::*;
+pub use wildcard::*;
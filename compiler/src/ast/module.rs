@@ -1,16 +1,48 @@
 use super::definition::Definition;
 use super::expression::Expression;
+use super::type_alias::TypeAlias;
+use super::type_definition::TypeDefinition;
 use crate::types::Type;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Module {
+    type_definitions: Vec<TypeDefinition>,
+    type_aliases: Vec<TypeAlias>,
     definitions: Vec<Definition>,
 }
 
 impl Module {
+    #[cfg(test)]
     pub fn new(definitions: Vec<Definition>) -> Self {
-        Self { definitions }
+        Self::with_type_definitions(vec![], definitions)
+    }
+
+    pub fn with_type_definitions(
+        type_definitions: Vec<TypeDefinition>,
+        definitions: Vec<Definition>,
+    ) -> Self {
+        Self::with_type_aliases(type_definitions, vec![], definitions)
+    }
+
+    pub fn with_type_aliases(
+        type_definitions: Vec<TypeDefinition>,
+        type_aliases: Vec<TypeAlias>,
+        definitions: Vec<Definition>,
+    ) -> Self {
+        Self {
+            type_definitions,
+            type_aliases,
+            definitions,
+        }
+    }
+
+    pub fn type_definitions(&self) -> &[TypeDefinition] {
+        &self.type_definitions
+    }
+
+    pub fn type_aliases(&self) -> &[TypeAlias] {
+        &self.type_aliases
     }
 
     pub fn definitions(&self) -> &[Definition] {
@@ -18,7 +50,9 @@ impl Module {
     }
 
     pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
-        Self::new(
+        Self::with_type_aliases(
+            self.type_definitions.clone(),
+            self.type_aliases.clone(),
             self.definitions
                 .iter()
                 .map(|definition| definition.substitute_type_variables(substitutions))
@@ -27,7 +61,9 @@ impl Module {
     }
 
     pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
-        Self::new(
+        Self::with_type_aliases(
+            self.type_definitions.clone(),
+            self.type_aliases.clone(),
             self.definitions
                 .iter()
                 .map(|definition| definition.convert_definitions(convert))
@@ -36,7 +72,9 @@ impl Module {
     }
 
     pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
-        Self::new(
+        Self::with_type_aliases(
+            self.type_definitions.clone(),
+            self.type_aliases.clone(),
             self.definitions
                 .iter()
                 .map(|definition| definition.convert_expressions(convert))
@@ -1,25 +1,85 @@
 use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::collections::HashMap;
 use std::rc::Rc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Number {
+    type_: Type,
     value: f64,
+    integer: Option<i64>,
     source_information: Rc<SourceInformation>,
 }
 
 impl Number {
+    #[cfg(test)]
     pub fn new(value: f64, source_information: impl Into<Rc<SourceInformation>>) -> Self {
+        let source_information = source_information.into();
+
+        Self::with_type(
+            types::Variable::new(source_information.clone()),
+            value,
+            source_information,
+        )
+    }
+
+    pub fn with_type(
+        type_: impl Into<Type>,
+        value: f64,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
         Self {
+            type_: type_.into(),
             value,
+            integer: if value.fract() == 0.0 && value >= -(2f64.powi(63)) && value < 2f64.powi(63) {
+                Some(value as i64)
+            } else {
+                None
+            },
             source_information: source_information.into(),
         }
     }
 
+    pub fn from_integer(value: i64, source_information: impl Into<Rc<SourceInformation>>) -> Self {
+        let source_information = source_information.into();
+
+        Self {
+            type_: types::Variable::new(source_information.clone()).into(),
+            value: value as f64,
+            integer: Some(value),
+            source_information,
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
     pub fn value(&self) -> f64 {
         self.value
     }
 
+    pub fn integer(&self) -> Option<i64> {
+        self.integer
+    }
+
+    pub fn negate(&self, source_information: impl Into<Rc<SourceInformation>>) -> Self {
+        Self {
+            type_: self.type_.clone(),
+            value: -self.value,
+            integer: self.integer.and_then(i64::checked_neg),
+            source_information: source_information.into(),
+        }
+    }
+
     pub fn source_information(&self) -> &Rc<SourceInformation> {
         &self.source_information
     }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self {
+            type_: self.type_.substitute_variables(substitutions),
+            ..self.clone()
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use super::definition::Definition;
 use super::expression::Expression;
 use super::operator::Operator;
 use crate::debug::SourceInformation;
@@ -52,4 +53,22 @@ impl Operation {
             self.source_information.clone(),
         )
     }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.operator,
+            self.lhs.convert_definitions(convert),
+            self.rhs.convert_definitions(convert),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.operator,
+            self.lhs.convert_expressions(convert),
+            self.rhs.convert_expressions(convert),
+            self.source_information.clone(),
+        )
+    }
 }
@@ -4,6 +4,24 @@ pub enum Operator {
     Subtract,
     Multiply,
     Divide,
+    Remainder,
+    Power,
+    FloorDivide,
+    Modulo,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
+    Concatenate,
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    And,
+    Or,
 }
 
 impl From<&Operator> for core::ast::Operator {
@@ -13,6 +31,23 @@ impl From<&Operator> for core::ast::Operator {
             Operator::Subtract => core::ast::Operator::Subtract,
             Operator::Multiply => core::ast::Operator::Multiply,
             Operator::Divide => core::ast::Operator::Divide,
+            Operator::Remainder => core::ast::Operator::Remainder,
+            Operator::Power => core::ast::Operator::Power,
+            Operator::FloorDivide => core::ast::Operator::FloorDivide,
+            Operator::Modulo => core::ast::Operator::Modulo,
+            Operator::BitwiseAnd => core::ast::Operator::BitwiseAnd,
+            Operator::BitwiseOr => core::ast::Operator::BitwiseOr,
+            Operator::BitwiseXor => core::ast::Operator::BitwiseXor,
+            Operator::ShiftLeft => core::ast::Operator::ShiftLeft,
+            Operator::ShiftRight => core::ast::Operator::ShiftRight,
+            Operator::Concatenate => core::ast::Operator::Concatenate,
+            Operator::Equal => core::ast::Operator::Equal,
+            Operator::NotEqual => core::ast::Operator::NotEqual,
+            Operator::LessThan => core::ast::Operator::LessThan,
+            Operator::LessThanOrEqual => core::ast::Operator::LessThanOrEqual,
+            Operator::GreaterThan => core::ast::Operator::GreaterThan,
+            Operator::GreaterThanOrEqual => core::ast::Operator::GreaterThanOrEqual,
+            Operator::And | Operator::Or => unreachable!(),
         }
     }
 }
@@ -0,0 +1,99 @@
+use super::boolean::Boolean;
+use super::constructor_pattern::ConstructorPattern;
+use super::number::Number;
+use super::tuple_pattern::TuplePattern;
+use super::variable::Variable;
+use super::wildcard::Wildcard;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    Boolean(Boolean),
+    Constructor(ConstructorPattern),
+    Number(Number),
+    Tuple(TuplePattern),
+    Variable(Variable),
+    Wildcard(Wildcard),
+}
+
+impl Pattern {
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        match self {
+            Self::Boolean(boolean) => boolean.source_information(),
+            Self::Constructor(constructor_pattern) => constructor_pattern.source_information(),
+            Self::Number(number) => number.source_information(),
+            Self::Tuple(tuple_pattern) => tuple_pattern.source_information(),
+            Self::Variable(variable) => variable.source_information(),
+            Self::Wildcard(wildcard) => wildcard.source_information(),
+        }
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        match self {
+            Self::Constructor(constructor_pattern) => constructor_pattern
+                .substitute_type_variables(substitutions)
+                .into(),
+            Self::Number(number) => number.substitute_type_variables(substitutions).into(),
+            Self::Tuple(tuple_pattern) => tuple_pattern
+                .substitute_type_variables(substitutions)
+                .into(),
+            Self::Boolean(_) | Self::Variable(_) | Self::Wildcard(_) => self.clone(),
+        }
+    }
+
+    pub fn variables(&self) -> Vec<&Variable> {
+        match self {
+            Self::Constructor(constructor_pattern) => constructor_pattern
+                .arguments()
+                .iter()
+                .flat_map(|pattern| pattern.variables())
+                .collect(),
+            Self::Tuple(tuple_pattern) => tuple_pattern
+                .elements()
+                .iter()
+                .flat_map(|pattern| pattern.variables())
+                .collect(),
+            Self::Variable(variable) => vec![variable],
+            Self::Boolean(_) | Self::Number(_) | Self::Wildcard(_) => vec![],
+        }
+    }
+}
+
+impl From<Boolean> for Pattern {
+    fn from(boolean: Boolean) -> Self {
+        Self::Boolean(boolean)
+    }
+}
+
+impl From<ConstructorPattern> for Pattern {
+    fn from(constructor_pattern: ConstructorPattern) -> Self {
+        Self::Constructor(constructor_pattern)
+    }
+}
+
+impl From<Number> for Pattern {
+    fn from(number: Number) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<TuplePattern> for Pattern {
+    fn from(tuple_pattern: TuplePattern) -> Self {
+        Self::Tuple(tuple_pattern)
+    }
+}
+
+impl From<Variable> for Pattern {
+    fn from(variable: Variable) -> Self {
+        Self::Variable(variable)
+    }
+}
+
+impl From<Wildcard> for Pattern {
+    fn from(wildcard: Wildcard) -> Self {
+        Self::Wildcard(wildcard)
+    }
+}
@@ -0,0 +1,102 @@
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordUpdate {
+    type_: Type,
+    record: Rc<Expression>,
+    elements: BTreeMap<String, Expression>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl RecordUpdate {
+    pub fn new(
+        record: impl Into<Expression>,
+        elements: BTreeMap<String, Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        let source_information = source_information.into();
+
+        Self::with_type(
+            types::Variable::new(source_information.clone()),
+            record,
+            elements,
+            source_information,
+        )
+    }
+
+    pub fn with_type(
+        type_: impl Into<Type>,
+        record: impl Into<Expression>,
+        elements: BTreeMap<String, Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            record: Rc::new(record.into()),
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn record(&self) -> &Expression {
+        &self.record
+    }
+
+    pub fn elements(&self) -> &BTreeMap<String, Expression> {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::with_type(
+            self.type_.substitute_variables(substitutions),
+            self.record.substitute_type_variables(substitutions),
+            self.elements
+                .iter()
+                .map(|(name, expression)| {
+                    (
+                        name.clone(),
+                        expression.substitute_type_variables(substitutions),
+                    )
+                })
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::with_type(
+            self.type_.clone(),
+            self.record.convert_definitions(convert),
+            self.elements
+                .iter()
+                .map(|(name, expression)| (name.clone(), expression.convert_definitions(convert)))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::with_type(
+            self.type_.clone(),
+            self.record.convert_expressions(convert),
+            self.elements
+                .iter()
+                .map(|(name, expression)| (name.clone(), expression.convert_expressions(convert)))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
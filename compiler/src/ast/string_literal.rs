@@ -0,0 +1,28 @@
+use crate::debug::SourceInformation;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringLiteral {
+    value: String,
+    source_information: Rc<SourceInformation>,
+}
+
+impl StringLiteral {
+    pub fn new(
+        value: impl Into<String>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            value: value.into(),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+}
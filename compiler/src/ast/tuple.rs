@@ -0,0 +1,62 @@
+use super::definition::Definition;
+use super::expression::Expression;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuple {
+    elements: Vec<Expression>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Tuple {
+    pub fn new(
+        elements: Vec<Expression>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn elements(&self) -> &[Expression] {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|element| element.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_definitions(&self, convert: &mut impl FnMut(&Definition) -> Definition) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|element| element.convert_definitions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_expressions(&self, convert: &mut impl FnMut(&Expression) -> Expression) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|element| element.convert_expressions(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
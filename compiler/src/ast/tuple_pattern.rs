@@ -0,0 +1,41 @@
+use super::pattern::Pattern;
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TuplePattern {
+    elements: Vec<Pattern>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl TuplePattern {
+    pub fn new(
+        elements: Vec<Pattern>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn elements(&self) -> &[Pattern] {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_type_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|pattern| pattern.substitute_type_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
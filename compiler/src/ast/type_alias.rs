@@ -0,0 +1,36 @@
+use crate::debug::SourceInformation;
+use crate::types::Type;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeAlias {
+    name: String,
+    type_: Type,
+    source_information: Rc<SourceInformation>,
+}
+
+impl TypeAlias {
+    pub fn new(
+        name: impl Into<String>,
+        type_: impl Into<Type>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            type_: type_.into(),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+}
@@ -0,0 +1,56 @@
+use super::constructor_definition::ConstructorDefinition;
+use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeDefinition {
+    name: String,
+    constructors: Vec<ConstructorDefinition>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl TypeDefinition {
+    pub fn new(
+        name: impl Into<String>,
+        constructors: Vec<ConstructorDefinition>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            constructors,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn constructors(&self) -> &[ConstructorDefinition] {
+        &self.constructors
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn type_(&self) -> Type {
+        types::Reference::new(self.name.clone(), self.source_information.clone()).into()
+    }
+
+    pub fn constructor_type(&self, constructor: &ConstructorDefinition) -> Type {
+        constructor
+            .elements()
+            .iter()
+            .rev()
+            .fold(self.type_(), |result, element| {
+                types::Function::new(
+                    element.clone(),
+                    result,
+                    constructor.source_information().clone(),
+                )
+                .into()
+            })
+    }
+}
@@ -0,0 +1,87 @@
+use super::free_variable_finder::FreeVariableFinder;
+use crate::ast;
+use std::collections::HashSet;
+
+pub struct DefinitionSorter {}
+
+impl DefinitionSorter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn sort<'a>(&self, definitions: &[&'a ast::Definition]) -> Vec<Vec<&'a ast::Definition>> {
+        let dependencies = definitions
+            .iter()
+            .map(|definition| {
+                FreeVariableFinder::new()
+                    .find_in_definition(definition)
+                    .iter()
+                    .filter_map(|name| {
+                        definitions
+                            .iter()
+                            .position(|definition| definition.name() == name)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let reachable_indices = (0..definitions.len())
+            .map(|index| Self::find_reachable_indices(index, &dependencies))
+            .collect::<Vec<_>>();
+
+        let mut components = Vec::<Vec<usize>>::new();
+
+        for index in 0..definitions.len() {
+            if !components
+                .iter()
+                .any(|component| component.contains(&index))
+            {
+                components.push(
+                    (index..definitions.len())
+                        .filter(|&other| {
+                            other == index
+                                || reachable_indices[index].contains(&other)
+                                    && reachable_indices[other].contains(&index)
+                        })
+                        .collect(),
+                );
+            }
+        }
+
+        let mut defined_indices = HashSet::new();
+        let mut sorted_definitions = vec![];
+
+        while !components.is_empty() {
+            let component = components.remove(
+                components
+                    .iter()
+                    .position(|component| {
+                        component.iter().all(|index| {
+                            dependencies[*index].iter().all(|dependency| {
+                                component.contains(dependency)
+                                    || defined_indices.contains(dependency)
+                            })
+                        })
+                    })
+                    .expect("acyclic components"),
+            );
+
+            defined_indices.extend(component.iter().cloned());
+            sorted_definitions.push(component.iter().map(|index| definitions[*index]).collect());
+        }
+
+        sorted_definitions
+    }
+
+    fn find_reachable_indices(index: usize, dependencies: &[Vec<usize>]) -> HashSet<usize> {
+        let mut reachable_indices = HashSet::new();
+        let mut indices = dependencies[index].clone();
+
+        while let Some(index) = indices.pop() {
+            if reachable_indices.insert(index) {
+                indices.extend_from_slice(&dependencies[index]);
+            }
+        }
+
+        reachable_indices
+    }
+}
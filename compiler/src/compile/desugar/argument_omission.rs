@@ -1,4 +1,5 @@
 use super::super::name_generator::NameGenerator;
+use super::main_function::MAIN_FUNCTION_NAME;
 use crate::ast::*;
 use crate::debug::*;
 use crate::types::Type;
@@ -46,7 +47,7 @@ pub fn desugar_argument_omission(module: &Module) -> Module {
             _ => unreachable!(),
         },
         Definition::ValueDefinition(value_definition) => match value_definition.type_() {
-            Type::Function(function_type) => {
+            Type::Function(function_type) if value_definition.name() == MAIN_FUNCTION_NAME => {
                 let arguments = function_type
                     .arguments()
                     .iter()
@@ -87,6 +88,35 @@ fn append_arguments_to_expression(expression: &Expression, arguments: &[Variable
                 application.source_information(),
             )
         }
+        Expression::Case(case) => Case::with_type(
+            case.type_().clone(),
+            case.argument().clone(),
+            case.alternatives()
+                .iter()
+                .map(|alternative| {
+                    Alternative::new(
+                        alternative.pattern().clone(),
+                        append_arguments_to_expression(alternative.expression(), arguments),
+                    )
+                })
+                .collect(),
+            case.source_information().clone(),
+        )
+        .into(),
+        Expression::Constructor(constructor) => {
+            append_arguments_to_expression_with_source_information(
+                expression,
+                arguments,
+                constructor.source_information(),
+            )
+        }
+        Expression::If(if_) => If::new(
+            if_.condition().clone(),
+            append_arguments_to_expression(if_.then(), arguments),
+            append_arguments_to_expression(if_.else_(), arguments),
+            if_.source_information().clone(),
+        )
+        .into(),
         Expression::Let(let_) => Let::new(
             let_.definitions().to_vec(),
             append_arguments_to_expression(let_.expression(), arguments),
@@ -124,10 +154,10 @@ mod test {
     use crate::types;
 
     #[test]
-    fn complement_an_omitted_argument_of_value_definition() {
+    fn complement_an_omitted_argument_of_main_function() {
         assert_eq!(
             desugar_argument_omission(&Module::new(vec![ValueDefinition::new(
-                "f",
+                MAIN_FUNCTION_NAME,
                 Variable::new("g", SourceInformation::dummy()),
                 types::Function::new(
                     types::Number::new(SourceInformation::dummy()),
@@ -138,7 +168,7 @@ mod test {
             )
             .into()])),
             Module::new(vec![FunctionDefinition::new(
-                "f",
+                MAIN_FUNCTION_NAME,
                 vec!["omitted_argument_0".into()],
                 Application::new(
                     Variable::new("g", SourceInformation::dummy()),
@@ -157,10 +187,10 @@ mod test {
     }
 
     #[test]
-    fn complement_2_omitted_arguments_of_value_definition() {
+    fn complement_2_omitted_arguments_of_main_function() {
         assert_eq!(
             desugar_argument_omission(&Module::new(vec![ValueDefinition::new(
-                "f",
+                MAIN_FUNCTION_NAME,
                 Variable::new("g", SourceInformation::dummy()),
                 types::Function::new(
                     types::Number::new(SourceInformation::dummy()),
@@ -175,7 +205,7 @@ mod test {
             )
             .into()])),
             Module::new(vec![FunctionDefinition::new(
-                "f",
+                MAIN_FUNCTION_NAME,
                 vec!["omitted_argument_0".into(), "omitted_argument_1".into()],
                 Application::new(
                     Application::new(
@@ -201,6 +231,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn keep_value_definitions_of_function_types() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "f",
+            Variable::new("g", SourceInformation::dummy()),
+            types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(desugar_argument_omission(&module), module);
+    }
+
     #[test]
     fn complement_an_omitted_argument_of_function_definition() {
         assert_eq!(
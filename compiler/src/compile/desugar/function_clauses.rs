@@ -0,0 +1,348 @@
+use super::super::name_generator::NameGenerator;
+use crate::ast::*;
+use crate::debug::SourceInformation;
+use crate::types;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub fn desugar_function_clauses(module: &Module) -> Module {
+    let constructor_counts = module
+        .type_definitions()
+        .iter()
+        .flat_map(|type_definition| {
+            type_definition
+                .constructors()
+                .iter()
+                .map(move |constructor| {
+                    (
+                        constructor.name().to_string(),
+                        type_definition.constructors().len(),
+                    )
+                })
+        })
+        .collect::<HashMap<_, _>>();
+    let mut name_generator = NameGenerator::new("next_clause_");
+
+    module.convert_expressions(&mut |expression| match expression {
+        Expression::Match(match_) => desugar_clauses(
+            match_.arguments(),
+            match_.clauses(),
+            match_.source_information(),
+            &constructor_counts,
+            &mut name_generator,
+        ),
+        _ => expression.clone(),
+    })
+}
+
+fn desugar_clauses(
+    arguments: &[String],
+    clauses: &[Clause],
+    source_information: &Rc<SourceInformation>,
+    constructor_counts: &HashMap<String, usize>,
+    name_generator: &mut NameGenerator,
+) -> Expression {
+    match clauses.split_first() {
+        None => MatchFailure::new(source_information.clone()).into(),
+        Some((clause, _)) if !can_fail(clause, constructor_counts) => {
+            desugar_clause(arguments, clause, constructor_counts, &|| unreachable!())
+        }
+        Some((clause, [])) => desugar_clause(arguments, clause, constructor_counts, &|| {
+            MatchFailure::new(source_information.clone()).into()
+        }),
+        Some((clause, clauses)) => {
+            let function_name = name_generator.generate();
+            let clause_source_information = clause.source_information();
+
+            Let::new(
+                vec![FunctionDefinition::new(
+                    function_name.clone(),
+                    arguments.to_vec(),
+                    desugar_clauses(
+                        arguments,
+                        clauses,
+                        source_information,
+                        constructor_counts,
+                        name_generator,
+                    ),
+                    types::Function::new(
+                        types::Variable::new(clause_source_information.clone()),
+                        types::Variable::new(clause_source_information.clone()),
+                        clause_source_information.clone(),
+                    ),
+                    clause_source_information.clone(),
+                )
+                .into()],
+                desugar_clause(arguments, clause, constructor_counts, &|| {
+                    arguments.iter().fold(
+                        Variable::new(function_name.clone(), clause_source_information.clone())
+                            .into(),
+                        |function, argument| {
+                            Application::new(
+                                function,
+                                Variable::new(argument, clause_source_information.clone()),
+                                clause_source_information.clone(),
+                            )
+                            .into()
+                        },
+                    )
+                }),
+            )
+            .into()
+        }
+    }
+}
+
+fn desugar_clause(
+    arguments: &[String],
+    clause: &Clause,
+    constructor_counts: &HashMap<String, usize>,
+    fail: &dyn Fn() -> Expression,
+) -> Expression {
+    let source_information = clause.source_information();
+    let (guards, fallback) = match clause.guards().iter().position(Guard::is_otherwise) {
+        Some(index) => (
+            &clause.guards()[..index],
+            clause.guards()[index].expression().clone(),
+        ),
+        None => (clause.guards(), fail()),
+    };
+    let guards = guards.iter().rev().fold(fallback, |expression, guard| {
+        If::new(
+            guard.condition().clone(),
+            guard.expression().clone(),
+            expression,
+            source_information.clone(),
+        )
+        .into()
+    });
+    let body = if clause.definitions().is_empty() {
+        guards
+    } else {
+        Let::new(clause.definitions().to_vec(), guards).into()
+    };
+
+    arguments
+        .iter()
+        .zip(clause.patterns())
+        .rev()
+        .fold(body, |expression, (argument, pattern)| {
+            let argument = Variable::new(argument, pattern.source_information().clone());
+
+            match pattern {
+                Pattern::Variable(variable) => Let::new(
+                    vec![ValueDefinition::new(
+                        variable.name(),
+                        argument,
+                        types::Variable::new(variable.source_information().clone()),
+                        variable.source_information().clone(),
+                    )
+                    .into()],
+                    expression,
+                )
+                .into(),
+                Pattern::Wildcard(_) => expression,
+                _ => Case::new(
+                    argument,
+                    if is_irrefutable(pattern, constructor_counts) {
+                        vec![Alternative::new(pattern.clone(), expression)]
+                    } else {
+                        vec![
+                            Alternative::new(pattern.clone(), expression),
+                            Alternative::new(
+                                Wildcard::new(pattern.source_information().clone()),
+                                fail(),
+                            ),
+                        ]
+                    },
+                    pattern.source_information().clone(),
+                )
+                .into(),
+            }
+        })
+}
+
+fn can_fail(clause: &Clause, constructor_counts: &HashMap<String, usize>) -> bool {
+    !clause.is_total()
+        || clause
+            .patterns()
+            .iter()
+            .any(|pattern| !is_irrefutable(pattern, constructor_counts))
+}
+
+fn is_irrefutable(pattern: &Pattern, constructor_counts: &HashMap<String, usize>) -> bool {
+    match pattern {
+        Pattern::Constructor(constructor_pattern) => {
+            constructor_counts.get(constructor_pattern.name()) == Some(&1)
+                && constructor_pattern
+                    .arguments()
+                    .iter()
+                    .all(|pattern| is_irrefutable(pattern, constructor_counts))
+        }
+        Pattern::Tuple(tuple_pattern) => tuple_pattern
+            .elements()
+            .iter()
+            .all(|pattern| is_irrefutable(pattern, constructor_counts)),
+        Pattern::Variable(_) | Pattern::Wildcard(_) => true,
+        Pattern::Boolean(_) | Pattern::Number(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug::*;
+
+    fn function_type() -> types::Function {
+        types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+    }
+
+    fn clause(pattern: impl Into<Pattern>, expression: impl Into<Expression>) -> Clause {
+        Clause::new(
+            vec![pattern.into()],
+            vec![Guard::new(
+                Boolean::new(true, SourceInformation::dummy()),
+                expression,
+            )],
+            vec![],
+            SourceInformation::dummy(),
+        )
+    }
+
+    #[test]
+    fn desugar_clauses_into_join_points() {
+        assert_eq!(
+            desugar_function_clauses(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Match::new(
+                    vec!["x".into()],
+                    vec![
+                        clause(
+                            Number::new(0.0, SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy())
+                        ),
+                        clause(
+                            Variable::new("n", SourceInformation::dummy()),
+                            Variable::new("n", SourceInformation::dummy())
+                        ),
+                        clause(
+                            Wildcard::new(SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy())
+                        ),
+                    ],
+                    SourceInformation::dummy()
+                ),
+                function_type(),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "next_clause_0",
+                        vec!["x".into()],
+                        Let::new(
+                            vec![ValueDefinition::new(
+                                "n",
+                                Variable::new("x", SourceInformation::dummy()),
+                                types::Variable::new(SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            )
+                            .into()],
+                            Variable::new("n", SourceInformation::dummy())
+                        ),
+                        types::Function::new(
+                            types::Variable::new(SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Case::new(
+                        Variable::new("x", SourceInformation::dummy()),
+                        vec![
+                            Alternative::new(
+                                Number::new(0.0, SourceInformation::dummy()),
+                                Number::new(1.0, SourceInformation::dummy())
+                            ),
+                            Alternative::new(
+                                Wildcard::new(SourceInformation::dummy()),
+                                Application::new(
+                                    Variable::new("next_clause_0", SourceInformation::dummy()),
+                                    Variable::new("x", SourceInformation::dummy()),
+                                    SourceInformation::dummy()
+                                )
+                            )
+                        ],
+                        SourceInformation::dummy()
+                    )
+                ),
+                function_type(),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+
+    #[test]
+    fn desugar_guards_with_match_failures() {
+        assert_eq!(
+            desugar_function_clauses(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Match::new(
+                    vec!["x".into()],
+                    vec![Clause::new(
+                        vec![Wildcard::new(SourceInformation::dummy()).into()],
+                        vec![Guard::new(
+                            Variable::new("y", SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy())
+                        )],
+                        vec![ValueDefinition::new(
+                            "y",
+                            Boolean::new(false, SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        )
+                        .into()],
+                        SourceInformation::dummy()
+                    )],
+                    SourceInformation::dummy()
+                ),
+                function_type(),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Let::new(
+                    vec![ValueDefinition::new(
+                        "y",
+                        Boolean::new(false, SourceInformation::dummy()),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    If::new(
+                        Variable::new("y", SourceInformation::dummy()),
+                        Number::new(1.0, SourceInformation::dummy()),
+                        MatchFailure::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                ),
+                function_type(),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use super::super::name_generator::NameGenerator;
+use crate::ast::*;
+
+pub fn desugar_lambdas(module: &Module) -> Module {
+    let mut name_generator = NameGenerator::new("anonymous_function_");
+
+    module.convert_expressions(&mut |expression| match expression {
+        Expression::Lambda(lambda) => {
+            let function_name = name_generator.generate();
+            let source_information = lambda.source_information();
+
+            Let::new(
+                vec![FunctionDefinition::new(
+                    function_name.clone(),
+                    lambda.arguments().to_vec(),
+                    lambda.body().clone(),
+                    lambda.type_().clone(),
+                    source_information.clone(),
+                )
+                .into()],
+                Variable::new(function_name, source_information.clone()),
+            )
+            .into()
+        }
+        _ => expression.clone(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug::*;
+    use crate::types;
+
+    #[test]
+    fn desugar_lambda() {
+        let type_ = types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            desugar_lambdas(&Module::new(vec![ValueDefinition::new(
+                "f",
+                Lambda::with_type(
+                    type_.clone(),
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                ),
+                type_.clone(),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Module::new(vec![ValueDefinition::new(
+                "f",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "anonymous_function_0",
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        type_.clone(),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Variable::new("anonymous_function_0", SourceInformation::dummy())
+                ),
+                type_,
+                SourceInformation::dummy(),
+            )
+            .into()])
+        );
+    }
+}
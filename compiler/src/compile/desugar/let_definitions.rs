@@ -0,0 +1,233 @@
+use super::super::definition_sorter::DefinitionSorter;
+use super::super::name_generator::NameGenerator;
+use crate::ast::*;
+use crate::types;
+
+pub fn desugar_let_definitions(module: &Module) -> Module {
+    let mut name_generator = NameGenerator::new("destructured_value_");
+
+    module.convert_expressions(&mut |expression| match expression {
+        Expression::Let(let_) => {
+            let definitions = let_
+                .definitions()
+                .iter()
+                .cloned()
+                .chain(let_.destructurings().iter().flat_map(|destructuring| {
+                    desugar_destructuring(destructuring, name_generator.generate())
+                }))
+                .collect::<Vec<_>>();
+
+            DefinitionSorter::new()
+                .sort(&definitions.iter().collect::<Vec<_>>())
+                .into_iter()
+                .rev()
+                .fold(let_.expression().clone(), |expression, definitions| {
+                    Let::new(definitions.into_iter().cloned().collect(), expression).into()
+                })
+        }
+        _ => expression.clone(),
+    })
+}
+
+fn desugar_destructuring(destructuring: &Destructuring, name: String) -> Vec<Definition> {
+    let source_information = destructuring.source_information();
+    let value = Variable::new(name.clone(), source_information.clone());
+
+    vec![ValueDefinition::new(
+        name,
+        destructuring.expression().clone(),
+        types::Variable::new(source_information.clone()),
+        source_information.clone(),
+    )
+    .into()]
+    .into_iter()
+    .chain(
+        destructuring
+            .pattern()
+            .variables()
+            .into_iter()
+            .map(|variable| {
+                ValueDefinition::new(
+                    variable.name(),
+                    Case::new(
+                        value.clone(),
+                        vec![Alternative::new(
+                            destructuring.pattern().clone(),
+                            variable.clone(),
+                        )],
+                        source_information.clone(),
+                    ),
+                    types::Variable::new(source_information.clone()),
+                    source_information.clone(),
+                )
+                .into()
+            }),
+    )
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug::*;
+
+    #[test]
+    fn sort_mixed_definitions() {
+        let function_definition: Definition = FunctionDefinition::new(
+            "f",
+            vec!["x".into()],
+            Variable::new("y", SourceInformation::dummy()),
+            types::Variable::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into();
+        let value_definition = |name: &str, expression: Expression| -> Definition {
+            ValueDefinition::new(
+                name,
+                expression,
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let z_definition = value_definition(
+            "z",
+            Application::new(
+                Variable::new("f", SourceInformation::dummy()),
+                Number::new(42.0, SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+        );
+        let y_definition =
+            value_definition("y", Number::new(42.0, SourceInformation::dummy()).into());
+
+        assert_eq!(
+            desugar_let_definitions(&Module::new(vec![value_definition(
+                "x",
+                Let::new(
+                    vec![
+                        z_definition.clone(),
+                        function_definition.clone(),
+                        y_definition.clone()
+                    ],
+                    Variable::new("z", SourceInformation::dummy())
+                )
+                .into()
+            )])),
+            Module::new(vec![value_definition(
+                "x",
+                Let::new(
+                    vec![y_definition],
+                    Let::new(
+                        vec![function_definition],
+                        Let::new(
+                            vec![z_definition],
+                            Variable::new("z", SourceInformation::dummy())
+                        )
+                    )
+                )
+                .into()
+            )])
+        );
+    }
+
+    #[test]
+    fn sort_destructured_definitions() {
+        let value_definition = |name: &str, expression: Expression| -> Definition {
+            ValueDefinition::new(
+                name,
+                expression,
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let variable = |name: &str| Variable::new(name, SourceInformation::dummy());
+        let pattern = TuplePattern::new(
+            vec![variable("a").into(), variable("b").into()],
+            SourceInformation::dummy(),
+        );
+        let element_definition = |name: &str| {
+            value_definition(
+                name,
+                Case::new(
+                    variable("destructured_value_0"),
+                    vec![Alternative::new(pattern.clone(), variable(name))],
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            )
+        };
+        let c_definition =
+            value_definition("c", Number::new(5.0, SourceInformation::dummy()).into());
+        let tuple: Expression = Tuple::new(
+            vec![
+                variable("c").into(),
+                Number::new(1.0, SourceInformation::dummy()).into(),
+            ],
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            desugar_let_definitions(&Module::new(vec![value_definition(
+                "x",
+                Let::with_destructurings(
+                    vec![c_definition.clone()],
+                    vec![Destructuring::new(
+                        pattern.clone(),
+                        tuple.clone(),
+                        SourceInformation::dummy()
+                    )],
+                    variable("a")
+                )
+                .into()
+            )])),
+            Module::new(vec![value_definition(
+                "x",
+                Let::new(
+                    vec![c_definition],
+                    Let::new(
+                        vec![value_definition("destructured_value_0", tuple)],
+                        Let::new(
+                            vec![element_definition("a")],
+                            Let::new(vec![element_definition("b")], variable("a"))
+                        )
+                    )
+                )
+                .into()
+            )])
+        );
+    }
+
+    #[test]
+    fn keep_mutually_recursive_definitions_together() {
+        let function_definition = |name: &str, other_name: &str| -> Definition {
+            FunctionDefinition::new(
+                name,
+                vec!["x".into()],
+                Application::new(
+                    Variable::new(other_name, SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Let::new(
+                vec![function_definition("f", "g"), function_definition("g", "f")],
+                Variable::new("f", SourceInformation::dummy()),
+            ),
+            types::Variable::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(desugar_let_definitions(&module), module);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::ast::*;
+use crate::types::{self, Type};
+
+pub use core::compile::MAIN_FUNCTION_NAME;
+
+const MAIN_NAME: &str = "main";
+
+pub fn desugar_main_function(module: &Module) -> Module {
+    let definitions = module
+        .definitions()
+        .iter()
+        .map(|definition| match definition {
+            Definition::FunctionDefinition(function_definition)
+                if function_definition.name() == MAIN_NAME =>
+            {
+                FunctionDefinition::new(
+                    function_definition.name(),
+                    function_definition.arguments().to_vec(),
+                    function_definition.body().clone(),
+                    convert_type(function_definition.type_()),
+                    function_definition.source_information().clone(),
+                )
+                .into()
+            }
+            Definition::ValueDefinition(value_definition)
+                if value_definition.name() == MAIN_NAME =>
+            {
+                ValueDefinition::new(
+                    value_definition.name(),
+                    value_definition.body().clone(),
+                    convert_type(value_definition.type_()),
+                    value_definition.source_information().clone(),
+                )
+                .into()
+            }
+            _ => definition.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    Module::with_type_aliases(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        match definitions
+            .iter()
+            .find(|definition| definition.name() == MAIN_NAME)
+        {
+            Some(definition) => definitions
+                .iter()
+                .cloned()
+                .chain(vec![create_main_function(definition)])
+                .collect(),
+            None => definitions,
+        },
+    )
+}
+
+fn convert_type(type_: &Type) -> Type {
+    if type_
+        .variables()
+        .iter()
+        .any(|variable| variable.name().is_none())
+    {
+        let source_information = type_.source_information();
+
+        types::Function::new(
+            types::Number::new(source_information.clone()),
+            types::Variable::new(source_information.clone()),
+            source_information.clone(),
+        )
+        .into()
+    } else {
+        type_.clone()
+    }
+}
+
+fn create_main_function(definition: &Definition) -> Definition {
+    let source_information = definition.source_information();
+
+    FunctionDefinition::new(
+        MAIN_FUNCTION_NAME,
+        vec!["x".into()],
+        Conversion::new(
+            types::String::new(source_information.clone()),
+            Application::new(
+                Variable::new(MAIN_NAME, source_information.clone()),
+                Variable::new("x", source_information.clone()),
+                source_information.clone(),
+            ),
+            source_information.clone(),
+        ),
+        types::Function::new(
+            types::Number::new(source_information.clone()),
+            types::String::new(source_information.clone()),
+            source_information.clone(),
+        ),
+        source_information.clone(),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug::*;
+
+    fn main_function() -> Definition {
+        FunctionDefinition::new(
+            "sloth_main",
+            vec!["x".into()],
+            Conversion::new(
+                types::String::new(SourceInformation::dummy()),
+                Application::new(
+                    Variable::new("main", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::String::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn add_main_function_for_function_definition() {
+        let definition: Definition = FunctionDefinition::new(
+            "main",
+            vec!["x".into()],
+            Number::new(42.0, SourceInformation::dummy()),
+            types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            desugar_main_function(&Module::new(vec![definition.clone()])),
+            Module::new(vec![definition, main_function()])
+        );
+    }
+
+    #[test]
+    fn add_main_function_for_value_definition() {
+        let definition: Definition = ValueDefinition::new(
+            "main",
+            Variable::new("f", SourceInformation::dummy()),
+            types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::String::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            desugar_main_function(&Module::new(vec![definition.clone()])),
+            Module::new(vec![definition, main_function()])
+        );
+    }
+
+    #[test]
+    fn convert_type_of_untyped_main_function() {
+        let source_information = SourceInformation::dummy();
+
+        assert_eq!(
+            desugar_main_function(&Module::new(vec![FunctionDefinition::new(
+                "main",
+                vec!["x".into()],
+                Number::new(42.0, source_information.clone()),
+                types::Function::new(
+                    types::Variable::new(source_information.clone()),
+                    types::Variable::new(source_information.clone()),
+                    source_information.clone(),
+                ),
+                source_information.clone(),
+            )
+            .into()])),
+            Module::new(vec![
+                FunctionDefinition::new(
+                    "main",
+                    vec!["x".into()],
+                    Number::new(42.0, source_information.clone()),
+                    types::Function::new(
+                        types::Number::new(source_information.clone()),
+                        types::Variable::new(source_information.clone()),
+                        source_information.clone(),
+                    ),
+                    source_information,
+                )
+                .into(),
+                main_function()
+            ])
+        );
+    }
+
+    #[test]
+    fn do_not_add_main_function_without_main_definition() {
+        let module = Module::new(vec![FunctionDefinition::new(
+            "mainish",
+            vec!["x".into()],
+            Number::new(42.0, SourceInformation::dummy()),
+            types::Function::new(
+                types::Variable::new(SourceInformation::dummy()),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(desugar_main_function(&module), module);
+    }
+}
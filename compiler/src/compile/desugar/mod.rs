@@ -1,16 +1,24 @@
 mod argument_omission;
-mod main_function_name;
+mod function_clauses;
+mod lambda;
+mod let_definitions;
+mod main_function;
 mod non_variable_application;
 
 use crate::ast::*;
 use argument_omission::*;
-use main_function_name::*;
+use function_clauses::*;
+use lambda::*;
+use let_definitions::*;
+use main_function::*;
 use non_variable_application::*;
 
 pub fn desugar_without_types(module: &Module) -> Module {
-    desugar_non_variable_applications(&desugar_main_function_name(module))
+    desugar_let_definitions(&desugar_non_variable_applications(&desugar_main_function(
+        &desugar_function_clauses(module),
+    )))
 }
 
 pub fn desugar_with_types(module: &Module) -> Module {
-    desugar_argument_omission(module)
+    desugar_argument_omission(&desugar_lambdas(module))
 }
@@ -7,14 +7,17 @@ pub fn desugar_non_variable_applications(module: &Module) -> Module {
 
     module.convert_expressions(&mut |expression| match expression {
         Expression::Application(application) => match application.function() {
-            Expression::Let(let_) => {
+            Expression::Case(_)
+            | Expression::If(_)
+            | Expression::Lambda(_)
+            | Expression::Let(_) => {
                 let function_name = name_generator.generate();
                 let source_information = application.source_information();
 
                 Let::new(
                     vec![ValueDefinition::new(
                         function_name.clone(),
-                        let_.clone(),
+                        application.function().clone(),
                         types::Variable::new(source_information.clone()),
                         source_information.clone(),
                     )
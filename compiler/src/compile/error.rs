@@ -1,3 +1,5 @@
+use super::monomorphization::MonomorphizationError;
+use super::pattern_check::PatternCheckError;
 use super::type_inference::TypeInferenceError;
 use crate::debug::*;
 use std::error::Error;
@@ -38,6 +40,18 @@ impl From<io::Error> for CompileError {
     }
 }
 
+impl From<MonomorphizationError> for CompileError {
+    fn from(error: MonomorphizationError) -> Self {
+        CompileError::External(error.into())
+    }
+}
+
+impl From<PatternCheckError> for CompileError {
+    fn from(error: PatternCheckError) -> Self {
+        CompileError::External(error.into())
+    }
+}
+
 impl From<TypeInferenceError> for CompileError {
     fn from(error: TypeInferenceError) -> Self {
         CompileError::External(error.into())
@@ -48,6 +62,8 @@ impl From<TypeInferenceError> for CompileError {
 pub enum ExternalCompileError {
     CoreCompileError(core::compile::CompileError),
     IOError(io::Error),
+    MonomorphizationError(MonomorphizationError),
+    PatternCheckError(PatternCheckError),
     TypeInferenceError(TypeInferenceError),
 }
 
@@ -58,6 +74,8 @@ impl Display for ExternalCompileError {
                 write!(formatter, "CoreCompileError: {}", error)
             }
             ExternalCompileError::IOError(error) => write!(formatter, "IOError: {}", error),
+            ExternalCompileError::MonomorphizationError(error) => write!(formatter, "{}", error),
+            ExternalCompileError::PatternCheckError(error) => write!(formatter, "{}", error),
             ExternalCompileError::TypeInferenceError(error) => write!(formatter, "{}", error),
         }
     }
@@ -71,6 +89,14 @@ impl PartialEq for ExternalCompileError {
                 ExternalCompileError::CoreCompileError(_),
             ) => true,
             (ExternalCompileError::IOError(_), ExternalCompileError::IOError(_)) => true,
+            (
+                ExternalCompileError::MonomorphizationError(_),
+                ExternalCompileError::MonomorphizationError(_),
+            ) => true,
+            (
+                ExternalCompileError::PatternCheckError(_),
+                ExternalCompileError::PatternCheckError(_),
+            ) => true,
             (
                 ExternalCompileError::TypeInferenceError(_),
                 ExternalCompileError::TypeInferenceError(_),
@@ -92,6 +118,18 @@ impl From<io::Error> for ExternalCompileError {
     }
 }
 
+impl From<MonomorphizationError> for ExternalCompileError {
+    fn from(error: MonomorphizationError) -> Self {
+        ExternalCompileError::MonomorphizationError(error)
+    }
+}
+
+impl From<PatternCheckError> for ExternalCompileError {
+    fn from(error: PatternCheckError) -> Self {
+        ExternalCompileError::PatternCheckError(error)
+    }
+}
+
 impl From<TypeInferenceError> for ExternalCompileError {
     fn from(error: TypeInferenceError) -> Self {
         ExternalCompileError::TypeInferenceError(error)
@@ -101,6 +139,7 @@ impl From<TypeInferenceError> for ExternalCompileError {
 #[derive(Debug, PartialEq)]
 pub enum InternalCompileError {
     MixedDefinitionsInLet(Rc<SourceInformation>),
+    TypeVariableNotCompiled(Rc<SourceInformation>),
 }
 
 impl Display for InternalCompileError {
@@ -111,6 +150,11 @@ impl Display for InternalCompileError {
                 "CompileError: Cannot mix function and value definitions in a let expression\n{}",
                 source_information
             ),
+            InternalCompileError::TypeVariableNotCompiled(source_information) => write!(
+                formatter,
+                "CompileError: Cannot compile polymorphic type\n{}",
+                source_information
+            ),
         }
     }
 }
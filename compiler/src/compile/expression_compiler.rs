@@ -1,17 +1,29 @@
 use super::error::{CompileError, InternalCompileError};
 use super::free_variable_finder::FreeVariableFinder;
+use super::name_generator::NameGenerator;
 use super::type_compiler::TypeCompiler;
 use crate::ast;
 use crate::types::Type;
+use std::cell::RefCell;
 use std::collections::HashMap;
 
+type Clause<'b> = (
+    Vec<(String, Type, &'b ast::Pattern)>,
+    Vec<(String, String, Type)>,
+    &'b ast::Expression,
+);
+
 pub struct ExpressionCompiler<'a> {
     type_compiler: &'a TypeCompiler,
+    name_generator: RefCell<NameGenerator>,
 }
 
 impl<'a> ExpressionCompiler<'a> {
     pub fn new(type_compiler: &'a TypeCompiler) -> Self {
-        Self { type_compiler }
+        Self {
+            type_compiler,
+            name_generator: RefCell::new(NameGenerator::new("generated_variable_")),
+        }
     }
 
     pub fn compile(
@@ -29,11 +41,16 @@ impl<'a> ExpressionCompiler<'a> {
                     arguments.push(application.argument());
                 }
 
+                if let ast::Expression::Constructor(constructor) = function {
+                    return self.compile_constructor(
+                        constructor,
+                        arguments.into_iter().rev().collect(),
+                        variables,
+                    );
+                }
+
                 Ok(core::ast::Application::new(
-                    self.compile(function, variables)?
-                        .to_variable()
-                        .expect("variable")
-                        .clone(),
+                    self.compile(function, variables)?,
                     arguments
                         .iter()
                         .rev()
@@ -42,6 +59,23 @@ impl<'a> ExpressionCompiler<'a> {
                 )
                 .into())
             }
+            ast::Expression::Boolean(boolean) => {
+                Ok(core::ast::Expression::Boolean(boolean.value()))
+            }
+            ast::Expression::CharLiteral(char_literal) => {
+                Ok(core::ast::Expression::Char(char_literal.value()))
+            }
+            ast::Expression::Case(case) => self.compile_case(case, variables),
+            ast::Expression::Constructor(constructor) => {
+                self.compile_constructor(constructor, vec![], variables)
+            }
+            ast::Expression::If(if_) => Ok(core::ast::If::new(
+                self.compile(if_.condition(), variables)?,
+                self.compile(if_.then(), variables)?,
+                self.compile(if_.else_(), variables)?,
+            )
+            .into()),
+            ast::Expression::Lambda(_) => unreachable!(),
             ast::Expression::Let(let_) => match let_.definitions()[0] {
                 ast::Definition::FunctionDefinition(_) => {
                     Ok(self.compile_let_functions(let_, variables)?.into())
@@ -50,11 +84,108 @@ impl<'a> ExpressionCompiler<'a> {
                     Ok(self.compile_let_values(let_, variables)?.into())
                 }
             },
-            ast::Expression::Number(number) => Ok(core::ast::Expression::Number(number.value())),
-            ast::Expression::Operation(operation) => Ok(core::ast::Operation::new(
-                operation.operator().into(),
-                self.compile(operation.lhs(), variables)?,
-                self.compile(operation.rhs(), variables)?,
+            ast::Expression::Match(_) => unreachable!(),
+            ast::Expression::EmptyListFailure(empty_list_failure) => {
+                Ok(core::ast::EmptyListFailure::new(
+                    self.type_compiler.compile(empty_list_failure.type_())?,
+                )
+                .into())
+            }
+            ast::Expression::MatchFailure(match_failure) => Ok(core::ast::MatchFailure::new(
+                self.type_compiler.compile(match_failure.type_())?,
+            )
+            .into()),
+            ast::Expression::BuiltInCall(built_in_call) => Ok(core::ast::BuiltInCall::new(
+                built_in_call.function().into(),
+                built_in_call
+                    .arguments()
+                    .iter()
+                    .map(|argument| self.compile(argument, variables))
+                    .collect::<Result<_, _>>()?,
+            )
+            .into()),
+            ast::Expression::Conversion(conversion) => Ok(core::ast::Conversion::new(
+                self.type_compiler.compile_value(conversion.type_())?,
+                self.compile(conversion.expression(), variables)?,
+            )
+            .into()),
+            ast::Expression::Negation(negation) => Ok(core::ast::Negation::new(
+                self.compile(negation.expression(), variables)?,
+            )
+            .into()),
+            ast::Expression::Number(number) => Ok(self.compile_number(number)),
+            ast::Expression::Operation(operation) => {
+                let lhs = self.compile(operation.lhs(), variables)?;
+                let rhs = self.compile(operation.rhs(), variables)?;
+
+                Ok(match operation.operator() {
+                    ast::Operator::And => core::ast::If::new(lhs, rhs, false).into(),
+                    ast::Operator::Or => core::ast::If::new(lhs, true, rhs).into(),
+                    _ => core::ast::Operation::new(operation.operator().into(), lhs, rhs).into(),
+                })
+            }
+            ast::Expression::Record(record) => Ok(core::ast::Record::new(
+                self.type_compiler
+                    .compile_record(record.type_().to_record().expect("record type"))?,
+                record
+                    .elements()
+                    .values()
+                    .map(|element| self.compile(element, variables))
+                    .collect::<Result<_, _>>()?,
+            )
+            .into()),
+            ast::Expression::RecordElement(record_element) => Ok(core::ast::RecordElement::new(
+                self.compile(record_element.record(), variables)?,
+                record_element
+                    .type_()
+                    .to_record()
+                    .expect("record type")
+                    .elements()
+                    .keys()
+                    .position(|name| name == record_element.name())
+                    .expect("record element"),
+            )
+            .into()),
+            ast::Expression::RecordUpdate(record_update) => {
+                let type_ = record_update.type_().to_record().expect("record type");
+                let name = self.name_generator.borrow_mut().generate();
+
+                Ok(core::ast::LetValues::new(
+                    vec![core::ast::ValueDefinition::new(
+                        &name,
+                        self.compile(record_update.record(), variables)?,
+                        self.type_compiler.compile(record_update.type_())?,
+                    )],
+                    core::ast::Record::new(
+                        self.type_compiler.compile_record(type_)?,
+                        type_
+                            .elements()
+                            .keys()
+                            .enumerate()
+                            .map(|(index, element_name)| {
+                                match record_update.elements().get(element_name) {
+                                    Some(element) => self.compile(element, variables),
+                                    None => Ok(core::ast::RecordElement::new(
+                                        core::ast::Variable::new(&name),
+                                        index,
+                                    )
+                                    .into()),
+                                }
+                            })
+                            .collect::<Result<_, _>>()?,
+                    ),
+                )
+                .into())
+            }
+            ast::Expression::StringLiteral(string_literal) => {
+                Ok(core::ast::Expression::String(string_literal.value().into()))
+            }
+            ast::Expression::Tuple(tuple) => Ok(core::ast::Tuple::new(
+                tuple
+                    .elements()
+                    .iter()
+                    .map(|element| self.compile(element, variables))
+                    .collect::<Result<_, _>>()?,
             )
             .into()),
             ast::Expression::Variable(variable) => Ok(core::ast::Expression::Variable(
@@ -63,6 +194,408 @@ impl<'a> ExpressionCompiler<'a> {
         }
     }
 
+    fn compile_number(&self, number: &ast::Number) -> core::ast::Expression {
+        match number.type_() {
+            Type::Int(_) => {
+                core::ast::Expression::Int(number.integer().unwrap_or(number.value() as i64))
+            }
+            _ => core::ast::Expression::Number(number.value()),
+        }
+    }
+
+    fn is_same_number(one: &ast::Number, other: &ast::Number) -> bool {
+        one.value() == other.value() && one.integer() == other.integer()
+    }
+
+    fn compile_case(
+        &self,
+        case: &ast::Case,
+        variables: &HashMap<String, Type>,
+    ) -> Result<core::ast::Expression, CompileError> {
+        let argument_name = self.name_generator.borrow_mut().generate();
+
+        Ok(core::ast::LetValues::new(
+            vec![core::ast::ValueDefinition::new(
+                &argument_name,
+                self.compile(case.argument(), variables)?,
+                self.type_compiler.compile(case.type_())?,
+            )],
+            self.compile_clauses(
+                case.alternatives()
+                    .iter()
+                    .map(|alternative| {
+                        let mut clause = (vec![], vec![], alternative.expression());
+                        Self::add_pattern(
+                            &mut clause,
+                            &argument_name,
+                            case.type_(),
+                            alternative.pattern(),
+                        );
+                        clause
+                    })
+                    .collect(),
+                variables,
+            )?,
+        )
+        .into())
+    }
+
+    fn compile_clauses(
+        &self,
+        clauses: Vec<Clause>,
+        variables: &HashMap<String, Type>,
+    ) -> Result<core::ast::Expression, CompileError> {
+        let (patterns, bindings, expression) = &clauses[0];
+
+        match patterns.first() {
+            None => {
+                let expression = self.compile(
+                    expression,
+                    &variables
+                        .iter()
+                        .map(|(name, type_)| (name.clone(), type_.clone()))
+                        .chain(
+                            bindings
+                                .iter()
+                                .map(|(name, _, type_)| (name.clone(), type_.clone())),
+                        )
+                        .collect(),
+                )?;
+
+                if bindings.is_empty() {
+                    return Ok(expression);
+                }
+
+                Ok(core::ast::LetValues::new(
+                    bindings
+                        .iter()
+                        .map(|(name, occurrence, type_)| {
+                            Ok(core::ast::ValueDefinition::new(
+                                name,
+                                core::ast::Variable::new(occurrence),
+                                self.type_compiler.compile(type_)?,
+                            ))
+                        })
+                        .collect::<Result<_, CompileError>>()?,
+                    expression,
+                )
+                .into())
+            }
+            Some((occurrence, type_, pattern)) => match pattern {
+                ast::Pattern::Boolean(_) => Ok(core::ast::If::new(
+                    core::ast::Variable::new(occurrence),
+                    self.compile_clauses(
+                        Self::specialize_clauses(&clauses, occurrence, |pattern| match pattern {
+                            ast::Pattern::Boolean(boolean) if boolean.value() => Some(vec![]),
+                            _ => None,
+                        }),
+                        variables,
+                    )?,
+                    self.compile_clauses(
+                        Self::specialize_clauses(&clauses, occurrence, |pattern| match pattern {
+                            ast::Pattern::Boolean(boolean) if !boolean.value() => Some(vec![]),
+                            _ => None,
+                        }),
+                        variables,
+                    )?,
+                )
+                .into()),
+                ast::Pattern::Constructor(_) => {
+                    self.compile_algebraic_clauses(&clauses, occurrence, type_, variables)
+                }
+                ast::Pattern::Number(number) => Ok(core::ast::If::new(
+                    core::ast::Operation::new(
+                        core::ast::Operator::Equal,
+                        core::ast::Variable::new(occurrence),
+                        self.compile_number(number),
+                    ),
+                    self.compile_clauses(
+                        Self::specialize_clauses(&clauses, occurrence, |pattern| match pattern {
+                            ast::Pattern::Number(other) if Self::is_same_number(other, number) => {
+                                Some(vec![])
+                            }
+                            _ => None,
+                        }),
+                        variables,
+                    )?,
+                    self.compile_clauses(
+                        clauses
+                            .iter()
+                            .filter(|(patterns, _, _)| {
+                                !patterns.iter().any(|(other_occurrence, _, pattern)| {
+                                    other_occurrence == occurrence
+                                        && match pattern {
+                                            ast::Pattern::Number(other) => {
+                                                Self::is_same_number(other, number)
+                                            }
+                                            _ => false,
+                                        }
+                                })
+                            })
+                            .cloned()
+                            .collect(),
+                        variables,
+                    )?,
+                )
+                .into()),
+                ast::Pattern::Tuple(_) => {
+                    self.compile_tuple_clauses(&clauses, occurrence, type_, variables)
+                }
+                ast::Pattern::Variable(_) | ast::Pattern::Wildcard(_) => unreachable!(),
+            },
+        }
+    }
+
+    fn compile_algebraic_clauses(
+        &self,
+        clauses: &[Clause],
+        occurrence: &str,
+        type_: &Type,
+        variables: &HashMap<String, Type>,
+    ) -> Result<core::ast::Expression, CompileError> {
+        let type_definition = self.type_compiler.find_type_definition(type_);
+        let algebraic_type = self.type_compiler.compile_algebraic(type_)?;
+        let mut alternatives = vec![];
+
+        for (index, constructor) in type_definition.constructors().iter().enumerate() {
+            let is_matched = |pattern: &ast::Pattern| match pattern {
+                ast::Pattern::Constructor(constructor_pattern) => {
+                    constructor_pattern.name() == constructor.name()
+                }
+                _ => false,
+            };
+
+            if !clauses.iter().any(|(patterns, _, _)| {
+                patterns.iter().any(|(other_occurrence, _, pattern)| {
+                    other_occurrence == occurrence && is_matched(pattern)
+                })
+            }) {
+                continue;
+            }
+
+            let element_names = constructor
+                .elements()
+                .iter()
+                .map(|_| self.name_generator.borrow_mut().generate())
+                .collect::<Vec<_>>();
+
+            alternatives.push(core::ast::Alternative::new(
+                core::ast::Constructor::new(algebraic_type.clone(), index),
+                element_names.clone(),
+                self.compile_clauses(
+                    Self::specialize_clauses(clauses, occurrence, |pattern| match pattern {
+                        ast::Pattern::Constructor(constructor_pattern) if is_matched(pattern) => {
+                            Some(
+                                element_names
+                                    .iter()
+                                    .cloned()
+                                    .zip(constructor.elements().iter().cloned())
+                                    .zip(constructor_pattern.arguments())
+                                    .map(|((name, type_), pattern)| (name, type_, pattern))
+                                    .collect(),
+                            )
+                        }
+                        _ => None,
+                    }),
+                    variables,
+                )?,
+            ));
+        }
+
+        let default_alternative = if alternatives.len() == type_definition.constructors().len() {
+            None
+        } else {
+            Some(self.compile_clauses(
+                Self::specialize_clauses(clauses, occurrence, |_| None),
+                variables,
+            )?)
+        };
+
+        Ok(core::ast::Case::new(
+            core::ast::Variable::new(occurrence),
+            alternatives,
+            default_alternative,
+        )
+        .into())
+    }
+
+    fn compile_tuple_clauses(
+        &self,
+        clauses: &[Clause],
+        occurrence: &str,
+        type_: &Type,
+        variables: &HashMap<String, Type>,
+    ) -> Result<core::ast::Expression, CompileError> {
+        let element_types = match type_ {
+            Type::Tuple(tuple) => tuple.elements(),
+            _ => unreachable!(),
+        };
+        let element_names = element_types
+            .iter()
+            .map(|_| self.name_generator.borrow_mut().generate())
+            .collect::<Vec<_>>();
+
+        Ok(core::ast::LetValues::new(
+            element_names
+                .iter()
+                .zip(element_types)
+                .enumerate()
+                .map(|(index, (name, type_))| {
+                    Ok(core::ast::ValueDefinition::new(
+                        name,
+                        core::ast::TupleElement::new(core::ast::Variable::new(occurrence), index),
+                        self.type_compiler.compile(type_)?,
+                    ))
+                })
+                .collect::<Result<_, CompileError>>()?,
+            self.compile_clauses(
+                Self::specialize_clauses(clauses, occurrence, |pattern| match pattern {
+                    ast::Pattern::Tuple(tuple_pattern) => Some(
+                        element_names
+                            .iter()
+                            .cloned()
+                            .zip(element_types.iter().cloned())
+                            .zip(tuple_pattern.elements())
+                            .map(|((name, type_), pattern)| (name, type_, pattern))
+                            .collect(),
+                    ),
+                    _ => None,
+                }),
+                variables,
+            )?,
+        )
+        .into())
+    }
+
+    fn specialize_clauses<'b>(
+        clauses: &[Clause<'b>],
+        occurrence: &str,
+        specialize: impl Fn(&'b ast::Pattern) -> Option<Vec<(String, Type, &'b ast::Pattern)>>,
+    ) -> Vec<Clause<'b>> {
+        clauses
+            .iter()
+            .filter_map(|clause| {
+                let (patterns, bindings, expression) = clause;
+
+                match patterns
+                    .iter()
+                    .position(|(other_occurrence, _, _)| other_occurrence == occurrence)
+                {
+                    Some(index) => {
+                        let mut clause = (patterns.clone(), bindings.clone(), *expression);
+                        let (_, _, pattern) = clause.0.remove(index);
+
+                        for (occurrence, type_, pattern) in specialize(pattern)? {
+                            Self::add_pattern(&mut clause, &occurrence, &type_, pattern);
+                        }
+
+                        Some(clause)
+                    }
+                    None => Some(clause.clone()),
+                }
+            })
+            .collect()
+    }
+
+    fn add_pattern<'b>(
+        clause: &mut Clause<'b>,
+        occurrence: &str,
+        type_: &Type,
+        pattern: &'b ast::Pattern,
+    ) {
+        match pattern {
+            ast::Pattern::Variable(variable) => {
+                clause
+                    .1
+                    .push((variable.name().into(), occurrence.into(), type_.clone()))
+            }
+            ast::Pattern::Wildcard(_) => {}
+            _ => clause.0.push((occurrence.into(), type_.clone(), pattern)),
+        }
+    }
+
+    fn compile_constructor(
+        &self,
+        constructor: &ast::Constructor,
+        arguments: Vec<&ast::Expression>,
+        variables: &HashMap<String, Type>,
+    ) -> Result<core::ast::Expression, CompileError> {
+        let type_ = self.type_compiler.find_algebraic_type(constructor);
+        let type_definition = self.type_compiler.find_type_definition(&type_);
+        let index = type_definition
+            .constructors()
+            .iter()
+            .position(|other| other.name() == constructor.name())
+            .expect("constructor");
+        let core_constructor =
+            core::ast::Constructor::new(self.type_compiler.compile_algebraic(&type_)?, index);
+        let elements = type_definition.constructors()[index].elements();
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.compile(argument, variables))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if arguments.len() == elements.len() {
+            return Ok(core::ast::ConstructorApplication::new(core_constructor, arguments).into());
+        }
+
+        let names = elements
+            .iter()
+            .map(|_| self.name_generator.borrow_mut().generate())
+            .collect::<Vec<_>>();
+        let function_name = self.name_generator.borrow_mut().generate();
+        let compile_arguments = |names: &[String], types: &[Type]| {
+            names
+                .iter()
+                .zip(types)
+                .map(|(name, type_)| {
+                    Ok(core::ast::Argument::new(
+                        name.clone(),
+                        self.type_compiler.compile(type_)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, CompileError>>()
+        };
+
+        let let_functions = core::ast::LetFunctions::new(
+            vec![core::ast::FunctionDefinition::new(
+                &function_name,
+                compile_arguments(&names[..arguments.len()], &elements[..arguments.len()])?,
+                compile_arguments(&names[arguments.len()..], &elements[arguments.len()..])?,
+                core::ast::ConstructorApplication::new(
+                    core_constructor,
+                    names
+                        .iter()
+                        .map(|name| core::ast::Variable::new(name).into())
+                        .collect(),
+                ),
+                self.type_compiler.compile_value(&type_)?,
+            )],
+            core::ast::Variable::new(function_name),
+        );
+
+        if arguments.is_empty() {
+            return Ok(let_functions.into());
+        }
+
+        Ok(core::ast::LetValues::new(
+            names
+                .iter()
+                .zip(elements)
+                .zip(arguments)
+                .map(|((name, type_), argument)| {
+                    Ok(core::ast::ValueDefinition::new(
+                        name,
+                        argument,
+                        self.type_compiler.compile(type_)?,
+                    ))
+                })
+                .collect::<Result<_, CompileError>>()?,
+            let_functions,
+        )
+        .into())
+    }
+
     fn compile_let_functions(
         &self,
         let_: &ast::Let,
@@ -106,27 +639,25 @@ impl<'a> ExpressionCompiler<'a> {
                         FreeVariableFinder::new()
                             .find(function_definition)
                             .iter()
-                            .map(|name| {
-                                variables.get(name).map(|type_| {
-                                    core::ast::Argument::new(
-                                        name.clone(),
-                                        self.type_compiler.compile(type_),
-                                    )
-                                })
+                            .filter_map(|name| variables.get(name).map(|type_| (name, type_)))
+                            .map(|(name, type_)| {
+                                Ok(core::ast::Argument::new(
+                                    name.clone(),
+                                    self.type_compiler.compile(type_)?,
+                                ))
                             })
-                            .collect::<Option<_>>()
-                            .unwrap_or_else(|| vec![]),
+                            .collect::<Result<_, CompileError>>()?,
                         function_definition
                             .arguments()
                             .iter()
                             .zip(type_.arguments())
                             .map(|(name, type_)| {
-                                core::ast::Argument::new(
+                                Ok(core::ast::Argument::new(
                                     name.clone(),
-                                    self.type_compiler.compile(type_),
-                                )
+                                    self.type_compiler.compile(type_)?,
+                                ))
                             })
-                            .collect(),
+                            .collect::<Result<_, CompileError>>()?,
                         self.compile(
                             function_definition.body(),
                             &variables
@@ -141,7 +672,7 @@ impl<'a> ExpressionCompiler<'a> {
                                 )
                                 .collect(),
                         )?,
-                        self.type_compiler.compile_value(type_.last_result()),
+                        self.type_compiler.compile(type_.last_result())?,
                     ))
                 })
                 .collect::<Result<Vec<_>, CompileError>>()?,
@@ -185,7 +716,7 @@ impl<'a> ExpressionCompiler<'a> {
                     Ok(core::ast::ValueDefinition::new(
                         value_definition.name(),
                         self.compile(value_definition.body(), variables)?,
-                        self.type_compiler.compile_value(value_definition.type_()),
+                        self.type_compiler.compile(value_definition.type_())?,
                     ))
                 })
                 .collect::<Result<Vec<_>, CompileError>>()?,
@@ -206,7 +737,7 @@ mod test {
     #[test]
     fn compile_operation() {
         assert_eq!(
-            ExpressionCompiler::new(&TypeCompiler::new()).compile(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
                 &Operation::new(
                     Operator::Add,
                     Number::new(1.0, SourceInformation::dummy()),
@@ -220,10 +751,73 @@ mod test {
         );
     }
 
+    #[test]
+    fn compile_negation() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
+                &Negation::new(
+                    Number::new(42.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::Negation::new(42.0).into())
+        );
+    }
+
+    #[test]
+    fn compile_if() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
+                &If::new(
+                    Boolean::new(true, SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::If::new(true, 1.0, 2.0).into())
+        );
+    }
+
+    #[test]
+    fn compile_boolean_operations() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
+                &Operation::new(
+                    Operator::And,
+                    Boolean::new(true, SourceInformation::dummy()),
+                    Boolean::new(false, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::If::new(true, false, false).into())
+        );
+
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
+                &Operation::new(
+                    Operator::Or,
+                    Boolean::new(false, SourceInformation::dummy()),
+                    Boolean::new(true, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::If::new(false, true, true).into())
+        );
+    }
+
     #[test]
     fn compile_let_values() {
         assert_eq!(
-            ExpressionCompiler::new(&TypeCompiler::new()).compile(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
                 &Let::new(
                     vec![ValueDefinition::new(
                         "x",
@@ -252,7 +846,7 @@ mod test {
     #[test]
     fn compile_let_functions() {
         assert_eq!(
-            ExpressionCompiler::new(&TypeCompiler::new()).compile(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
                 &Let::new(
                     vec![FunctionDefinition::new(
                         "f",
@@ -288,7 +882,7 @@ mod test {
     #[test]
     fn compile_let_functions_with_recursive_functions() {
         assert_eq!(
-            ExpressionCompiler::new(&TypeCompiler::new()).compile(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
                 &Let::new(
                     vec![FunctionDefinition::new(
                         "f",
@@ -337,7 +931,7 @@ mod test {
     #[test]
     fn compile_nested_let_functions() {
         assert_eq!(
-            ExpressionCompiler::new(&TypeCompiler::new()).compile(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
                 &Let::new(
                     vec![FunctionDefinition::new(
                         "f",
@@ -396,7 +990,7 @@ mod test {
     #[test]
     fn compile_let_values_with_free_variables() {
         assert_eq!(
-            ExpressionCompiler::new(&TypeCompiler::new()).compile(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
                 &Let::new(
                     vec![ValueDefinition::new(
                         "y",
@@ -444,4 +1038,345 @@ mod test {
             .into())
         );
     }
+
+    #[test]
+    fn compile_let_functions_with_global_variables() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
+                &Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        Operation::new(
+                            Operator::Add,
+                            Application::new(
+                                Variable::new("g", SourceInformation::dummy()),
+                                Variable::new("x", SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            ),
+                            Variable::new("y", SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        types::Function::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("f", SourceInformation::dummy())
+                )
+                .into(),
+                &vec![(
+                    "y".into(),
+                    types::Number::new(SourceInformation::dummy()).into()
+                )]
+                .into_iter()
+                .collect()
+            ),
+            Ok(core::ast::LetFunctions::new(
+                vec![core::ast::FunctionDefinition::new(
+                    "f",
+                    vec![core::ast::Argument::new("y", core::types::Value::Number)],
+                    vec![core::ast::Argument::new("x", core::types::Value::Number)],
+                    core::ast::Operation::new(
+                        core::ast::Operator::Add,
+                        core::ast::Application::new(
+                            core::ast::Variable::new("g"),
+                            vec![core::ast::Variable::new("x").into()]
+                        ),
+                        core::ast::Variable::new("y")
+                    ),
+                    core::types::Value::Number,
+                )],
+                core::ast::Variable::new("f")
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_partial_applications() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&Module::new(vec![]))).compile(
+                &Application::new(
+                    Variable::new("f", SourceInformation::dummy()),
+                    Number::new(42.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::Application::new(
+                core::ast::Variable::new("f"),
+                vec![core::ast::Expression::Number(42.0)]
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_records() {
+        let record_type: types::Type = types::Record::new(
+            vec![
+                (
+                    "x".into(),
+                    types::Number::new(SourceInformation::dummy()).into(),
+                ),
+                (
+                    "y".into(),
+                    types::Boolean::new(SourceInformation::dummy()).into(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            SourceInformation::dummy(),
+        )
+        .into();
+        let core_record_type = core::types::Record::new(vec![
+            core::types::Value::Number.into(),
+            core::types::Value::Boolean.into(),
+        ]);
+        let type_compiler = TypeCompiler::new(&Module::new(vec![]));
+        let expression_compiler = ExpressionCompiler::new(&type_compiler);
+
+        assert_eq!(
+            expression_compiler.compile(
+                &RecordElement::with_type(
+                    record_type.clone(),
+                    Record::with_type(
+                        record_type.clone(),
+                        vec![
+                            (
+                                "y".into(),
+                                Boolean::new(true, SourceInformation::dummy()).into()
+                            ),
+                            (
+                                "x".into(),
+                                Number::new(42.0, SourceInformation::dummy()).into()
+                            )
+                        ]
+                        .into_iter()
+                        .collect(),
+                        SourceInformation::dummy()
+                    ),
+                    "y",
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::RecordElement::new(
+                core::ast::Record::new(core_record_type.clone(), vec![42.0.into(), true.into()]),
+                1
+            )
+            .into())
+        );
+
+        assert_eq!(
+            expression_compiler.compile(
+                &RecordUpdate::with_type(
+                    record_type,
+                    Variable::new("r", SourceInformation::dummy()),
+                    vec![(
+                        "y".into(),
+                        Boolean::new(false, SourceInformation::dummy()).into()
+                    )]
+                    .into_iter()
+                    .collect(),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::LetValues::new(
+                vec![core::ast::ValueDefinition::new(
+                    "generated_variable_0",
+                    core::ast::Variable::new("r"),
+                    core_record_type.clone()
+                )],
+                core::ast::Record::new(
+                    core_record_type,
+                    vec![
+                        core::ast::RecordElement::new(
+                            core::ast::Variable::new("generated_variable_0"),
+                            0
+                        )
+                        .into(),
+                        false.into()
+                    ]
+                )
+            )
+            .into())
+        );
+    }
+
+    fn shape_module() -> Module {
+        Module::with_type_definitions(
+            vec![TypeDefinition::new(
+                "Shape",
+                vec![
+                    ConstructorDefinition::new(
+                        "Circle",
+                        vec![types::Number::new(SourceInformation::dummy()).into()],
+                        SourceInformation::dummy(),
+                    ),
+                    ConstructorDefinition::new(
+                        "Rect",
+                        vec![
+                            types::Number::new(SourceInformation::dummy()).into(),
+                            types::Number::new(SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                ],
+                SourceInformation::dummy(),
+            )],
+            vec![],
+        )
+    }
+
+    fn shape_type() -> core::types::Algebraic {
+        core::types::Algebraic::new(vec![
+            core::types::Constructor::new(vec![core::types::Value::Number.into()]),
+            core::types::Constructor::new(vec![
+                core::types::Value::Number.into(),
+                core::types::Value::Number.into(),
+            ]),
+        ])
+    }
+
+    #[test]
+    fn compile_constructor_applications() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&shape_module())).compile(
+                &Application::new(
+                    Application::new(
+                        Constructor::new("Rect", SourceInformation::dummy()),
+                        Number::new(1.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::ConstructorApplication::new(
+                core::ast::Constructor::new(shape_type(), 1),
+                vec![1.0.into(), 2.0.into()]
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_partially_applied_constructors() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&shape_module())).compile(
+                &Application::new(
+                    Constructor::new("Rect", SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::LetValues::new(
+                vec![core::ast::ValueDefinition::new(
+                    "generated_variable_0",
+                    1.0,
+                    core::types::Value::Number,
+                )],
+                core::ast::LetFunctions::new(
+                    vec![core::ast::FunctionDefinition::new(
+                        "generated_variable_2",
+                        vec![core::ast::Argument::new(
+                            "generated_variable_0",
+                            core::types::Value::Number
+                        )],
+                        vec![core::ast::Argument::new(
+                            "generated_variable_1",
+                            core::types::Value::Number
+                        )],
+                        core::ast::ConstructorApplication::new(
+                            core::ast::Constructor::new(shape_type(), 1),
+                            vec![
+                                core::ast::Variable::new("generated_variable_0").into(),
+                                core::ast::Variable::new("generated_variable_1").into()
+                            ]
+                        ),
+                        core::types::Value::from(shape_type()),
+                    )],
+                    core::ast::Variable::new("generated_variable_2")
+                )
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_case() {
+        assert_eq!(
+            ExpressionCompiler::new(&TypeCompiler::new(&shape_module())).compile(
+                &Case::with_type(
+                    types::Reference::new("Shape", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    vec![
+                        Alternative::new(
+                            ConstructorPattern::new(
+                                "Rect",
+                                vec![
+                                    Number::new(1.0, SourceInformation::dummy()).into(),
+                                    Variable::new("y", SourceInformation::dummy()).into()
+                                ],
+                                SourceInformation::dummy()
+                            ),
+                            Variable::new("y", SourceInformation::dummy())
+                        ),
+                        Alternative::new(
+                            Wildcard::new(SourceInformation::dummy()),
+                            Number::new(42.0, SourceInformation::dummy())
+                        )
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into(),
+                &HashMap::new()
+            ),
+            Ok(core::ast::LetValues::new(
+                vec![core::ast::ValueDefinition::new(
+                    "generated_variable_0",
+                    core::ast::Variable::new("x"),
+                    shape_type(),
+                )],
+                core::ast::Case::new(
+                    core::ast::Variable::new("generated_variable_0"),
+                    vec![core::ast::Alternative::new(
+                        core::ast::Constructor::new(shape_type(), 1),
+                        vec!["generated_variable_1".into(), "generated_variable_2".into()],
+                        core::ast::If::new(
+                            core::ast::Operation::new(
+                                core::ast::Operator::Equal,
+                                core::ast::Variable::new("generated_variable_1"),
+                                1.0
+                            ),
+                            core::ast::LetValues::new(
+                                vec![core::ast::ValueDefinition::new(
+                                    "y",
+                                    core::ast::Variable::new("generated_variable_2"),
+                                    core::types::Value::Number,
+                                )],
+                                core::ast::Variable::new("y")
+                            ),
+                            42.0
+                        )
+                    )],
+                    Some(42.0.into())
+                )
+            )
+            .into())
+        );
+    }
 }
@@ -9,7 +9,30 @@ impl FreeVariableFinder {
     }
 
     pub fn find(&self, function_definition: &ast::FunctionDefinition) -> Vec<String> {
-        self.find_in_function_definition(function_definition, &HashSet::new())
+        Self::deduplicate(self.find_in_function_definition(function_definition, &HashSet::new()))
+    }
+
+    pub fn find_in_definition(&self, definition: &ast::Definition) -> Vec<String> {
+        match definition {
+            ast::Definition::FunctionDefinition(function_definition) => {
+                self.find(function_definition)
+            }
+            ast::Definition::ValueDefinition(value_definition) => {
+                Self::deduplicate(self.find_in_expression(value_definition.body(), &HashSet::new()))
+            }
+        }
+    }
+
+    fn deduplicate(names: Vec<String>) -> Vec<String> {
+        let mut unique_names = vec![];
+
+        for name in names {
+            if !unique_names.contains(&name) {
+                unique_names.push(name);
+            }
+        }
+
+        unique_names
     }
 
     fn find_in_function_definition(
@@ -38,6 +61,43 @@ impl FreeVariableFinder {
                 .into_iter()
                 .chain(self.find_in_expression(application.argument(), variables))
                 .collect(),
+            ast::Expression::Boolean(_)
+            | ast::Expression::CharLiteral(_)
+            | ast::Expression::Constructor(_) => vec![],
+            ast::Expression::Case(case) => self
+                .find_in_expression(case.argument(), variables)
+                .into_iter()
+                .chain(case.alternatives().iter().flat_map(|alternative| {
+                    self.find_in_expression(
+                        alternative.expression(),
+                        &variables
+                            .iter()
+                            .cloned()
+                            .chain(
+                                alternative
+                                    .pattern()
+                                    .variables()
+                                    .iter()
+                                    .map(|variable| variable.name().into()),
+                            )
+                            .collect(),
+                    )
+                }))
+                .collect(),
+            ast::Expression::If(if_) => self
+                .find_in_expression(if_.condition(), variables)
+                .into_iter()
+                .chain(self.find_in_expression(if_.then(), variables))
+                .chain(self.find_in_expression(if_.else_(), variables))
+                .collect(),
+            ast::Expression::Lambda(lambda) => self.find_in_expression(
+                lambda.body(),
+                &variables
+                    .iter()
+                    .cloned()
+                    .chain(lambda.arguments().iter().cloned())
+                    .collect(),
+            ),
             ast::Expression::Let(let_) => {
                 let mut variables = variables.clone();
                 let mut free_variables = vec![];
@@ -69,7 +129,45 @@ impl FreeVariableFinder {
 
                 free_variables
             }
-            ast::Expression::Number(_) => vec![],
+            ast::Expression::Match(_) => unreachable!(),
+            ast::Expression::BuiltInCall(built_in_call) => built_in_call
+                .arguments()
+                .iter()
+                .flat_map(|argument| self.find_in_expression(argument, variables))
+                .collect(),
+            ast::Expression::Record(record) => record
+                .elements()
+                .values()
+                .flat_map(|element| self.find_in_expression(element, variables))
+                .collect(),
+            ast::Expression::RecordElement(record_element) => {
+                self.find_in_expression(record_element.record(), variables)
+            }
+            ast::Expression::RecordUpdate(record_update) => self
+                .find_in_expression(record_update.record(), variables)
+                .into_iter()
+                .chain(
+                    record_update
+                        .elements()
+                        .values()
+                        .flat_map(|element| self.find_in_expression(element, variables)),
+                )
+                .collect(),
+            ast::Expression::Tuple(tuple) => tuple
+                .elements()
+                .iter()
+                .flat_map(|element| self.find_in_expression(element, variables))
+                .collect(),
+            ast::Expression::Conversion(conversion) => {
+                self.find_in_expression(conversion.expression(), variables)
+            }
+            ast::Expression::Negation(negation) => {
+                self.find_in_expression(negation.expression(), variables)
+            }
+            ast::Expression::EmptyListFailure(_)
+            | ast::Expression::MatchFailure(_)
+            | ast::Expression::Number(_)
+            | ast::Expression::StringLiteral(_) => vec![],
             ast::Expression::Operation(operation) => self
                 .find_in_expression(operation.lhs(), variables)
                 .into_iter()
@@ -246,4 +344,36 @@ mod test {
             vec!["z".to_string()]
         );
     }
+
+    #[test]
+    fn find_free_variables_in_lambdas() {
+        assert_eq!(
+            FreeVariableFinder::new().find(&FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Lambda::new(
+                    vec!["y".into()],
+                    Operation::new(
+                        Operator::Add,
+                        Operation::new(
+                            Operator::Add,
+                            Variable::new("y", SourceInformation::dummy()),
+                            Variable::new("z", SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        Variable::new("z", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                ),
+                types::Function::new(
+                    types::Variable::new(SourceInformation::dummy()),
+                    types::Variable::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                ),
+                SourceInformation::dummy()
+            )),
+            vec!["z".to_string()]
+        );
+    }
 }
@@ -1,9 +1,13 @@
+mod definition_sorter;
 mod desugar;
 mod error;
 mod expression_compiler;
 mod free_variable_finder;
 mod module_compiler;
+mod monomorphization;
 mod name_generator;
+mod pattern_check;
+mod prelude;
 mod type_compiler;
 mod type_inference;
 
@@ -11,15 +15,24 @@ use crate::ast;
 use desugar::{desugar_with_types, desugar_without_types};
 use error::CompileError;
 use module_compiler::ModuleCompiler;
+use monomorphization::monomorphize;
+use pattern_check::{check_clauses, check_patterns, PatternCheckWarning};
+use prelude::import_prelude;
 use std::io::Write;
 use type_inference::infer_types;
 
-pub fn compile(ast_module: &ast::Module, destination: &str) -> Result<(), CompileError> {
+pub fn compile(
+    ast_module: &ast::Module,
+    destination: &str,
+) -> Result<Vec<PatternCheckWarning>, CompileError> {
+    let warnings = check_clauses(ast_module)?;
+    let module = infer_types(&desugar_without_types(&import_prelude(ast_module)))?;
+
+    check_patterns(&module)?;
+
     std::fs::File::create(destination)?.write_all(core::compile::compile(
-        &ModuleCompiler::new().compile(&desugar_with_types(&infer_types(
-            &desugar_without_types(ast_module),
-        )?))?,
+        &ModuleCompiler::new().compile(&desugar_with_types(&monomorphize(&module)?))?,
     )?)?;
 
-    Ok(())
+    Ok(warnings)
 }
@@ -4,29 +4,27 @@ use super::type_compiler::TypeCompiler;
 use crate::ast;
 use std::collections::HashMap;
 
-pub struct ModuleCompiler {
-    type_compiler: TypeCompiler,
-}
+pub struct ModuleCompiler {}
 
 impl ModuleCompiler {
     pub fn new() -> Self {
-        Self {
-            type_compiler: TypeCompiler::new(),
-        }
+        Self {}
     }
 
     pub fn compile(&self, module: &ast::Module) -> Result<core::ast::Module, CompileError> {
+        let type_compiler = TypeCompiler::new(module);
+
         Ok(core::ast::Module::new(
             module
                 .definitions()
                 .iter()
                 .map(|definition| match definition {
                     ast::Definition::FunctionDefinition(function_definition) => Ok(self
-                        .compile_function_definition(function_definition)?
+                        .compile_function_definition(function_definition, &type_compiler)?
+                        .into()),
+                    ast::Definition::ValueDefinition(value_definition) => Ok(self
+                        .compile_value_definition(value_definition, &type_compiler)?
                         .into()),
-                    ast::Definition::ValueDefinition(value_definition) => {
-                        Ok(self.compile_value_definition(value_definition)?.into())
-                    }
                 })
                 .collect::<Result<Vec<_>, CompileError>>()?,
         ))
@@ -35,6 +33,7 @@ impl ModuleCompiler {
     fn compile_function_definition(
         &self,
         function_definition: &ast::FunctionDefinition,
+        type_compiler: &TypeCompiler,
     ) -> Result<core::ast::FunctionDefinition, CompileError> {
         let type_ = function_definition
             .type_()
@@ -49,10 +48,13 @@ impl ModuleCompiler {
                 .iter()
                 .zip(type_.arguments())
                 .map(|(name, type_)| {
-                    core::ast::Argument::new(name.clone(), self.type_compiler.compile(type_))
+                    Ok(core::ast::Argument::new(
+                        name.clone(),
+                        type_compiler.compile(type_)?,
+                    ))
                 })
-                .collect::<Vec<_>>(),
-            ExpressionCompiler::new(&self.type_compiler).compile(
+                .collect::<Result<Vec<_>, CompileError>>()?,
+            ExpressionCompiler::new(type_compiler).compile(
                 function_definition.body(),
                 &function_definition
                     .arguments()
@@ -61,19 +63,20 @@ impl ModuleCompiler {
                     .map(|(name, type_)| (name.clone(), type_.clone()))
                     .collect(),
             )?,
-            self.type_compiler.compile_value(type_.last_result()),
+            type_compiler.compile(type_.last_result())?,
         ))
     }
 
     fn compile_value_definition(
         &self,
         value_definition: &ast::ValueDefinition,
+        type_compiler: &TypeCompiler,
     ) -> Result<core::ast::ValueDefinition, CompileError> {
         Ok(core::ast::ValueDefinition::new(
             value_definition.name(),
-            ExpressionCompiler::new(&self.type_compiler)
+            ExpressionCompiler::new(type_compiler)
                 .compile(value_definition.body(), &HashMap::new())?,
-            self.type_compiler.compile_value(value_definition.type_()),
+            type_compiler.compile(value_definition.type_())?,
         ))
     }
 }
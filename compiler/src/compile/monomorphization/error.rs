@@ -0,0 +1,23 @@
+use crate::debug::SourceInformation;
+use std::error::Error;
+use std::fmt::Display;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MonomorphizationError {
+    PolymorphicRecursion(String, Rc<SourceInformation>),
+}
+
+impl Display for MonomorphizationError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            MonomorphizationError::PolymorphicRecursion(name, source_information) => write!(
+                formatter,
+                "MonomorphizationError: Polymorphic recursion of \"{}\" needs too many instances\n{}",
+                name, source_information
+            ),
+        }
+    }
+}
+
+impl Error for MonomorphizationError {}
@@ -0,0 +1,401 @@
+mod error;
+mod monomorphizer;
+
+use crate::ast::*;
+pub use error::*;
+use monomorphizer::*;
+
+pub fn monomorphize(module: &Module) -> Result<Module, MonomorphizationError> {
+    Monomorphizer::new().monomorphize(module)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{monomorphize, MonomorphizationError};
+    use crate::ast::*;
+    use crate::debug::*;
+    use crate::types::{self, Type};
+
+    #[test]
+    fn monomorphize_empty_modules() {
+        assert_eq!(monomorphize(&Module::new(vec![])), Ok(Module::new(vec![])));
+    }
+
+    #[test]
+    fn monomorphize_monomorphic_modules() {
+        let module = Module::new(vec![FunctionDefinition::new(
+            "f",
+            vec!["x".into()],
+            Variable::new("x", SourceInformation::dummy()),
+            types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(monomorphize(&module), Ok(module));
+    }
+
+    #[test]
+    fn monomorphize_polymorphic_functions() {
+        let type_variable = types::Variable::new(SourceInformation::dummy());
+        let number_function_type = types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            monomorphize(&Module::new(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        type_variable.clone(),
+                        type_variable,
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "x",
+                    Application::new(
+                        Variable::with_type(
+                            "f",
+                            number_function_type.clone(),
+                            SourceInformation::dummy()
+                        ),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])),
+            Ok(Module::new(vec![
+                ValueDefinition::new(
+                    "x",
+                    Application::new(
+                        Variable::new("f<Number->Number>", SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                FunctionDefinition::new(
+                    "f<Number->Number>",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    number_function_type,
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn monomorphize_polymorphic_functions_once_per_type() {
+        let type_variable = types::Variable::new(SourceInformation::dummy());
+        let boolean_function_type = types::Function::new(
+            types::Boolean::new(SourceInformation::dummy()),
+            types::Boolean::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        let module = monomorphize(&Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    type_variable.clone(),
+                    type_variable,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into(),
+            ValueDefinition::new(
+                "x",
+                Application::new(
+                    Variable::with_type(
+                        "f",
+                        boolean_function_type.clone(),
+                        SourceInformation::dummy(),
+                    ),
+                    Application::new(
+                        Variable::with_type("f", boolean_function_type, SourceInformation::dummy()),
+                        Boolean::new(true, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Boolean::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            module
+                .definitions()
+                .iter()
+                .map(|definition| definition.name())
+                .collect::<Vec<_>>(),
+            vec!["x", "f<Boolean->Boolean>"]
+        );
+    }
+
+    #[test]
+    fn monomorphize_polymorphic_let_functions() {
+        let type_variable = types::Variable::new(SourceInformation::dummy());
+
+        assert_eq!(
+            monomorphize(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["y".into()],
+                        Variable::new("y", SourceInformation::dummy()),
+                        types::Function::new(
+                            type_variable.clone(),
+                            type_variable,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Application::new(
+                        Variable::with_type(
+                            "f",
+                            types::Function::new(
+                                types::Number::new(SourceInformation::dummy()),
+                                types::Number::new(SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy()
+                        ),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Ok(Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f<Number->Number>",
+                        vec!["y".into()],
+                        Variable::new("y", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Application::new(
+                        Variable::new("f<Number->Number>", SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn monomorphize_unused_polymorphic_let_functions() {
+        let type_variable = types::Variable::new(SourceInformation::dummy());
+
+        assert_eq!(
+            monomorphize(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["y".into()],
+                        Variable::new("y", SourceInformation::dummy()),
+                        types::Function::new(
+                            type_variable.clone(),
+                            type_variable,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Number::new(42.0, SourceInformation::dummy()),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Ok(Module::new(vec![ValueDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn monomorphize_polymorphic_recursion_with_overlapping_type_names() {
+        let number_type = types::Number::new(SourceInformation::dummy());
+        let function_type = |type_: Type| {
+            types::Function::new(type_, number_type.clone(), SourceInformation::dummy())
+        };
+        let monomorphize_names = |type_: Type, other_type: Type| {
+            let type_variable = types::Variable::new(SourceInformation::dummy());
+
+            monomorphize(&Module::new(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Application::new(
+                        Variable::with_type(
+                            "f",
+                            function_type(other_type),
+                            SourceInformation::dummy(),
+                        ),
+                        Variable::new("x", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    function_type(type_variable.into()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "x",
+                    Application::new(
+                        Variable::with_type("f", function_type(type_), SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    number_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ]))
+            .map(|module| {
+                module
+                    .definitions()
+                    .iter()
+                    .map(|definition| definition.name().to_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(
+            monomorphize_names(
+                types::Int::new(SourceInformation::dummy()).into(),
+                types::Reference::new("Interval", SourceInformation::dummy()).into()
+            ),
+            Ok(vec![
+                "x".into(),
+                "f<Int->Number>".into(),
+                "f<Interval->Number>".into()
+            ])
+        );
+        assert_eq!(
+            monomorphize_names(
+                types::Reference::new("Point", SourceInformation::dummy()).into(),
+                types::Reference::new("Points", SourceInformation::dummy()).into()
+            ),
+            Ok(vec![
+                "x".into(),
+                "f<Point->Number>".into(),
+                "f<Points->Number>".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn fail_to_monomorphize_polymorphic_recursion_with_growing_types() {
+        let type_variable = types::Variable::new(SourceInformation::dummy());
+        let number_type = types::Number::new(SourceInformation::dummy());
+
+        assert_eq!(
+            monomorphize(&Module::new(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Application::new(
+                        Variable::with_type(
+                            "f",
+                            types::Function::new(
+                                types::Tuple::new(
+                                    vec![
+                                        type_variable.clone().into(),
+                                        type_variable.clone().into()
+                                    ],
+                                    SourceInformation::dummy(),
+                                ),
+                                number_type.clone(),
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy()
+                        ),
+                        Tuple::new(
+                            vec![
+                                Variable::new("x", SourceInformation::dummy()).into(),
+                                Variable::new("x", SourceInformation::dummy()).into(),
+                            ],
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Function::new(
+                        type_variable,
+                        number_type.clone(),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "x",
+                    Application::new(
+                        Variable::with_type(
+                            "f",
+                            types::Function::new(
+                                number_type.clone(),
+                                number_type.clone(),
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy()
+                        ),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    number_type,
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])),
+            Err(MonomorphizationError::PolymorphicRecursion(
+                "f".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+}
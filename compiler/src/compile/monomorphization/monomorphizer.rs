@@ -0,0 +1,484 @@
+use super::error::MonomorphizationError;
+use crate::ast::*;
+use crate::types::Type;
+use std::collections::{HashMap, HashSet};
+
+const MAXIMUM_SPECIALIZATION_DEPTH: usize = 64;
+
+type Specialization = (usize, HashMap<usize, Type>);
+
+pub struct Monomorphizer {
+    polymorphic_definitions: Vec<Definition>,
+    requests: Vec<(usize, Type, Vec<Specialization>)>,
+    specialized_names: HashSet<(usize, String)>,
+    specialization_path: Vec<Specialization>,
+}
+
+impl Monomorphizer {
+    pub fn new() -> Self {
+        Self {
+            polymorphic_definitions: vec![],
+            requests: vec![],
+            specialized_names: HashSet::new(),
+            specialization_path: vec![],
+        }
+    }
+
+    pub fn monomorphize(&mut self, module: &Module) -> Result<Module, MonomorphizationError> {
+        let (variables, ids) = self.register_definitions(module.definitions());
+
+        let mut definitions = module
+            .definitions()
+            .iter()
+            .filter(|definition| !is_polymorphic(definition))
+            .map(|definition| {
+                self.specialize_definition(
+                    definition,
+                    definition.name(),
+                    &variables,
+                    &HashMap::new(),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        definitions.extend(self.specialize_requests(&ids, &variables)?);
+
+        Ok(Module::with_type_definitions(
+            module.type_definitions().to_vec(),
+            definitions,
+        ))
+    }
+
+    fn register_definitions(
+        &mut self,
+        definitions: &[Definition],
+    ) -> (HashMap<String, Option<usize>>, Vec<usize>) {
+        let mut variables = HashMap::new();
+        let mut ids = vec![];
+
+        for definition in definitions {
+            if is_polymorphic(definition) {
+                let id = self.polymorphic_definitions.len();
+
+                self.polymorphic_definitions.push(definition.clone());
+                variables.insert(definition.name().into(), Some(id));
+                ids.push(id);
+            } else {
+                variables.insert(definition.name().into(), None);
+            }
+        }
+
+        (variables, ids)
+    }
+
+    fn specialize_requests(
+        &mut self,
+        ids: &[usize],
+        variables: &HashMap<String, Option<usize>>,
+    ) -> Result<Vec<Definition>, MonomorphizationError> {
+        let mut definitions = vec![];
+
+        loop {
+            let (requests, other_requests): (Vec<_>, Vec<_>) = std::mem::take(&mut self.requests)
+                .into_iter()
+                .partition(|(id, _, _)| ids.contains(id));
+
+            self.requests = other_requests;
+
+            if requests.is_empty() {
+                return Ok(definitions);
+            }
+
+            for (id, type_, path) in requests {
+                let definition = self.polymorphic_definitions[id].clone();
+                let name = mangle_name(definition.name(), &type_);
+
+                if !self.specialized_names.insert((id, name.clone())) {
+                    continue;
+                }
+
+                let mut substitutions = HashMap::new();
+                match_types(definition.type_(), &type_, &mut substitutions);
+
+                let ancestors = path
+                    .iter()
+                    .filter(|(other_id, _)| *other_id == id)
+                    .collect::<Vec<_>>();
+
+                if ancestors.len() >= MAXIMUM_SPECIALIZATION_DEPTH
+                    || ancestors
+                        .iter()
+                        .any(|(_, ancestor)| is_growing(ancestor, &substitutions))
+                {
+                    return Err(MonomorphizationError::PolymorphicRecursion(
+                        definition.name().into(),
+                        definition.source_information().clone(),
+                    ));
+                }
+
+                let parent_path = std::mem::replace(
+                    &mut self.specialization_path,
+                    path.into_iter()
+                        .chain(vec![(id, substitutions.clone())])
+                        .collect(),
+                );
+                let definition = self.specialize_definition(
+                    &definition.substitute_type_variables(&substitutions),
+                    &name,
+                    variables,
+                    &substitutions,
+                );
+                self.specialization_path = parent_path;
+
+                definitions.push(definition?);
+            }
+        }
+    }
+
+    fn specialize_definition(
+        &mut self,
+        definition: &Definition,
+        name: &str,
+        variables: &HashMap<String, Option<usize>>,
+        substitutions: &HashMap<usize, Type>,
+    ) -> Result<Definition, MonomorphizationError> {
+        Ok(match definition {
+            Definition::FunctionDefinition(function_definition) => {
+                let mut variables = variables.clone();
+
+                for argument in function_definition.arguments() {
+                    variables.insert(argument.clone(), None);
+                }
+
+                FunctionDefinition::new(
+                    name,
+                    function_definition.arguments().to_vec(),
+                    self.specialize_expression(
+                        function_definition.body(),
+                        &variables,
+                        substitutions,
+                    )?,
+                    function_definition.type_().clone(),
+                    function_definition.source_information().clone(),
+                )
+                .into()
+            }
+            Definition::ValueDefinition(value_definition) => ValueDefinition::new(
+                name,
+                self.specialize_expression(value_definition.body(), variables, substitutions)?,
+                value_definition.type_().clone(),
+                value_definition.source_information().clone(),
+            )
+            .into(),
+        })
+    }
+
+    fn specialize_expression(
+        &mut self,
+        expression: &Expression,
+        variables: &HashMap<String, Option<usize>>,
+        substitutions: &HashMap<usize, Type>,
+    ) -> Result<Expression, MonomorphizationError> {
+        Ok(match expression {
+            Expression::Application(application) => Application::new(
+                self.specialize_expression(application.function(), variables, substitutions)?,
+                self.specialize_expression(application.argument(), variables, substitutions)?,
+                application.source_information().clone(),
+            )
+            .into(),
+            Expression::If(if_) => If::new(
+                self.specialize_expression(if_.condition(), variables, substitutions)?,
+                self.specialize_expression(if_.then(), variables, substitutions)?,
+                self.specialize_expression(if_.else_(), variables, substitutions)?,
+                if_.source_information().clone(),
+            )
+            .into(),
+            Expression::Case(case) => Case::with_type(
+                case.type_().clone(),
+                self.specialize_expression(case.argument(), variables, substitutions)?,
+                case.alternatives()
+                    .iter()
+                    .map(|alternative| {
+                        let mut variables = variables.clone();
+
+                        for variable in alternative.pattern().variables() {
+                            variables.insert(variable.name().into(), None);
+                        }
+
+                        Ok(Alternative::new(
+                            alternative.pattern().clone(),
+                            self.specialize_expression(
+                                alternative.expression(),
+                                &variables,
+                                substitutions,
+                            )?,
+                        ))
+                    })
+                    .collect::<Result<_, _>>()?,
+                case.source_information().clone(),
+            )
+            .into(),
+            Expression::Lambda(lambda) => {
+                let mut variables = variables.clone();
+
+                for argument in lambda.arguments() {
+                    variables.insert(argument.clone(), None);
+                }
+
+                Lambda::with_type(
+                    lambda.type_().clone(),
+                    lambda.arguments().to_vec(),
+                    self.specialize_expression(lambda.body(), &variables, substitutions)?,
+                    lambda.source_information().clone(),
+                )
+                .into()
+            }
+            Expression::Let(let_) => self.specialize_let(let_, variables, substitutions)?,
+            Expression::Match(_) => unreachable!(),
+            Expression::BuiltInCall(built_in_call) => BuiltInCall::new(
+                built_in_call.function(),
+                built_in_call
+                    .arguments()
+                    .iter()
+                    .map(|argument| self.specialize_expression(argument, variables, substitutions))
+                    .collect::<Result<_, _>>()?,
+                built_in_call.source_information().clone(),
+            )
+            .into(),
+            Expression::Conversion(conversion) => Conversion::new(
+                conversion.type_().clone(),
+                self.specialize_expression(conversion.expression(), variables, substitutions)?,
+                conversion.source_information().clone(),
+            )
+            .into(),
+            Expression::Negation(negation) => Negation::new(
+                self.specialize_expression(negation.expression(), variables, substitutions)?,
+                negation.source_information().clone(),
+            )
+            .into(),
+            Expression::Operation(operation) => Operation::new(
+                *operation.operator(),
+                self.specialize_expression(operation.lhs(), variables, substitutions)?,
+                self.specialize_expression(operation.rhs(), variables, substitutions)?,
+                operation.source_information().clone(),
+            )
+            .into(),
+            Expression::Record(record) => Record::with_type(
+                record.type_().clone(),
+                record
+                    .elements()
+                    .iter()
+                    .map(|(name, element)| {
+                        Ok((
+                            name.clone(),
+                            self.specialize_expression(element, variables, substitutions)?,
+                        ))
+                    })
+                    .collect::<Result<_, _>>()?,
+                record.source_information().clone(),
+            )
+            .into(),
+            Expression::RecordElement(record_element) => RecordElement::with_type(
+                record_element.type_().clone(),
+                self.specialize_expression(record_element.record(), variables, substitutions)?,
+                record_element.name(),
+                record_element.source_information().clone(),
+            )
+            .into(),
+            Expression::RecordUpdate(record_update) => RecordUpdate::with_type(
+                record_update.type_().clone(),
+                self.specialize_expression(record_update.record(), variables, substitutions)?,
+                record_update
+                    .elements()
+                    .iter()
+                    .map(|(name, element)| {
+                        Ok((
+                            name.clone(),
+                            self.specialize_expression(element, variables, substitutions)?,
+                        ))
+                    })
+                    .collect::<Result<_, _>>()?,
+                record_update.source_information().clone(),
+            )
+            .into(),
+            Expression::Tuple(tuple) => Tuple::new(
+                tuple
+                    .elements()
+                    .iter()
+                    .map(|element| self.specialize_expression(element, variables, substitutions))
+                    .collect::<Result<_, _>>()?,
+                tuple.source_information().clone(),
+            )
+            .into(),
+            Expression::Variable(variable) => match variables.get(variable.name()) {
+                Some(Some(id)) => {
+                    let type_ = variable.type_().cloned().unwrap_or_else(|| {
+                        self.polymorphic_definitions[*id]
+                            .type_()
+                            .substitute_variables(substitutions)
+                    });
+                    let name = mangle_name(variable.name(), &type_);
+
+                    self.requests
+                        .push((*id, type_, self.specialization_path.clone()));
+
+                    Variable::new(name, variable.source_information().clone()).into()
+                }
+                _ => expression.clone(),
+            },
+            Expression::Boolean(_)
+            | Expression::CharLiteral(_)
+            | Expression::Constructor(_)
+            | Expression::EmptyListFailure(_)
+            | Expression::MatchFailure(_)
+            | Expression::Number(_)
+            | Expression::StringLiteral(_) => expression.clone(),
+        })
+    }
+
+    fn specialize_let(
+        &mut self,
+        let_: &Let,
+        variables: &HashMap<String, Option<usize>>,
+        substitutions: &HashMap<usize, Type>,
+    ) -> Result<Expression, MonomorphizationError> {
+        let (let_variables, ids) = self.register_definitions(let_.definitions());
+        let variables = variables.clone().into_iter().chain(let_variables).collect();
+
+        let mut definitions = let_
+            .definitions()
+            .iter()
+            .filter(|definition| !is_polymorphic(definition))
+            .map(|definition| {
+                self.specialize_definition(definition, definition.name(), &variables, substitutions)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let expression =
+            self.specialize_expression(let_.expression(), &variables, substitutions)?;
+
+        definitions.extend(self.specialize_requests(&ids, &variables)?);
+
+        Ok(if definitions.is_empty() {
+            expression
+        } else {
+            Let::new(definitions, expression).into()
+        })
+    }
+}
+
+fn is_growing(
+    ancestor_substitutions: &HashMap<usize, Type>,
+    substitutions: &HashMap<usize, Type>,
+) -> bool {
+    substitutions.iter().any(|(id, type_)| {
+        ancestor_substitutions
+            .get(id)
+            .map(|ancestor_type| is_proper_subterm(ancestor_type, type_))
+            .unwrap_or(false)
+    })
+}
+
+fn is_proper_subterm(subterm: &Type, type_: &Type) -> bool {
+    let elements = match type_ {
+        Type::Function(function) => vec![function.argument(), function.result()],
+        Type::List(list) => vec![list.element()],
+        Type::Record(record) => record.elements().values().collect(),
+        Type::Tuple(tuple) => tuple.elements().iter().collect(),
+        Type::Boolean(_)
+        | Type::Char(_)
+        | Type::Int(_)
+        | Type::Number(_)
+        | Type::Reference(_)
+        | Type::String(_)
+        | Type::Variable(_) => vec![],
+    };
+
+    elements.into_iter().any(|element| {
+        mangle_type(element) == mangle_type(subterm) || is_proper_subterm(subterm, element)
+    })
+}
+
+fn is_polymorphic(definition: &Definition) -> bool {
+    !definition.type_().variables().is_empty()
+}
+
+fn match_types(generic_type: &Type, type_: &Type, substitutions: &mut HashMap<usize, Type>) {
+    match (generic_type, type_) {
+        (Type::Function(generic_function), Type::Function(function)) => {
+            match_types(
+                generic_function.argument(),
+                function.argument(),
+                substitutions,
+            );
+            match_types(generic_function.result(), function.result(), substitutions);
+        }
+        (Type::List(generic_list), Type::List(list)) => {
+            match_types(generic_list.element(), list.element(), substitutions);
+        }
+        (Type::Record(generic_record), Type::Record(record)) => {
+            for (generic_type, type_) in generic_record
+                .elements()
+                .values()
+                .zip(record.elements().values())
+            {
+                match_types(generic_type, type_, substitutions);
+            }
+        }
+        (Type::Tuple(generic_tuple), Type::Tuple(tuple)) => {
+            for (generic_type, type_) in generic_tuple.elements().iter().zip(tuple.elements()) {
+                match_types(generic_type, type_, substitutions);
+            }
+        }
+        (Type::Variable(variable), _) => {
+            substitutions.insert(variable.id(), type_.clone());
+        }
+        _ => {}
+    }
+}
+
+fn mangle_name(name: &str, type_: &Type) -> String {
+    format!("{}<{}>", name, mangle_type(type_))
+}
+
+fn mangle_type(type_: &Type) -> String {
+    match type_ {
+        Type::Boolean(_) => "Boolean".into(),
+        Type::Char(_) => "Char".into(),
+        Type::Function(function) => match function.argument() {
+            Type::Function(_) => format!(
+                "({})->{}",
+                mangle_type(function.argument()),
+                mangle_type(function.result())
+            ),
+            _ => format!(
+                "{}->{}",
+                mangle_type(function.argument()),
+                mangle_type(function.result())
+            ),
+        },
+        Type::Int(_) => "Int".into(),
+        Type::List(list) => format!("List<{}>", mangle_type(list.element())),
+        Type::Number(_) => "Number".into(),
+        Type::Record(record) => format!(
+            "{{{}}}",
+            record
+                .elements()
+                .iter()
+                .map(|(name, type_)| format!("{}:{}", name, mangle_type(type_)))
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        Type::Reference(reference) => reference.name().into(),
+        Type::String(_) => "String".into(),
+        Type::Tuple(tuple) => format!(
+            "({})",
+            tuple
+                .elements()
+                .iter()
+                .map(mangle_type)
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        Type::Variable(_) => "_".into(),
+    }
+}
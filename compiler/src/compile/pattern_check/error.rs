@@ -0,0 +1,36 @@
+use crate::debug::SourceInformation;
+use std::error::Error;
+use std::fmt::Display;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternCheckError {
+    DuplicateVariable(String, Rc<SourceInformation>),
+    MissingPatterns(Vec<String>, Rc<SourceInformation>),
+    RedundantPattern(Rc<SourceInformation>),
+}
+
+impl Display for PatternCheckError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            PatternCheckError::DuplicateVariable(name, source_information) => write!(
+                formatter,
+                "PatternCheckError: Variable \"{}\" is bound more than once in a pattern\n{}",
+                name, source_information
+            ),
+            PatternCheckError::MissingPatterns(patterns, source_information) => write!(
+                formatter,
+                "PatternCheckError: Patterns are not exhaustive; missing {}\n{}",
+                patterns.join(", "),
+                source_information
+            ),
+            PatternCheckError::RedundantPattern(source_information) => write!(
+                formatter,
+                "PatternCheckError: Pattern is redundant\n{}",
+                source_information
+            ),
+        }
+    }
+}
+
+impl Error for PatternCheckError {}
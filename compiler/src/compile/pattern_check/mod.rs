@@ -0,0 +1,365 @@
+mod error;
+mod pattern_checker;
+mod warning;
+
+use crate::ast::*;
+pub use error::*;
+use pattern_checker::*;
+pub use warning::*;
+
+pub fn check_patterns(module: &Module) -> Result<(), PatternCheckError> {
+    PatternChecker::new().check(module)
+}
+
+pub fn check_clauses(module: &Module) -> Result<Vec<PatternCheckWarning>, PatternCheckError> {
+    PatternChecker::new().check_clauses(module)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug::*;
+    use crate::types;
+
+    fn list_type_definition() -> TypeDefinition {
+        TypeDefinition::new(
+            "List",
+            vec![
+                ConstructorDefinition::new(
+                    "Cons",
+                    vec![
+                        types::Number::new(SourceInformation::dummy()).into(),
+                        types::Reference::new("List", SourceInformation::dummy()).into(),
+                    ],
+                    SourceInformation::dummy(),
+                ),
+                ConstructorDefinition::new("Nil", vec![], SourceInformation::dummy()),
+            ],
+            SourceInformation::dummy(),
+        )
+    }
+
+    fn check_case_patterns(patterns: Vec<Pattern>) -> Result<(), PatternCheckError> {
+        check_patterns(&Module::with_type_definitions(
+            vec![list_type_definition()],
+            vec![ValueDefinition::new(
+                "x",
+                Case::new(
+                    Variable::new("y", SourceInformation::dummy()),
+                    patterns
+                        .into_iter()
+                        .map(|pattern| {
+                            Alternative::new(pattern, Number::new(42.0, SourceInformation::dummy()))
+                        })
+                        .collect(),
+                    SourceInformation::dummy(),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()],
+        ))
+    }
+
+    fn check_function_clauses(
+        clauses: Vec<Clause>,
+    ) -> Result<Vec<PatternCheckWarning>, PatternCheckError> {
+        check_clauses(&Module::with_type_definitions(
+            vec![list_type_definition()],
+            vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into(), "y".into()],
+                Match::new(
+                    vec!["x".into(), "y".into()],
+                    clauses,
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Reference::new("List", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()],
+        ))
+    }
+
+    fn clause(patterns: Vec<Pattern>, guards: Vec<Guard>) -> Clause {
+        Clause::new(patterns, guards, vec![], SourceInformation::dummy())
+    }
+
+    fn otherwise() -> Vec<Guard> {
+        vec![Guard::new(
+            Boolean::new(true, SourceInformation::dummy()),
+            Number::new(42.0, SourceInformation::dummy()),
+        )]
+    }
+
+    fn guard() -> Vec<Guard> {
+        vec![Guard::new(
+            Boolean::new(false, SourceInformation::dummy()),
+            Number::new(42.0, SourceInformation::dummy()),
+        )]
+    }
+
+    fn cons(head: impl Into<Pattern>, tail: impl Into<Pattern>) -> Pattern {
+        ConstructorPattern::new(
+            "Cons",
+            vec![head.into(), tail.into()],
+            SourceInformation::dummy(),
+        )
+        .into()
+    }
+
+    fn nil() -> Pattern {
+        ConstructorPattern::new("Nil", vec![], SourceInformation::dummy()).into()
+    }
+
+    fn wildcard() -> Wildcard {
+        Wildcard::new(SourceInformation::dummy())
+    }
+
+    #[test]
+    fn check_exhaustive_patterns() {
+        assert_eq!(
+            check_case_patterns(vec![cons(wildcard(), wildcard()), nil()]),
+            Ok(())
+        );
+        assert_eq!(
+            check_case_patterns(vec![
+                cons(wildcard(), nil()),
+                cons(wildcard(), cons(wildcard(), wildcard())),
+                nil()
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            check_case_patterns(vec![Variable::new("z", SourceInformation::dummy()).into()]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_check_non_exhaustive_patterns() {
+        assert_eq!(
+            check_case_patterns(vec![cons(wildcard(), wildcard())]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["Nil".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![cons(wildcard(), nil())]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["Cons _ (Cons _ _)".into(), "Nil".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![
+                cons(Number::new(1.0, SourceInformation::dummy()), wildcard()),
+                nil()
+            ]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["Cons _ _".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_check_non_exhaustive_list_patterns() {
+        let list_cons = |head: Pattern, tail: Pattern| -> Pattern {
+            ConstructorPattern::new(
+                CONS_CONSTRUCTOR_NAME,
+                vec![head, tail],
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let list_nil =
+            || ConstructorPattern::new(NIL_CONSTRUCTOR_NAME, vec![], SourceInformation::dummy());
+
+        assert_eq!(
+            check_case_patterns(vec![list_cons(wildcard().into(), wildcard().into())]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["[]".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![list_cons(wildcard().into(), list_nil().into())]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["[]".into(), "_ :: _ :: _".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![
+                list_nil().into(),
+                list_cons(wildcard().into(), wildcard().into())
+            ]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_check_non_exhaustive_tuple_patterns() {
+        let tuple = |elements: Vec<Pattern>| -> Pattern {
+            TuplePattern::new(elements, SourceInformation::dummy()).into()
+        };
+        let boolean = |value| -> Pattern { Boolean::new(value, SourceInformation::dummy()).into() };
+
+        assert_eq!(
+            check_case_patterns(vec![tuple(vec![boolean(true), wildcard().into()])]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["(False, _)".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![tuple(vec![nil(), cons(wildcard(), wildcard())])]),
+            Err(PatternCheckError::MissingPatterns(
+                vec!["(Cons _ _, _)".into(), "(Nil, Nil)".into()],
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![
+                tuple(vec![boolean(true), wildcard().into()]),
+                tuple(vec![boolean(false), wildcard().into()])
+            ]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_check_redundant_patterns() {
+        assert_eq!(
+            check_case_patterns(vec![wildcard().into(), nil()]),
+            Err(PatternCheckError::RedundantPattern(
+                SourceInformation::dummy().into()
+            ))
+        );
+        assert_eq!(
+            check_case_patterns(vec![
+                cons(Boolean::new(true, SourceInformation::dummy()), wildcard()),
+                cons(Boolean::new(false, SourceInformation::dummy()), wildcard()),
+                cons(wildcard(), wildcard()),
+                nil()
+            ]),
+            Err(PatternCheckError::RedundantPattern(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_check_patterns_with_duplicate_variables() {
+        assert_eq!(
+            check_case_patterns(vec![
+                cons(
+                    Variable::new("z", SourceInformation::dummy()),
+                    Variable::new("z", SourceInformation::dummy())
+                ),
+                nil()
+            ]),
+            Err(PatternCheckError::DuplicateVariable(
+                "z".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn check_exhaustive_clauses() {
+        assert_eq!(
+            check_function_clauses(vec![
+                clause(vec![nil(), wildcard().into()], otherwise()),
+                clause(
+                    vec![
+                        cons(wildcard(), wildcard()),
+                        Variable::new("z", SourceInformation::dummy()).into()
+                    ],
+                    otherwise()
+                ),
+            ]),
+            Ok(vec![])
+        );
+        assert_eq!(
+            check_function_clauses(vec![
+                clause(vec![wildcard().into(), wildcard().into()], guard()),
+                clause(vec![wildcard().into(), wildcard().into()], otherwise()),
+            ]),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn warn_about_missing_clauses() {
+        assert_eq!(
+            check_function_clauses(vec![
+                clause(
+                    vec![nil(), Number::new(0.0, SourceInformation::dummy()).into()],
+                    otherwise()
+                ),
+                clause(
+                    vec![cons(wildcard(), wildcard()), wildcard().into()],
+                    guard()
+                ),
+            ]),
+            Ok(vec![PatternCheckWarning::MissingClauses(
+                vec!["(Cons _ _) _".into(), "Nil _".into()],
+                SourceInformation::dummy().into()
+            )])
+        );
+    }
+
+    #[test]
+    fn warn_about_overlapped_clauses() {
+        assert_eq!(
+            check_function_clauses(vec![
+                clause(vec![wildcard().into(), wildcard().into()], otherwise()),
+                clause(vec![nil(), wildcard().into()], otherwise()),
+            ]),
+            Ok(vec![PatternCheckWarning::OverlappedClause(
+                SourceInformation::dummy().into()
+            )])
+        );
+    }
+
+    #[test]
+    fn warn_about_unreachable_guards() {
+        assert_eq!(
+            check_function_clauses(vec![clause(
+                vec![wildcard().into(), wildcard().into()],
+                otherwise().into_iter().chain(guard()).collect()
+            )]),
+            Ok(vec![PatternCheckWarning::UnreachableGuards(
+                SourceInformation::dummy().into()
+            )])
+        );
+    }
+
+    #[test]
+    fn fail_to_check_clauses_with_duplicate_variables() {
+        assert_eq!(
+            check_function_clauses(vec![clause(
+                vec![
+                    cons(Variable::new("z", SourceInformation::dummy()), wildcard()),
+                    Variable::new("z", SourceInformation::dummy()).into()
+                ],
+                otherwise()
+            )]),
+            Err(PatternCheckError::DuplicateVariable(
+                "z".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+}
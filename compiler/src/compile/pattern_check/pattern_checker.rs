@@ -0,0 +1,382 @@
+use super::error::*;
+use super::warning::*;
+use crate::ast::*;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Head {
+    Boolean(bool),
+    Constructor(String, usize),
+    Number(f64, Option<i64>),
+    Tuple(usize),
+}
+
+impl Head {
+    fn arity(&self) -> usize {
+        match self {
+            Self::Constructor(_, arity) | Self::Tuple(arity) => *arity,
+            Self::Boolean(_) | Self::Number(_, _) => 0,
+        }
+    }
+
+    fn format(&self, arguments: &[String]) -> String {
+        match self {
+            Self::Boolean(value) => if *value { "True" } else { "False" }.into(),
+            Self::Constructor(name, _) if name == CONS_CONSTRUCTOR_NAME => {
+                format!("{} {} {}", parenthesize(&arguments[0]), name, arguments[1])
+            }
+            Self::Constructor(name, _) => std::iter::once(name.clone())
+                .chain(arguments.iter().map(|argument| parenthesize(argument)))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Self::Number(_, Some(integer)) => format!("{}", integer),
+            Self::Number(value, None) => format!("{}", value),
+            Self::Tuple(_) => format!("({})", arguments.join(", ")),
+        }
+    }
+}
+
+type Row<'a> = Vec<Option<&'a Pattern>>;
+
+pub struct PatternChecker {
+    constructors: HashMap<String, Vec<Head>>,
+}
+
+impl PatternChecker {
+    pub fn new() -> Self {
+        Self {
+            constructors: HashMap::new(),
+        }
+    }
+
+    pub fn check(&mut self, module: &Module) -> Result<(), PatternCheckError> {
+        self.register_constructors(module);
+
+        let mut result = Ok(());
+
+        module.convert_expressions(&mut |expression| {
+            if let Expression::Case(case) = expression {
+                if result.is_ok() {
+                    result = self.check_case(case);
+                }
+            }
+
+            expression.clone()
+        });
+
+        result
+    }
+
+    pub fn check_clauses(
+        &mut self,
+        module: &Module,
+    ) -> Result<Vec<PatternCheckWarning>, PatternCheckError> {
+        self.register_constructors(module);
+
+        let mut warnings = vec![];
+        let mut result = Ok(());
+
+        module.convert_expressions(&mut |expression| {
+            if let Expression::Match(match_) = expression {
+                if result.is_ok() {
+                    result = self
+                        .check_match(match_)
+                        .map(|match_warnings| warnings.extend(match_warnings));
+                }
+            }
+
+            expression.clone()
+        });
+
+        result.map(|_| warnings)
+    }
+
+    fn register_constructors(&mut self, module: &Module) {
+        let list_heads = vec![
+            Head::Constructor(NIL_CONSTRUCTOR_NAME.into(), 0),
+            Head::Constructor(CONS_CONSTRUCTOR_NAME.into(), 2),
+        ];
+
+        for name in &[NIL_CONSTRUCTOR_NAME, CONS_CONSTRUCTOR_NAME] {
+            self.constructors.insert((*name).into(), list_heads.clone());
+        }
+
+        for type_definition in module.type_definitions() {
+            let heads = type_definition
+                .constructors()
+                .iter()
+                .map(|constructor| {
+                    Head::Constructor(constructor.name().into(), constructor.elements().len())
+                })
+                .collect::<Vec<_>>();
+
+            for constructor in type_definition.constructors() {
+                self.constructors
+                    .insert(constructor.name().into(), heads.clone());
+            }
+        }
+    }
+
+    fn check_case(&self, case: &Case) -> Result<(), PatternCheckError> {
+        let mut rows = vec![];
+
+        for alternative in case.alternatives() {
+            let pattern = alternative.pattern();
+
+            self.check_variables(pattern.variables())?;
+
+            let row = vec![Some(pattern)];
+
+            if !self.is_useful(&rows, &row) {
+                return Err(PatternCheckError::RedundantPattern(
+                    pattern.source_information().clone(),
+                ));
+            }
+
+            rows.push(row);
+        }
+
+        let missing_patterns = self.find_missing_patterns(&rows, 1);
+
+        if missing_patterns.is_empty() {
+            Ok(())
+        } else {
+            Err(PatternCheckError::MissingPatterns(
+                missing_patterns
+                    .into_iter()
+                    .map(|mut patterns| patterns.remove(0))
+                    .collect(),
+                case.source_information().clone(),
+            ))
+        }
+    }
+
+    fn check_match(&self, match_: &Match) -> Result<Vec<PatternCheckWarning>, PatternCheckError> {
+        let mut warnings = vec![];
+        let mut rows = vec![];
+
+        for clause in match_.clauses() {
+            self.check_variables(clause.patterns().iter().flat_map(Pattern::variables))?;
+
+            let row = clause.patterns().iter().map(Some).collect::<Vec<_>>();
+
+            if !self.is_useful(&rows, &row) {
+                warnings.push(PatternCheckWarning::OverlappedClause(
+                    clause.source_information().clone(),
+                ));
+            }
+
+            if clause
+                .guards()
+                .iter()
+                .position(Guard::is_otherwise)
+                .map(|index| index + 1 < clause.guards().len())
+                .unwrap_or(false)
+            {
+                warnings.push(PatternCheckWarning::UnreachableGuards(
+                    clause.source_information().clone(),
+                ));
+            }
+
+            if clause.is_total() {
+                rows.push(row);
+            }
+        }
+
+        let missing_patterns = self.find_missing_patterns(&rows, match_.arguments().len());
+
+        if !missing_patterns.is_empty() {
+            warnings.push(PatternCheckWarning::MissingClauses(
+                missing_patterns
+                    .into_iter()
+                    .map(|patterns| {
+                        patterns
+                            .iter()
+                            .map(|pattern| parenthesize(pattern))
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect(),
+                match_.source_information().clone(),
+            ));
+        }
+
+        Ok(warnings)
+    }
+
+    fn check_variables<'a>(
+        &self,
+        variables: impl IntoIterator<Item = &'a Variable>,
+    ) -> Result<(), PatternCheckError> {
+        let mut names = HashSet::new();
+
+        for variable in variables {
+            if !names.insert(variable.name()) {
+                return Err(PatternCheckError::DuplicateVariable(
+                    variable.name().into(),
+                    variable.source_information().clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_useful(&self, rows: &[Row], row: &[Option<&Pattern>]) -> bool {
+        if row.is_empty() {
+            return rows.is_empty();
+        }
+
+        match Self::head(row[0]) {
+            Some(head) => self.is_useful(
+                &Self::specialize(rows, &head),
+                &Self::specialize(&[row.to_vec()], &head)[0],
+            ),
+            None => match self.complete_heads(&Self::heads(rows)) {
+                Some(heads) => heads.iter().any(|head| {
+                    self.is_useful(
+                        &Self::specialize(rows, head),
+                        &Self::specialize(&[row.to_vec()], head)[0],
+                    )
+                }),
+                None => self.is_useful(&Self::default(rows), &row[1..]),
+            },
+        }
+    }
+
+    fn find_missing_patterns(&self, rows: &[Row], width: usize) -> Vec<Vec<String>> {
+        if width == 0 {
+            return if rows.is_empty() {
+                vec![vec![]]
+            } else {
+                vec![]
+            };
+        }
+
+        match self.all_heads(&Self::heads(rows)) {
+            Some(heads) => heads
+                .iter()
+                .flat_map(|head| {
+                    self.find_missing_patterns(
+                        &Self::specialize(rows, head),
+                        head.arity() + width - 1,
+                    )
+                    .into_iter()
+                    .map(move |patterns| {
+                        let (arguments, rest) = patterns.split_at(head.arity());
+
+                        std::iter::once(head.format(arguments))
+                            .chain(rest.iter().cloned())
+                            .collect()
+                    })
+                })
+                .collect(),
+            None => self
+                .find_missing_patterns(&Self::default(rows), width - 1)
+                .into_iter()
+                .map(|patterns| std::iter::once("_".into()).chain(patterns).collect())
+                .collect(),
+        }
+    }
+
+    fn all_heads(&self, heads: &[Head]) -> Option<Vec<Head>> {
+        match heads.first()? {
+            Head::Boolean(_) => Some(vec![Head::Boolean(false), Head::Boolean(true)]),
+            Head::Constructor(name, _) => self.constructors.get(name).cloned(),
+            Head::Number(_, _) => None,
+            Head::Tuple(arity) => Some(vec![Head::Tuple(*arity)]),
+        }
+    }
+
+    fn complete_heads(&self, heads: &[Head]) -> Option<Vec<Head>> {
+        self.all_heads(heads)
+            .filter(|all_heads| all_heads.iter().all(|head| heads.contains(head)))
+    }
+
+    fn head(pattern: Option<&Pattern>) -> Option<Head> {
+        match pattern? {
+            Pattern::Boolean(boolean) => Some(Head::Boolean(boolean.value())),
+            Pattern::Constructor(constructor_pattern) => Some(Head::Constructor(
+                constructor_pattern.name().into(),
+                constructor_pattern.arguments().len(),
+            )),
+            Pattern::Number(number) => Some(Head::Number(number.value(), number.integer())),
+            Pattern::Tuple(tuple_pattern) => Some(Head::Tuple(tuple_pattern.elements().len())),
+            Pattern::Variable(_) | Pattern::Wildcard(_) => None,
+        }
+    }
+
+    fn heads(rows: &[Row]) -> Vec<Head> {
+        let mut heads = vec![];
+
+        for head in rows.iter().filter_map(|row| Self::head(row[0])) {
+            if !heads.contains(&head) {
+                heads.push(head);
+            }
+        }
+
+        heads
+    }
+
+    fn specialize<'a>(rows: &[Row<'a>], head: &Head) -> Vec<Row<'a>> {
+        rows.iter()
+            .filter_map(|row| {
+                let rest = row[1..].iter().cloned();
+
+                match Self::head(row[0]) {
+                    None => Some(vec![None; head.arity()].into_iter().chain(rest).collect()),
+                    Some(other) if &other == head => Some(match row[0] {
+                        Some(Pattern::Constructor(constructor_pattern)) => constructor_pattern
+                            .arguments()
+                            .iter()
+                            .map(Some)
+                            .chain(rest)
+                            .collect(),
+                        Some(Pattern::Tuple(tuple_pattern)) => tuple_pattern
+                            .elements()
+                            .iter()
+                            .map(Some)
+                            .chain(rest)
+                            .collect(),
+                        _ => rest.collect(),
+                    }),
+                    Some(_) => None,
+                }
+            })
+            .collect()
+    }
+
+    fn default<'a>(rows: &[Row<'a>]) -> Vec<Row<'a>> {
+        rows.iter()
+            .filter(|row| Self::head(row[0]).is_none())
+            .map(|row| row[1..].to_vec())
+            .collect()
+    }
+}
+
+fn parenthesize(pattern: &str) -> String {
+    if pattern.contains(' ') && !is_parenthesized(pattern) {
+        format!("({})", pattern)
+    } else {
+        pattern.into()
+    }
+}
+
+fn is_parenthesized(pattern: &str) -> bool {
+    let mut depth = 0;
+
+    for (index, character) in pattern.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ if depth == 0 => return false,
+            _ => {}
+        }
+
+        if depth == 0 && index + 1 < pattern.len() {
+            return false;
+        }
+    }
+
+    depth == 0
+}
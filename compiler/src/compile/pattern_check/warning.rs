@@ -0,0 +1,33 @@
+use crate::debug::SourceInformation;
+use std::fmt::Display;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternCheckWarning {
+    MissingClauses(Vec<String>, Rc<SourceInformation>),
+    OverlappedClause(Rc<SourceInformation>),
+    UnreachableGuards(Rc<SourceInformation>),
+}
+
+impl Display for PatternCheckWarning {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            PatternCheckWarning::MissingClauses(patterns, source_information) => write!(
+                formatter,
+                "PatternCheckWarning: Clauses are not exhaustive; missing {}\n{}",
+                patterns.join(", "),
+                source_information
+            ),
+            PatternCheckWarning::OverlappedClause(source_information) => write!(
+                formatter,
+                "PatternCheckWarning: Clause is overlapped by previous clauses\n{}",
+                source_information
+            ),
+            PatternCheckWarning::UnreachableGuards(source_information) => write!(
+                formatter,
+                "PatternCheckWarning: Guards after otherwise are unreachable\n{}",
+                source_information
+            ),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use crate::ast::*;
+use crate::debug::{Location, SourceInformation};
+use crate::types::{self, Type};
+use std::rc::Rc;
+
+pub const TO_STRING_FUNCTION_NAME: &str = "toString";
+
+pub fn import_prelude(module: &Module) -> Module {
+    let names = module
+        .definitions()
+        .iter()
+        .map(|definition| definition.name())
+        .collect::<Vec<_>>();
+
+    Module::with_type_aliases(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        prelude_definitions()
+            .into_iter()
+            .filter(|definition| !names.contains(&definition.name()))
+            .chain(module.definitions().iter().cloned())
+            .collect(),
+    )
+}
+
+fn prelude_definitions() -> Vec<Definition> {
+    vec![
+        integer_operation_definition("div", Operator::FloorDivide, 1),
+        integer_operation_definition("mod", Operator::Modulo, 2),
+        conversion_definition("toNumber", ("Int", "Number"), 3),
+        conversion_definition("toInt", ("Number", "Int"), 4),
+        integer_operation_definition("xor", Operator::BitwiseXor, 5),
+        integer_operation_definition("shiftL", Operator::ShiftLeft, 6),
+        integer_operation_definition("shiftR", Operator::ShiftRight, 7),
+        pop_count_definition(8),
+        built_in_function_definition("sqrt", BuiltInFunction::SquareRoot, 9),
+        built_in_function_definition("floor", BuiltInFunction::Floor, 10),
+        built_in_function_definition("ceil", BuiltInFunction::Ceiling, 11),
+        built_in_function_definition("exp", BuiltInFunction::Exponential, 12),
+        built_in_function_definition("log", BuiltInFunction::Logarithm, 13),
+        built_in_function_definition("sin", BuiltInFunction::Sine, 14),
+        built_in_function_definition("cos", BuiltInFunction::Cosine, 15),
+        built_in_function_definition("abs", BuiltInFunction::Absolute, 16),
+        string_length_definition(17),
+        string_slice_definition(18),
+        conversion_definition("toCodePoint", ("Char", "Int"), 19),
+        conversion_definition("fromCodePoint", ("Int", "Char"), 20),
+        list_function_definition("head", "a", 21),
+        list_function_definition("tail", "List a", 22),
+        list_function_definition("isEmpty", "Boolean", 23),
+        list_function_definition("length", "Int", 24),
+    ]
+}
+
+fn integer_operation_definition(name: &str, operator: Operator, line_number: usize) -> Definition {
+    let source_information =
+        create_source_information(line_number, format!("{} : Int -> Int -> Int", name));
+    let int_type: Type = types::Int::new(source_information.clone()).into();
+
+    FunctionDefinition::new(
+        name,
+        vec!["x".into(), "y".into()],
+        Operation::new(
+            operator,
+            Variable::new("x", source_information.clone()),
+            Variable::new("y", source_information.clone()),
+            source_information.clone(),
+        ),
+        types::Function::new(
+            int_type.clone(),
+            types::Function::new(int_type.clone(), int_type, source_information.clone()),
+            source_information.clone(),
+        ),
+        source_information,
+    )
+    .into()
+}
+
+fn conversion_definition(
+    name: &str,
+    (argument_type, result_type): (&str, &str),
+    line_number: usize,
+) -> Definition {
+    let source_information = create_source_information(
+        line_number,
+        format!("{} : {} -> {}", name, argument_type, result_type),
+    );
+    let create_type = |name| -> Type {
+        match name {
+            "Char" => types::Char::new(source_information.clone()).into(),
+            "Int" => types::Int::new(source_information.clone()).into(),
+            _ => types::Number::new(source_information.clone()).into(),
+        }
+    };
+
+    FunctionDefinition::new(
+        name,
+        vec!["x".into()],
+        Conversion::new(
+            create_type(result_type),
+            Variable::new("x", source_information.clone()),
+            source_information.clone(),
+        ),
+        types::Function::new(
+            create_type(argument_type),
+            create_type(result_type),
+            source_information.clone(),
+        ),
+        source_information.clone(),
+    )
+    .into()
+}
+
+fn pop_count_definition(line_number: usize) -> Definition {
+    let source_information = create_source_information(line_number, "popCount : Int -> Int".into());
+    let int_type: Type = types::Int::new(source_information.clone()).into();
+
+    FunctionDefinition::new(
+        "popCount",
+        vec!["x".into()],
+        BuiltInCall::new(
+            BuiltInFunction::PopCount,
+            vec![Variable::new("x", source_information.clone()).into()],
+            source_information.clone(),
+        ),
+        types::Function::new(int_type.clone(), int_type, source_information.clone()),
+        source_information,
+    )
+    .into()
+}
+
+fn built_in_function_definition(
+    name: &str,
+    function: BuiltInFunction,
+    line_number: usize,
+) -> Definition {
+    let source_information =
+        create_source_information(line_number, format!("{} : Number -> Number", name));
+    let number_type: Type = types::Number::new(source_information.clone()).into();
+
+    FunctionDefinition::new(
+        name,
+        vec!["x".into()],
+        BuiltInCall::new(
+            function,
+            vec![Variable::new("x", source_information.clone()).into()],
+            source_information.clone(),
+        ),
+        types::Function::new(number_type.clone(), number_type, source_information.clone()),
+        source_information,
+    )
+    .into()
+}
+
+fn string_length_definition(line_number: usize) -> Definition {
+    let source_information =
+        create_source_information(line_number, "stringLength : String -> Int".into());
+
+    FunctionDefinition::new(
+        "stringLength",
+        vec!["x".into()],
+        BuiltInCall::new(
+            BuiltInFunction::StringLength,
+            vec![Variable::new("x", source_information.clone()).into()],
+            source_information.clone(),
+        ),
+        types::Function::new(
+            types::String::new(source_information.clone()),
+            types::Int::new(source_information.clone()),
+            source_information.clone(),
+        ),
+        source_information,
+    )
+    .into()
+}
+
+fn string_slice_definition(line_number: usize) -> Definition {
+    let source_information = create_source_information(
+        line_number,
+        "stringSlice : String -> Int -> Int -> String".into(),
+    );
+    let int_type: Type = types::Int::new(source_information.clone()).into();
+    let string_type: Type = types::String::new(source_information.clone()).into();
+
+    FunctionDefinition::new(
+        "stringSlice",
+        vec!["x".into(), "y".into(), "z".into()],
+        BuiltInCall::new(
+            BuiltInFunction::StringSlice,
+            vec![
+                Variable::new("x", source_information.clone()).into(),
+                Variable::new("y", source_information.clone()).into(),
+                Variable::new("z", source_information.clone()).into(),
+            ],
+            source_information.clone(),
+        ),
+        types::Function::new(
+            string_type.clone(),
+            types::Function::new(
+                int_type.clone(),
+                types::Function::new(int_type, string_type, source_information.clone()),
+                source_information.clone(),
+            ),
+            source_information.clone(),
+        ),
+        source_information,
+    )
+    .into()
+}
+
+fn list_function_definition(name: &str, result_type: &str, line_number: usize) -> Definition {
+    let source_information =
+        create_source_information(line_number, format!("{} : List a -> {}", name, result_type));
+    let element_type = types::Variable::with_name("a", source_information.clone());
+    let list_type = types::List::new(element_type.clone(), source_information.clone());
+    let int_type: Type = types::Int::new(source_information.clone()).into();
+    let variable = |name| Variable::new(name, source_information.clone());
+    let wildcard = || Wildcard::new(source_information.clone()).into();
+    let nil_pattern =
+        || ConstructorPattern::new(NIL_CONSTRUCTOR_NAME, vec![], source_information.clone());
+    let cons_pattern = |head, tail| {
+        ConstructorPattern::new(
+            CONS_CONSTRUCTOR_NAME,
+            vec![head, tail],
+            source_information.clone(),
+        )
+    };
+    let boolean = |value| Boolean::new(value, source_information.clone());
+    let number = |value| Number::with_type(int_type.clone(), value, source_information.clone());
+
+    let (alternatives, result_type): (_, Type) = match result_type {
+        "a" => (
+            vec![
+                Alternative::new(
+                    cons_pattern(variable("y").into(), wildcard()),
+                    variable("y"),
+                ),
+                Alternative::new(
+                    wildcard(),
+                    EmptyListFailure::new(source_information.clone()),
+                ),
+            ],
+            element_type.into(),
+        ),
+        "List a" => (
+            vec![
+                Alternative::new(
+                    cons_pattern(wildcard(), variable("y").into()),
+                    variable("y"),
+                ),
+                Alternative::new(
+                    wildcard(),
+                    EmptyListFailure::new(source_information.clone()),
+                ),
+            ],
+            list_type.clone().into(),
+        ),
+        "Boolean" => (
+            vec![
+                Alternative::new(nil_pattern(), boolean(true)),
+                Alternative::new(wildcard(), boolean(false)),
+            ],
+            types::Boolean::new(source_information.clone()).into(),
+        ),
+        _ => (
+            vec![
+                Alternative::new(nil_pattern(), number(0.0)),
+                Alternative::new(
+                    cons_pattern(wildcard(), variable("y").into()),
+                    Operation::new(
+                        Operator::Add,
+                        number(1.0),
+                        Application::new(variable(name), variable("y"), source_information.clone()),
+                        source_information.clone(),
+                    ),
+                ),
+            ],
+            int_type.clone(),
+        ),
+    };
+
+    FunctionDefinition::new(
+        name,
+        vec!["x".into()],
+        Case::new(variable("x"), alternatives, source_information.clone()),
+        types::Function::new(list_type, result_type, source_information.clone()),
+        source_information,
+    )
+    .into()
+}
+
+fn create_source_information(line_number: usize, line: String) -> Rc<SourceInformation> {
+    SourceInformation::new("<prelude>", Location::new(line_number, 1), line).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn import_prelude_definitions() {
+        assert_eq!(
+            import_prelude(&Module::new(vec![]))
+                .definitions()
+                .iter()
+                .map(|definition| definition.name())
+                .collect::<Vec<_>>(),
+            vec![
+                "div",
+                "mod",
+                "toNumber",
+                "toInt",
+                "xor",
+                "shiftL",
+                "shiftR",
+                "popCount",
+                "sqrt",
+                "floor",
+                "ceil",
+                "exp",
+                "log",
+                "sin",
+                "cos",
+                "abs",
+                "stringLength",
+                "stringSlice",
+                "toCodePoint",
+                "fromCodePoint",
+                "head",
+                "tail",
+                "isEmpty",
+                "length"
+            ]
+        );
+    }
+
+    #[test]
+    fn skip_prelude_definitions_defined_in_modules() {
+        let definition: Definition = ValueDefinition::new(
+            "toInt",
+            Number::new(42.0, SourceInformation::dummy()),
+            types::Variable::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            import_prelude(&Module::new(vec![definition.clone()]))
+                .definitions()
+                .iter()
+                .filter(|definition| definition.name() == "toInt")
+                .collect::<Vec<_>>(),
+            vec![&definition]
+        );
+    }
+}
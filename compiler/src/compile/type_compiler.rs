@@ -1,36 +1,380 @@
+use super::error::{CompileError, InternalCompileError};
+use crate::ast;
 use crate::types::{self, Type};
+use std::collections::HashMap;
+use std::rc::Rc;
 
-pub struct TypeCompiler {}
+pub struct TypeCompiler {
+    type_definitions: Rc<HashMap<String, ast::TypeDefinition>>,
+    references: Vec<Option<String>>,
+}
 
 impl TypeCompiler {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(module: &ast::Module) -> Self {
+        Self {
+            type_definitions: Rc::new(
+                module
+                    .type_definitions()
+                    .iter()
+                    .map(|type_definition| (type_definition.name().into(), type_definition.clone()))
+                    .collect(),
+            ),
+            references: vec![],
+        }
     }
 
-    pub fn compile(&self, type_: &Type) -> core::types::Type {
+    pub fn compile(&self, type_: &Type) -> Result<core::types::Type, CompileError> {
         match type_ {
-            Type::Function(function) => self.compile_function(function).into(),
-            Type::Number(_) => core::types::Value::Number.into(),
-            Type::Variable(_) => unreachable!(),
+            Type::Function(function) => Ok(self.compile_function(function)?.into()),
+            _ => Ok(self.compile_value(type_)?.into()),
         }
     }
 
-    pub fn compile_function(&self, function: &types::Function) -> core::types::Function {
-        core::types::Function::new(
+    pub fn compile_function(
+        &self,
+        function: &types::Function,
+    ) -> Result<core::types::Function, CompileError> {
+        Ok(core::types::Function::new(
             function
                 .arguments()
                 .iter()
                 .map(|type_| self.compile(*type_))
-                .collect::<Vec<_>>(),
-            self.compile_value(function.last_result()),
-        )
+                .collect::<Result<Vec<_>, _>>()?,
+            self.compile(function.last_result())?,
+        ))
     }
 
-    pub fn compile_value(&self, type_: &Type) -> core::types::Value {
+    pub fn compile_value(&self, type_: &Type) -> Result<core::types::Value, CompileError> {
         match type_ {
+            Type::Boolean(_) => Ok(core::types::Value::Boolean),
+            Type::Char(_) => Ok(core::types::Value::Char),
             Type::Function(_) => unreachable!(),
-            Type::Number(_) => core::types::Value::Number,
-            Type::Variable(_) => unreachable!(),
+            Type::Int(_) => Ok(core::types::Value::Int),
+            Type::List(list) => Ok(self.compile_list(list)?.into()),
+            Type::Number(_) => Ok(core::types::Value::Number),
+            Type::Record(record) => Ok(self.compile_record(record)?.into()),
+            Type::String(_) => Ok(core::types::Value::String),
+            Type::Tuple(tuple) => Ok(self.compile_tuple(tuple)?.into()),
+            Type::Reference(reference) => {
+                match self
+                    .references
+                    .iter()
+                    .rev()
+                    .position(|name| name.as_deref() == Some(reference.name()))
+                {
+                    Some(index) => Ok(core::types::Value::Index(index)),
+                    None => Ok(self.compile_algebraic(type_)?.into()),
+                }
+            }
+            Type::Variable(variable) => Err(CompileError::Internal(
+                InternalCompileError::TypeVariableNotCompiled(
+                    variable.source_information().clone(),
+                ),
+            )),
+        }
+    }
+
+    pub fn compile_algebraic(&self, type_: &Type) -> Result<core::types::Algebraic, CompileError> {
+        match type_ {
+            Type::List(list) => self.compile_list(list),
+            Type::Reference(_) => self.compile_type_definition(&self.find_type_definition(type_)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn compile_list(&self, list: &types::List) -> Result<core::types::Algebraic, CompileError> {
+        Ok(core::types::Algebraic::new(vec![
+            core::types::Constructor::new(vec![]),
+            core::types::Constructor::new(vec![
+                self.push_reference(None).compile(list.element())?,
+                core::types::Value::Index(0).into(),
+            ]),
+        ]))
+    }
+
+    pub fn compile_record(
+        &self,
+        record: &types::Record,
+    ) -> Result<core::types::Record, CompileError> {
+        Ok(core::types::Record::new(
+            record
+                .elements()
+                .values()
+                .map(|type_| self.compile(type_))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    pub fn compile_tuple(&self, tuple: &types::Tuple) -> Result<core::types::Tuple, CompileError> {
+        Ok(core::types::Tuple::new(
+            tuple
+                .elements()
+                .iter()
+                .map(|type_| self.compile(type_))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    pub fn compile_type_definition(
+        &self,
+        type_definition: &ast::TypeDefinition,
+    ) -> Result<core::types::Algebraic, CompileError> {
+        let type_compiler = self.push_reference(Some(type_definition.name().into()));
+
+        Ok(core::types::Algebraic::new(
+            type_definition
+                .constructors()
+                .iter()
+                .map(|constructor| {
+                    Ok(core::types::Constructor::new(
+                        constructor
+                            .elements()
+                            .iter()
+                            .map(|type_| type_compiler.compile(type_))
+                            .collect::<Result<_, CompileError>>()?,
+                    ))
+                })
+                .collect::<Result<_, CompileError>>()?,
+        ))
+    }
+
+    pub fn find_type_definition(&self, type_: &Type) -> ast::TypeDefinition {
+        match type_ {
+            Type::List(list) => ast::TypeDefinition::new(
+                "List",
+                vec![
+                    ast::ConstructorDefinition::new(
+                        ast::NIL_CONSTRUCTOR_NAME,
+                        vec![],
+                        list.source_information().clone(),
+                    ),
+                    ast::ConstructorDefinition::new(
+                        ast::CONS_CONSTRUCTOR_NAME,
+                        vec![list.element().clone(), list.clone().into()],
+                        list.source_information().clone(),
+                    ),
+                ],
+                list.source_information().clone(),
+            ),
+            Type::Reference(reference) => self.type_definitions[reference.name()].clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn find_algebraic_type(&self, constructor: &ast::Constructor) -> Type {
+        match constructor.name() {
+            ast::NIL_CONSTRUCTOR_NAME | ast::CONS_CONSTRUCTOR_NAME => {
+                match constructor.type_().expect("list type") {
+                    Type::Function(function) => function.last_result().clone(),
+                    type_ => type_.clone(),
+                }
+            }
+            name => self
+                .type_definitions
+                .values()
+                .find(|type_definition| {
+                    type_definition
+                        .constructors()
+                        .iter()
+                        .any(|constructor| constructor.name() == name)
+                })
+                .expect("constructor")
+                .type_(),
+        }
+    }
+
+    fn push_reference(&self, name: Option<String>) -> Self {
+        Self {
+            type_definitions: self.type_definitions.clone(),
+            references: self.references.iter().cloned().chain(vec![name]).collect(),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::debug::SourceInformation;
+
+    #[test]
+    fn compile_algebraic_types() {
+        let module = ast::Module::with_type_definitions(
+            vec![ast::TypeDefinition::new(
+                "Shape",
+                vec![
+                    ast::ConstructorDefinition::new(
+                        "Circle",
+                        vec![types::Number::new(SourceInformation::dummy()).into()],
+                        SourceInformation::dummy(),
+                    ),
+                    ast::ConstructorDefinition::new("Empty", vec![], SourceInformation::dummy()),
+                ],
+                SourceInformation::dummy(),
+            )],
+            vec![],
+        );
+
+        assert_eq!(
+            TypeCompiler::new(&module)
+                .compile(&types::Reference::new("Shape", SourceInformation::dummy()).into()),
+            Ok(core::types::Algebraic::new(vec![
+                core::types::Constructor::new(vec![core::types::Value::Number.into()]),
+                core::types::Constructor::new(vec![]),
+            ])
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_recursive_algebraic_types() {
+        let module = ast::Module::with_type_definitions(
+            vec![ast::TypeDefinition::new(
+                "List",
+                vec![
+                    ast::ConstructorDefinition::new(
+                        "Cons",
+                        vec![
+                            types::Number::new(SourceInformation::dummy()).into(),
+                            types::Reference::new("List", SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    ast::ConstructorDefinition::new("Nil", vec![], SourceInformation::dummy()),
+                ],
+                SourceInformation::dummy(),
+            )],
+            vec![],
+        );
+
+        assert_eq!(
+            TypeCompiler::new(&module)
+                .compile(&types::Reference::new("List", SourceInformation::dummy()).into()),
+            Ok(core::types::Algebraic::new(vec![
+                core::types::Constructor::new(vec![
+                    core::types::Value::Number.into(),
+                    core::types::Value::Index(0).into()
+                ]),
+                core::types::Constructor::new(vec![]),
+            ])
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_list_types() {
+        assert_eq!(
+            TypeCompiler::new(&ast::Module::new(vec![])).compile(
+                &types::List::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            Ok(core::types::Algebraic::new(vec![
+                core::types::Constructor::new(vec![]),
+                core::types::Constructor::new(vec![
+                    core::types::Value::Number.into(),
+                    core::types::Value::Index(0).into()
+                ]),
+            ])
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_record_types() {
+        assert_eq!(
+            TypeCompiler::new(&ast::Module::new(vec![])).compile(
+                &types::Record::new(
+                    vec![
+                        (
+                            "y".into(),
+                            types::Boolean::new(SourceInformation::dummy()).into()
+                        ),
+                        (
+                            "x".into(),
+                            types::Number::new(SourceInformation::dummy()).into()
+                        )
+                    ]
+                    .into_iter()
+                    .collect(),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            Ok(core::types::Record::new(vec![
+                core::types::Value::Number.into(),
+                core::types::Value::Boolean.into()
+            ])
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_tuple_types() {
+        assert_eq!(
+            TypeCompiler::new(&ast::Module::new(vec![])).compile(
+                &types::Tuple::new(
+                    vec![
+                        types::Number::new(SourceInformation::dummy()).into(),
+                        types::Function::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            types::Boolean::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            Ok(core::types::Tuple::new(vec![
+                core::types::Value::Number.into(),
+                core::types::Function::new(
+                    vec![core::types::Value::Number.into()],
+                    core::types::Value::Boolean
+                )
+                .into()
+            ])
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_recursive_algebraic_types_with_lists() {
+        let module = ast::Module::with_type_definitions(
+            vec![ast::TypeDefinition::new(
+                "Tree",
+                vec![ast::ConstructorDefinition::new(
+                    "Node",
+                    vec![types::List::new(
+                        types::Reference::new("Tree", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    SourceInformation::dummy(),
+                )],
+                SourceInformation::dummy(),
+            )],
+            vec![],
+        );
+
+        assert_eq!(
+            TypeCompiler::new(&module)
+                .compile(&types::Reference::new("Tree", SourceInformation::dummy()).into()),
+            Ok(
+                core::types::Algebraic::new(vec![core::types::Constructor::new(vec![
+                    core::types::Algebraic::new(vec![
+                        core::types::Constructor::new(vec![]),
+                        core::types::Constructor::new(vec![
+                            core::types::Value::Index(1).into(),
+                            core::types::Value::Index(0).into()
+                        ]),
+                    ])
+                    .into()
+                ])])
+                .into()
+            )
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use super::error::TypeInferenceError;
+use crate::debug::SourceInformation;
+use crate::types::{self, Type};
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Constraint {
+    Equatable,
+    Numeric,
+    Ordered,
+    Showable,
+}
+
+impl Constraint {
+    pub fn accepts(self, type_: &Type) -> bool {
+        match self {
+            Self::Equatable => matches!(
+                type_,
+                Type::Boolean(_) | Type::Char(_) | Type::Int(_) | Type::Number(_) | Type::String(_)
+            ),
+            Self::Numeric => matches!(type_, Type::Int(_) | Type::Number(_)),
+            Self::Ordered => matches!(type_, Type::Char(_) | Type::Int(_) | Type::Number(_)),
+            Self::Showable => matches!(
+                type_,
+                Type::Char(_) | Type::Int(_) | Type::Number(_) | Type::String(_)
+            ),
+        }
+    }
+
+    pub fn default_type(self, source_information: &Rc<SourceInformation>) -> Type {
+        match self {
+            Self::Equatable | Self::Numeric | Self::Ordered => {
+                types::Number::new(source_information.clone()).into()
+            }
+            Self::Showable => types::String::new(source_information.clone()).into(),
+        }
+    }
+
+    pub fn error(self, source_information: &Rc<SourceInformation>) -> TypeInferenceError {
+        match self {
+            Self::Equatable => TypeInferenceError::TypeNotEquatable(source_information.clone()),
+            Self::Numeric => TypeInferenceError::TypeNotNumeric(source_information.clone()),
+            Self::Ordered => TypeInferenceError::TypeNotOrdered(source_information.clone()),
+            Self::Showable => TypeInferenceError::TypeNotShowable(source_information.clone()),
+        }
+    }
+}
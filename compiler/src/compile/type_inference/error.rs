@@ -5,6 +5,19 @@ use std::rc::Rc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TypeInferenceError {
+    CircularInitialization(Rc<SourceInformation>),
+    CircularTypeAlias(String, Rc<SourceInformation>),
+    ConstructorNotFound(String, Rc<SourceInformation>),
+    DuplicateConstructor(String, Rc<SourceInformation>),
+    DuplicateDefinition(String, Rc<SourceInformation>),
+    DuplicateType(String, Rc<SourceInformation>),
+    RecordElementNotFound(String, Rc<SourceInformation>),
+    TypeAmbiguous(Rc<SourceInformation>),
+    TypeNotEquatable(Rc<SourceInformation>),
+    TypeNotFound(String, Rc<SourceInformation>),
+    TypeNotNumeric(Rc<SourceInformation>),
+    TypeNotOrdered(Rc<SourceInformation>),
+    TypeNotShowable(Rc<SourceInformation>),
     TypesNotMatched(Rc<SourceInformation>, Rc<SourceInformation>),
     VariableNotFound(String, Rc<SourceInformation>),
 }
@@ -14,6 +27,71 @@ impl TypeInferenceError {}
 impl Display for TypeInferenceError {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match self {
+            TypeInferenceError::CircularInitialization(source_information) => write!(
+                formatter,
+                "TypeInferenceError: Initialization of value is circular\n{}",
+                source_information
+            ),
+            TypeInferenceError::CircularTypeAlias(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type alias \"{}\" is circular\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::ConstructorNotFound(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Constructor \"{}\" not found\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::DuplicateConstructor(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Constructor \"{}\" is defined more than once\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::DuplicateDefinition(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Variable \"{}\" is defined more than once\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::DuplicateType(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type \"{}\" is defined more than once\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::RecordElementNotFound(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Record element \"{}\" not found\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::TypeAmbiguous(source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type is ambiguous\n{}",
+                source_information
+            ),
+            TypeInferenceError::TypeNotEquatable(source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type must be Boolean, Char, Int, Number or String\n{}",
+                source_information
+            ),
+            TypeInferenceError::TypeNotFound(name, source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type \"{}\" not found\n{}",
+                name, source_information
+            ),
+            TypeInferenceError::TypeNotNumeric(source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type must be Int or Number\n{}",
+                source_information
+            ),
+            TypeInferenceError::TypeNotOrdered(source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type must be Char, Int or Number\n{}",
+                source_information
+            ),
+            TypeInferenceError::TypeNotShowable(source_information) => write!(
+                formatter,
+                "TypeInferenceError: Type must be Char, Int, Number or String\n{}",
+                source_information
+            ),
             TypeInferenceError::TypesNotMatched(lhs_source_information, rhs_source_information) => {
                 write!(
                     formatter,
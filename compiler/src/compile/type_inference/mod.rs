@@ -1,6 +1,8 @@
+mod constraint;
 mod equation;
 mod error;
 mod type_inferer;
+mod type_scheme;
 
 use crate::ast::*;
 pub use error::*;
@@ -16,7 +18,7 @@ mod test {
     use super::infer_types;
     use crate::ast::*;
     use crate::debug::*;
-    use crate::types;
+    use crate::types::{self, Type};
 
     #[test]
     fn infer_types_with_empty_modules() {
@@ -27,7 +29,11 @@ mod test {
     fn infer_types_of_variables() {
         let module = Module::new(vec![ValueDefinition::new(
             "x",
-            Number::new(42.0, SourceInformation::dummy()),
+            Number::with_type(
+                types::Number::new(SourceInformation::dummy()),
+                42.0,
+                SourceInformation::dummy(),
+            ),
             types::Number::new(SourceInformation::dummy()),
             SourceInformation::dummy(),
         )
@@ -39,7 +45,11 @@ mod test {
     fn fail_to_infer_types_of_variables() {
         let module = Module::new(vec![ValueDefinition::new(
             "x",
-            Number::new(42.0, SourceInformation::dummy()),
+            Number::with_type(
+                types::Number::new(SourceInformation::dummy()),
+                42.0,
+                SourceInformation::dummy(),
+            ),
             types::Function::new(
                 types::Number::new(SourceInformation::dummy()),
                 types::Number::new(SourceInformation::dummy()),
@@ -63,7 +73,11 @@ mod test {
         let module = Module::new(vec![FunctionDefinition::new(
             "f",
             vec!["x".into()],
-            Number::new(42.0, SourceInformation::dummy()),
+            Number::with_type(
+                types::Number::new(SourceInformation::dummy()),
+                42.0,
+                SourceInformation::dummy(),
+            ),
             types::Function::new(
                 types::Number::new(SourceInformation::dummy()),
                 types::Number::new(SourceInformation::dummy()),
@@ -81,7 +95,11 @@ mod test {
         let module = Module::new(vec![FunctionDefinition::new(
             "f",
             vec!["x".into()],
-            Number::new(42.0, SourceInformation::dummy()),
+            Number::with_type(
+                types::Number::new(SourceInformation::dummy()),
+                42.0,
+                SourceInformation::dummy(),
+            ),
             types::Function::new(
                 types::Number::new(SourceInformation::dummy()),
                 types::Function::new(
@@ -110,7 +128,11 @@ mod test {
             FunctionDefinition::new(
                 "f",
                 vec!["x".into()],
-                Number::new(42.0, SourceInformation::dummy()),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
                 types::Function::new(
                     types::Number::new(SourceInformation::dummy()),
                     types::Number::new(SourceInformation::dummy()),
@@ -123,7 +145,11 @@ mod test {
                 "x",
                 Application::new(
                     Variable::new("f", SourceInformation::dummy()),
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
                     SourceInformation::dummy(),
                 ),
                 types::Number::new(SourceInformation::dummy()),
@@ -141,7 +167,11 @@ mod test {
             FunctionDefinition::new(
                 "f",
                 vec!["x".into()],
-                Number::new(42.0, SourceInformation::dummy()),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
                 types::Function::new(
                     types::Number::new(SourceInformation::dummy()),
                     types::Number::new(SourceInformation::dummy()),
@@ -155,10 +185,18 @@ mod test {
                 Application::new(
                     Application::new(
                         Variable::new("f", SourceInformation::dummy()),
-                        Number::new(42.0, SourceInformation::dummy()),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
                         SourceInformation::dummy(),
                     ),
-                    Number::new(42.0, SourceInformation::dummy()),
                     SourceInformation::dummy(),
                 ),
                 types::Number::new(SourceInformation::dummy()),
@@ -183,7 +221,11 @@ mod test {
             Let::new(
                 vec![ValueDefinition::new(
                     "y",
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
                     types::Number::new(SourceInformation::dummy()),
                     SourceInformation::dummy(),
                 )
@@ -205,7 +247,11 @@ mod test {
             Let::new(
                 vec![ValueDefinition::new(
                     "y",
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
                     types::Function::new(
                         types::Number::new(SourceInformation::dummy()),
                         types::Number::new(SourceInformation::dummy()),
@@ -249,7 +295,11 @@ mod test {
                 .into()],
                 Application::new(
                     Variable::new("f", SourceInformation::dummy()),
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
                     SourceInformation::dummy(),
                 ),
             ),
@@ -284,7 +334,11 @@ mod test {
                 .into()],
                 Application::new(
                     Variable::new("f", SourceInformation::dummy()),
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
                     SourceInformation::dummy(),
                 ),
             ),
@@ -310,7 +364,11 @@ mod test {
                 Let::new(
                     vec![ValueDefinition::new(
                         "y",
-                        Number::new(42.0, SourceInformation::dummy()),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy()
+                        ),
                         types::Variable::new(SourceInformation::dummy()),
                         SourceInformation::dummy()
                     )
@@ -326,7 +384,11 @@ mod test {
                 Let::new(
                     vec![ValueDefinition::new(
                         "y",
-                        Number::new(42.0, SourceInformation::dummy()),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy()
+                        ),
                         types::Number::new(SourceInformation::dummy()),
                         SourceInformation::dummy()
                     )
@@ -341,10 +403,125 @@ mod test {
     }
 
     #[test]
-    fn fail_to_infer_types_with_missing_variables() {
+    fn fail_to_infer_types_of_circular_initialization() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![
+                        FunctionDefinition::new(
+                            "f",
+                            vec!["z".into()],
+                            Variable::new("y", SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into(),
+                        ValueDefinition::new(
+                            "y",
+                            Application::new(
+                                Variable::new("f", SourceInformation::dummy()),
+                                Number::with_type(
+                                    types::Number::new(SourceInformation::dummy()),
+                                    42.0,
+                                    SourceInformation::dummy()
+                                ),
+                                SourceInformation::dummy()
+                            ),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    Variable::new("y", SourceInformation::dummy()),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::CircularInitialization(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_circular_initialization_of_values() {
+        let value_definition = |name: &str, other_name: &str| -> Definition {
+            ValueDefinition::new(
+                name,
+                Variable::new(other_name, SourceInformation::dummy()),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![value_definition("a", "b"), value_definition("b", "a")],
+                    Variable::new("a", SourceInformation::dummy()),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::CircularInitialization(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_if() {
         let module = Module::new(vec![ValueDefinition::new(
             "x",
-            Variable::new("y", SourceInformation::dummy()),
+            If::new(
+                Boolean::new(true, SourceInformation::dummy()),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    13.0,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(infer_types(&module), Ok(module));
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_if_with_non_boolean_conditions() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    13.0,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
             types::Number::new(SourceInformation::dummy()),
             SourceInformation::dummy(),
         )
@@ -352,8 +529,1948 @@ mod test {
 
         assert_eq!(
             infer_types(&module),
-            Err(TypeInferenceError::VariableNotFound(
-                "y".into(),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_if_with_different_branch_types() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(
+                Boolean::new(true, SourceInformation::dummy()),
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+                Boolean::new(false, SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_comparisons() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(
+                Operator::And,
+                Operation::new(
+                    Operator::LessThan,
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        13.0,
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                Operation::new(
+                    Operator::Equal,
+                    Boolean::new(true, SourceInformation::dummy()),
+                    Boolean::new(false, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            types::Boolean::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(infer_types(&module), Ok(module));
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_equality_of_different_types() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(
+                Operator::Equal,
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+                Boolean::new(false, SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Boolean::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_equality_of_algebraic_types() {
+        let module = Module::with_type_definitions(
+            vec![TypeDefinition::new(
+                "Nat",
+                vec![ConstructorDefinition::new(
+                    "Zero",
+                    vec![],
+                    SourceInformation::dummy(),
+                )],
+                SourceInformation::dummy(),
+            )],
+            vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Equal,
+                    Constructor::new("Zero", SourceInformation::dummy()),
+                    Constructor::new("Zero", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Boolean::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()],
+        );
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::TypeNotEquatable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_equality_of_tuples() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(
+                Operator::NotEqual,
+                Tuple::new(vec![], SourceInformation::dummy()),
+                Tuple::new(vec![], SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Boolean::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::TypeNotEquatable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_negations() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Negation::new(
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(infer_types(&module), Ok(module));
+    }
+
+    #[test]
+    fn infer_int_types_of_number_literals() {
+        let create_module = |type_: Type| {
+            Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Operation::new(
+                    Operator::Add,
+                    Variable::new("x", SourceInformation::dummy()),
+                    Number::with_type(type_, 1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Int::new(SourceInformation::dummy()),
+                    types::Int::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Int::new(SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn default_types_of_number_literals_to_numbers() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Ok(Module::new(vec![ValueDefinition::new(
+                "x",
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy()
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_number_literals_of_non_numeric_types() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Add,
+                    Boolean::new(true, SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotNumeric(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_polymorphic_functions_with_numeric_bodies() {
+        assert_eq!(
+            infer_types(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Operation::new(
+                    Operator::Add,
+                    Variable::new("x", SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Variable::with_name("a", SourceInformation::dummy()),
+                    types::Variable::with_name("a", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotNumeric(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_floor_divisions_of_numbers() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::FloorDivide,
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        1.5,
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_math_operations() {
+        let create_module = |type_: Type| {
+            Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Remainder,
+                    Number::with_type(type_.clone(), 42.0, SourceInformation::dummy()),
+                    Number::with_type(type_, 5.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Int::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Int::new(SourceInformation::dummy()).into()
+            ))
+        );
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            BuiltInCall::new(
+                BuiltInFunction::SquareRoot,
+                vec![Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                )
+                .into()],
+                SourceInformation::dummy(),
+            ),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(infer_types(&module), Ok(module));
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_powers_of_integers() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Power,
+                    Number::new(2.0, SourceInformation::dummy()),
+                    Number::new(3.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Int::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_strings() {
+        let create_module = |type_: Type| {
+            Module::new(vec![ValueDefinition::new(
+                "x",
+                BuiltInCall::new(
+                    BuiltInFunction::StringLength,
+                    vec![Operation::new(
+                        Operator::Concatenate,
+                        StringLiteral::new("foo", SourceInformation::dummy()),
+                        Conversion::new(
+                            types::String::new(SourceInformation::dummy()),
+                            Number::with_type(type_, 42.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    SourceInformation::dummy(),
+                ),
+                types::Int::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Number::new(SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_to_string_function() {
+        let create_module = |argument: Expression| {
+            Module::new(vec![ValueDefinition::new(
+                "x",
+                Application::new(
+                    Variable::new("toString", SourceInformation::dummy()),
+                    argument,
+                    SourceInformation::dummy(),
+                ),
+                types::String::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert!(infer_types(&create_module(
+            Number::new(42.0, SourceInformation::dummy()).into()
+        ))
+        .is_ok());
+        assert!(infer_types(&create_module(
+            StringLiteral::new("foo", SourceInformation::dummy()).into()
+        ))
+        .is_ok());
+        assert_eq!(
+            infer_types(&create_module(
+                Boolean::new(true, SourceInformation::dummy()).into()
+            )),
+            Err(TypeInferenceError::TypeNotShowable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_chars() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(
+                Operator::LessThan,
+                CharLiteral::new('a', SourceInformation::dummy()),
+                Conversion::new(
+                    types::Char::new(SourceInformation::dummy()),
+                    Conversion::new(
+                        types::Int::new(SourceInformation::dummy()),
+                        CharLiteral::new('b', SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            types::Boolean::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(infer_types(&module), Ok(module));
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_arithmetic_operations_of_chars() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Add,
+                    CharLiteral::new('a', SourceInformation::dummy()),
+                    CharLiteral::new('b', SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Char::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotNumeric(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_comparisons_of_strings() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::LessThan,
+                    StringLiteral::new("a", SourceInformation::dummy()),
+                    StringLiteral::new("b", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Boolean::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotOrdered(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_polymorphic_functions_with_comparisons() {
+        assert_eq!(
+            infer_types(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Operation::new(
+                    Operator::LessThan,
+                    Variable::new("x", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Variable::with_name("a", SourceInformation::dummy()),
+                    types::Boolean::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotOrdered(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_concatenation_of_numbers() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Concatenate,
+                    StringLiteral::new("foo", SourceInformation::dummy()),
+                    Number::new(42.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::String::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotNumeric(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_polymorphic_functions_with_showable_bodies() {
+        assert_eq!(
+            infer_types(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Conversion::new(
+                    types::String::new(SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Variable::with_name("a", SourceInformation::dummy()),
+                    types::String::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotShowable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_bitwise_operations_of_numbers() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::BitwiseAnd,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_negations_of_non_numbers() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Negation::new(
+                Boolean::new(true, SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_missing_variables() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Variable::new("y", SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::VariableNotFound(
+                "y".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_polymorphic_functions() {
+        let type_variable = types::Variable::with_name("a", SourceInformation::dummy());
+        let create_module = |boolean_function: Variable, number_function: Variable| {
+            Module::new(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        type_variable.clone(),
+                        type_variable.clone(),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "x",
+                    If::new(
+                        Application::new(
+                            boolean_function,
+                            Boolean::new(true, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        Application::new(
+                            number_function,
+                            Number::with_type(
+                                types::Number::new(SourceInformation::dummy()),
+                                42.0,
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            13.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                Variable::new("f", SourceInformation::dummy()),
+                Variable::new("f", SourceInformation::dummy())
+            )),
+            Ok(create_module(
+                Variable::with_type(
+                    "f",
+                    types::Function::new(
+                        types::Boolean::new(SourceInformation::dummy()),
+                        types::Boolean::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                ),
+                Variable::with_type(
+                    "f",
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_polymorphic_functions_with_concrete_bodies() {
+        let type_variable = types::Variable::with_name("a", SourceInformation::dummy());
+
+        assert_eq!(
+            infer_types(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy()
+                ),
+                types::Function::new(
+                    type_variable.clone(),
+                    type_variable,
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_polymorphic_functions_with_unified_type_variables() {
+        let module = Module::new(vec![FunctionDefinition::new(
+            "f",
+            vec!["x".into(), "y".into()],
+            If::new(
+                Boolean::new(true, SourceInformation::dummy()),
+                Variable::new("x", SourceInformation::dummy()),
+                Variable::new("y", SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Function::new(
+                types::Variable::with_name("a", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Variable::with_name("b", SourceInformation::dummy()),
+                    types::Variable::with_name("c", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_polymorphic_let_functions() {
+        let create_module = |boolean_function: Variable, number_function: Variable| {
+            Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["y".into()],
+                        Variable::new("y", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Variable::new(SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    If::new(
+                        Application::new(
+                            boolean_function,
+                            Boolean::new(true, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        Application::new(
+                            number_function,
+                            Number::with_type(
+                                types::Number::new(SourceInformation::dummy()),
+                                42.0,
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            13.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                Variable::new("f", SourceInformation::dummy()),
+                Variable::new("f", SourceInformation::dummy())
+            )),
+            Ok(create_module(
+                Variable::with_type(
+                    "f",
+                    types::Function::new(
+                        types::Boolean::new(SourceInformation::dummy()),
+                        types::Boolean::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                ),
+                Variable::with_type(
+                    "f",
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_untyped_definitions() {
+        assert_eq!(
+            infer_types(&Module::new(vec![
+                ValueDefinition::new(
+                    "x",
+                    Variable::new("y", SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "y",
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy()
+                    ),
+                    types::Variable::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ])),
+            Ok(Module::new(vec![
+                ValueDefinition::new(
+                    "x",
+                    Variable::new("y", SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "y",
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy()
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ]))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_untyped_polymorphic_functions() {
+        let create_module = |boolean_function: Variable, number_function: Variable| {
+            Module::new(vec![
+                FunctionDefinition::new(
+                    "f",
+                    vec!["y".into()],
+                    Variable::new("y", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Variable::new(SourceInformation::dummy()),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "x",
+                    If::new(
+                        Application::new(
+                            boolean_function,
+                            Boolean::new(true, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        Application::new(
+                            number_function,
+                            Number::with_type(
+                                types::Number::new(SourceInformation::dummy()),
+                                42.0,
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            13.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                Variable::new("f", SourceInformation::dummy()),
+                Variable::new("f", SourceInformation::dummy())
+            )),
+            Ok(create_module(
+                Variable::with_type(
+                    "f",
+                    types::Function::new(
+                        types::Boolean::new(SourceInformation::dummy()),
+                        types::Boolean::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                ),
+                Variable::with_type(
+                    "f",
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_unused_polymorphic_lambdas() {
+        assert!(infer_types(&Module::new(vec![ValueDefinition::new(
+            "f",
+            Lambda::new(
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Variable::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]))
+        .is_ok());
+    }
+
+    #[test]
+    fn infer_types_of_unused_polymorphic_let_lambdas() {
+        assert!(infer_types(&Module::new(vec![ValueDefinition::new(
+            "x",
+            Let::new(
+                vec![ValueDefinition::new(
+                    "f",
+                    Lambda::new(
+                        vec!["y".into()],
+                        Variable::new("y", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Variable::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy(),
+                ),
+            ),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]))
+        .is_ok());
+    }
+
+    #[test]
+    fn infer_types_of_polymorphic_lambdas() {
+        assert!(infer_types(&Module::new(vec![
+            ValueDefinition::new(
+                "f",
+                Lambda::new(
+                    vec!["y".into()],
+                    Variable::new("y", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+            ValueDefinition::new(
+                "x",
+                If::new(
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Boolean::new(true, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        13.0,
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+        ]))
+        .is_ok());
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_recursive_let_functions_with_infinite_types() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["y".into()],
+                        Variable::new("f", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Variable::new(SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy()
+                    ),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_ambiguous_types() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Let::new(
+                    vec![ValueDefinition::new(
+                        "y",
+                        Let::new(
+                            vec![FunctionDefinition::new(
+                                "f",
+                                vec!["z".into()],
+                                Variable::new("z", SourceInformation::dummy()),
+                                types::Function::new(
+                                    types::Variable::new(SourceInformation::dummy()),
+                                    types::Variable::new(SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                                SourceInformation::dummy(),
+                            )
+                            .into()],
+                            Variable::new("f", SourceInformation::dummy()),
+                        ),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy()
+                    ),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeAmbiguous(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_lambdas() {
+        let create_module = |type_: Type| {
+            Module::new(vec![ValueDefinition::new(
+                "f",
+                Lambda::with_type(
+                    type_,
+                    vec!["x".into()],
+                    Operation::new(
+                        Operator::Add,
+                        Variable::new("x", SourceInformation::dummy()),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_lambdas_with_wrong_arguments() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Application::new(
+                    Lambda::new(
+                        vec!["y".into()],
+                        Operation::new(
+                            Operator::Add,
+                            Variable::new("y", SourceInformation::dummy()),
+                            Number::with_type(
+                                types::Number::new(SourceInformation::dummy()),
+                                42.0,
+                                SourceInformation::dummy()
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    Boolean::new(true, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    fn shape_type_definition() -> TypeDefinition {
+        TypeDefinition::new(
+            "Shape",
+            vec![
+                ConstructorDefinition::new(
+                    "Circle",
+                    vec![types::Number::new(SourceInformation::dummy()).into()],
+                    SourceInformation::dummy(),
+                ),
+                ConstructorDefinition::new(
+                    "Rect",
+                    vec![
+                        types::Number::new(SourceInformation::dummy()).into(),
+                        types::Number::new(SourceInformation::dummy()).into(),
+                    ],
+                    SourceInformation::dummy(),
+                ),
+            ],
+            SourceInformation::dummy(),
+        )
+    }
+
+    #[test]
+    fn infer_types_of_constructors() {
+        let module = Module::with_type_definitions(
+            vec![shape_type_definition()],
+            vec![ValueDefinition::new(
+                "x",
+                Application::new(
+                    Constructor::new("Circle", SourceInformation::dummy()),
+                    Number::with_type(
+                        types::Number::new(SourceInformation::dummy()),
+                        42.0,
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Reference::new("Shape", SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()],
+        );
+
+        assert_eq!(infer_types(&module), Ok(module));
+    }
+
+    #[test]
+    fn infer_types_of_lists() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let list_type: Type =
+            types::List::new(number_type.clone(), SourceInformation::dummy()).into();
+        let create_module = |cons_type: Option<Type>, nil_type: Option<Type>| {
+            let constructor = |name, type_: Option<Type>| match type_ {
+                Some(type_) => Constructor::with_type(name, type_, SourceInformation::dummy()),
+                None => Constructor::new(name, SourceInformation::dummy()),
+            };
+
+            Module::new(vec![ValueDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(
+                        constructor(CONS_CONSTRUCTOR_NAME, cons_type),
+                        Number::with_type(number_type.clone(), 42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    constructor(NIL_CONSTRUCTOR_NAME, nil_type),
+                    SourceInformation::dummy(),
+                ),
+                list_type.clone(),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(None, None)),
+            Ok(create_module(
+                Some(
+                    types::Function::new(
+                        number_type.clone(),
+                        types::Function::new(
+                            list_type.clone(),
+                            list_type.clone(),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    )
+                    .into()
+                ),
+                Some(list_type.clone())
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_empty_lists() {
+        let list_type: Type = types::List::new(
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Constructor::new(NIL_CONSTRUCTOR_NAME, SourceInformation::dummy()),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Ok(Module::new(vec![ValueDefinition::new(
+                "x",
+                Constructor::with_type(
+                    NIL_CONSTRUCTOR_NAME,
+                    list_type.clone(),
+                    SourceInformation::dummy()
+                ),
+                list_type,
+                SourceInformation::dummy(),
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_lists_with_different_element_types() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(
+                        Constructor::new(CONS_CONSTRUCTOR_NAME, SourceInformation::dummy()),
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::List::new(
+                    types::Boolean::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_tuples() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let tuple_type: Type = types::Tuple::new(
+            vec![
+                number_type.clone(),
+                types::Boolean::new(SourceInformation::dummy()).into(),
+            ],
+            SourceInformation::dummy(),
+        )
+        .into();
+        let create_module = |type_: Type| {
+            Module::new(vec![
+                ValueDefinition::new(
+                    "x",
+                    Tuple::new(
+                        vec![
+                            Number::with_type(
+                                number_type.clone(),
+                                42.0,
+                                SourceInformation::dummy(),
+                            )
+                            .into(),
+                            Boolean::new(true, SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    tuple_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "y",
+                    Case::with_type(
+                        type_,
+                        Variable::new("x", SourceInformation::dummy()),
+                        vec![Alternative::new(
+                            TuplePattern::new(
+                                vec![
+                                    Variable::new("z", SourceInformation::dummy()).into(),
+                                    Wildcard::new(SourceInformation::dummy()).into(),
+                                ],
+                                SourceInformation::dummy(),
+                            ),
+                            Variable::new("z", SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                    number_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(tuple_type.clone()))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_tuples_with_different_lengths() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Tuple::new(
+                    vec![
+                        Number::new(1.0, SourceInformation::dummy()).into(),
+                        Number::new(2.0, SourceInformation::dummy()).into(),
+                    ],
+                    SourceInformation::dummy(),
+                ),
+                types::Tuple::new(
+                    vec![number_type.clone(), number_type.clone(), number_type],
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_records() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let record_type: Type = types::Record::new(
+            vec![("a".into(), number_type.clone())]
+                .into_iter()
+                .collect(),
+            SourceInformation::dummy(),
+        )
+        .into();
+        let create_module = |type_: Type| {
+            Module::new(vec![
+                ValueDefinition::new(
+                    "x",
+                    Record::with_type(
+                        type_.clone(),
+                        vec![(
+                            "a".into(),
+                            Number::with_type(
+                                number_type.clone(),
+                                42.0,
+                                SourceInformation::dummy(),
+                            )
+                            .into(),
+                        )]
+                        .into_iter()
+                        .collect(),
+                        SourceInformation::dummy(),
+                    ),
+                    record_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "y",
+                    RecordElement::with_type(
+                        type_.clone(),
+                        Variable::new("x", SourceInformation::dummy()),
+                        "a",
+                        SourceInformation::dummy(),
+                    ),
+                    number_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                ValueDefinition::new(
+                    "z",
+                    RecordUpdate::with_type(
+                        type_,
+                        Variable::new("x", SourceInformation::dummy()),
+                        vec![(
+                            "a".into(),
+                            Variable::new("y", SourceInformation::dummy()).into(),
+                        )]
+                        .into_iter()
+                        .collect(),
+                        SourceInformation::dummy(),
+                    ),
+                    record_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(record_type.clone()))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_records_with_different_elements() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Record::new(
+                    vec![(
+                        "a".into(),
+                        Number::new(42.0, SourceInformation::dummy()).into(),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    SourceInformation::dummy(),
+                ),
+                types::Record::new(
+                    vec![(
+                        "b".into(),
+                        types::Number::new(SourceInformation::dummy()).into(),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_record_elements() {
+        let record = Record::new(
+            vec![(
+                "a".into(),
+                Number::new(42.0, SourceInformation::dummy()).into(),
+            )]
+            .into_iter()
+            .collect(),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                RecordElement::new(record, "b", SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::RecordElementNotFound(
+                "b".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+
+        assert_eq!(
+            infer_types(&Module::new(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                RecordElement::new(
+                    Variable::new("x", SourceInformation::dummy()),
+                    "a",
+                    SourceInformation::dummy()
+                ),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeAmbiguous(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_of_case_expressions() {
+        let create_module = |type_: Type| {
+            Module::with_type_definitions(
+                vec![shape_type_definition()],
+                vec![FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Case::with_type(
+                        type_,
+                        Variable::new("x", SourceInformation::dummy()),
+                        vec![
+                            Alternative::new(
+                                ConstructorPattern::new(
+                                    "Circle",
+                                    vec![Variable::new("r", SourceInformation::dummy()).into()],
+                                    SourceInformation::dummy(),
+                                ),
+                                Variable::new("r", SourceInformation::dummy()),
+                            ),
+                            Alternative::new(
+                                ConstructorPattern::new(
+                                    "Rect",
+                                    vec![
+                                        Wildcard::new(SourceInformation::dummy()).into(),
+                                        Number::with_type(
+                                            types::Number::new(SourceInformation::dummy()),
+                                            1.0,
+                                            SourceInformation::dummy(),
+                                        )
+                                        .into(),
+                                    ],
+                                    SourceInformation::dummy(),
+                                ),
+                                Number::with_type(
+                                    types::Number::new(SourceInformation::dummy()),
+                                    2.0,
+                                    SourceInformation::dummy(),
+                                ),
+                            ),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    types::Function::new(
+                        types::Reference::new("Shape", SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+            )
+        };
+
+        assert_eq!(
+            infer_types(&create_module(
+                types::Variable::new(SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                types::Reference::new("Shape", SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_case_expressions_with_wrong_patterns() {
+        assert_eq!(
+            infer_types(&Module::with_type_definitions(
+                vec![shape_type_definition()],
+                vec![ValueDefinition::new(
+                    "x",
+                    Case::new(
+                        Number::with_type(
+                            types::Number::new(SourceInformation::dummy()),
+                            42.0,
+                            SourceInformation::dummy()
+                        ),
+                        vec![Alternative::new(
+                            ConstructorPattern::new(
+                                "Circle",
+                                vec![Wildcard::new(SourceInformation::dummy()).into()],
+                                SourceInformation::dummy(),
+                            ),
+                            Number::with_type(
+                                types::Number::new(SourceInformation::dummy()),
+                                42.0,
+                                SourceInformation::dummy()
+                            ),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+            )),
+            Err(TypeInferenceError::TypesNotMatched(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_missing_constructors() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Constructor::new("Circle", SourceInformation::dummy()),
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::ConstructorNotFound(
+                "Circle".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_missing_types() {
+        assert_eq!(
+            infer_types(&Module::new(vec![ValueDefinition::new(
+                "x",
+                Number::with_type(
+                    types::Number::new(SourceInformation::dummy()),
+                    42.0,
+                    SourceInformation::dummy()
+                ),
+                types::Reference::new("Shape", SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            Err(TypeInferenceError::TypeNotFound(
+                "Shape".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+
+        assert_eq!(
+            infer_types(&Module::with_type_definitions(
+                vec![TypeDefinition::new(
+                    "Box",
+                    vec![ConstructorDefinition::new(
+                        "Box",
+                        vec![types::Variable::with_name("a", SourceInformation::dummy()).into()],
+                        SourceInformation::dummy(),
+                    )],
+                    SourceInformation::dummy(),
+                )],
+                vec![],
+            )),
+            Err(TypeInferenceError::TypeNotFound(
+                "a".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_types_with_type_aliases() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let value_definition = |type_: Type| -> Definition {
+            ValueDefinition::new(
+                "x",
+                Number::with_type(number_type.clone(), 42.0, SourceInformation::dummy()),
+                type_,
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+
+        assert_eq!(
+            infer_types(&Module::with_type_aliases(
+                vec![],
+                vec![
+                    TypeAlias::new(
+                        "Meters",
+                        types::Reference::new("Distance", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    TypeAlias::new("Distance", number_type.clone(), SourceInformation::dummy())
+                ],
+                vec![value_definition(
+                    types::Reference::new("Meters", SourceInformation::dummy()).into()
+                )],
+            )),
+            Ok(Module::new(vec![value_definition(number_type.clone())]))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_circular_type_aliases() {
+        let reference = |name: &str| types::Reference::new(name, SourceInformation::dummy());
+
+        assert_eq!(
+            infer_types(&Module::with_type_aliases(
+                vec![],
+                vec![TypeAlias::new(
+                    "Node",
+                    types::Record::new(
+                        vec![("next".into(), reference("Node").into())]
+                            .into_iter()
+                            .collect(),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )],
+                vec![],
+            )),
+            Err(TypeInferenceError::CircularTypeAlias(
+                "Node".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+
+        assert_eq!(
+            infer_types(&Module::with_type_aliases(
+                vec![],
+                vec![
+                    TypeAlias::new("A", reference("B"), SourceInformation::dummy()),
+                    TypeAlias::new("B", reference("A"), SourceInformation::dummy())
+                ],
+                vec![],
+            )),
+            Err(TypeInferenceError::CircularTypeAlias(
+                "A".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_duplicate_types() {
+        assert_eq!(
+            infer_types(&Module::with_type_definitions(
+                vec![
+                    TypeDefinition::new("Shape", vec![], SourceInformation::dummy()),
+                    TypeDefinition::new("Shape", vec![], SourceInformation::dummy()),
+                ],
+                vec![],
+            )),
+            Err(TypeInferenceError::DuplicateType(
+                "Shape".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+
+        assert_eq!(
+            infer_types(&Module::with_type_aliases(
+                vec![TypeDefinition::new(
+                    "Shape",
+                    vec![],
+                    SourceInformation::dummy()
+                )],
+                vec![TypeAlias::new(
+                    "Shape",
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )],
+                vec![],
+            )),
+            Err(TypeInferenceError::DuplicateType(
+                "Shape".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_duplicate_definitions() {
+        let definition: Definition = ValueDefinition::new(
+            "x",
+            Number::new(42.0, SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            infer_types(&Module::new(vec![definition.clone(), definition])),
+            Err(TypeInferenceError::DuplicateDefinition(
+                "x".into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_types_with_duplicate_constructors() {
+        assert_eq!(
+            infer_types(&Module::with_type_definitions(
+                vec![
+                    TypeDefinition::new(
+                        "Foo",
+                        vec![ConstructorDefinition::new(
+                            "A",
+                            vec![types::Number::new(SourceInformation::dummy()).into()],
+                            SourceInformation::dummy(),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                    TypeDefinition::new(
+                        "Bar",
+                        vec![ConstructorDefinition::new(
+                            "A",
+                            vec![types::Boolean::new(SourceInformation::dummy()).into()],
+                            SourceInformation::dummy(),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                ],
+                vec![],
+            )),
+            Err(TypeInferenceError::DuplicateConstructor(
+                "A".into(),
                 SourceInformation::dummy().into()
             ))
         );
@@ -1,60 +1,416 @@
+use super::super::definition_sorter::DefinitionSorter;
+use super::super::prelude::TO_STRING_FUNCTION_NAME;
+use super::constraint::*;
 use super::equation::*;
 use super::error::*;
+use super::type_scheme::*;
 use crate::ast::*;
+use crate::debug::SourceInformation;
 use crate::types::{self, Type};
 use std::collections::*;
+use std::rc::Rc;
 
 #[derive(Clone, Debug)]
 pub struct TypeInferer {
     equations: Vec<Equation>,
+    substitutions: HashMap<usize, Type>,
+    generalized_variables: HashSet<usize>,
+    constrained_variables: Vec<(types::Variable, Constraint)>,
+    list_element_variables: Vec<types::Variable>,
+    record_elements: Vec<(Type, String, Type)>,
+    type_names: HashSet<String>,
+    constructors: HashMap<String, Type>,
 }
 
 impl TypeInferer {
     pub fn new() -> Self {
-        Self { equations: vec![] }
+        Self {
+            equations: vec![],
+            substitutions: HashMap::new(),
+            generalized_variables: HashSet::new(),
+            constrained_variables: vec![],
+            list_element_variables: vec![],
+            record_elements: vec![],
+            type_names: HashSet::new(),
+            constructors: HashMap::new(),
+        }
     }
 
     pub fn infer(&mut self, module: &Module) -> Result<Module, TypeInferenceError> {
-        self.collect_equations(module)?;
-        Ok(module.substitute_type_variables(&self.reduce_equations()?))
+        let module = self.resolve_type_aliases(module)?;
+
+        self.register_type_definitions(module.type_definitions())?;
+        self.check_duplicate_definitions(module.definitions())?;
+        self.check_signatures(&module)?;
+
+        let module = self.collect_equations(&module)?;
+
+        self.default_constrained_types()?;
+        self.default_list_element_types()?;
+        self.check_record_elements()?;
+
+        let module = module.substitute_type_variables(&self.substitutions);
+
+        self.check_ambiguity(&module)?;
+
+        Ok(module)
     }
 
-    fn collect_equations(&mut self, module: &Module) -> Result<(), TypeInferenceError> {
-        let mut variables = HashMap::<&str, Type>::new();
+    fn collect_equations(&mut self, module: &Module) -> Result<Module, TypeInferenceError> {
+        let (typed_definitions, untyped_definitions): (Vec<_>, Vec<_>) =
+            module.definitions().iter().partition(|definition| {
+                definition
+                    .type_()
+                    .variables()
+                    .iter()
+                    .all(|variable| variable.name().is_some())
+            });
+        let mut variables = typed_definitions
+            .iter()
+            .map(|definition| (definition.name(), self.declare(definition.type_())))
+            .collect::<HashMap<_, _>>();
+        let mut inferred_definitions = HashMap::<&str, Definition>::new();
 
-        for definition in module.definitions() {
-            match definition {
-                Definition::FunctionDefinition(function_definition) => {
-                    variables.insert(
-                        function_definition.name(),
-                        function_definition.type_().clone(),
-                    );
+        for definitions in DefinitionSorter::new()
+            .sort(&untyped_definitions)
+            .into_iter()
+            .chain(vec![typed_definitions])
+        {
+            for definition in &definitions {
+                variables.remove(definition.name());
+            }
+
+            let (inferred, group_variables) =
+                self.infer_definitions(&definitions, &variables, true)?;
+
+            variables = group_variables;
+            inferred_definitions.extend(
+                definitions
+                    .iter()
+                    .map(|definition| definition.name())
+                    .zip(inferred),
+            );
+        }
+
+        Ok(Module::with_type_definitions(
+            module.type_definitions().to_vec(),
+            module
+                .definitions()
+                .iter()
+                .map(|definition| inferred_definitions[definition.name()].clone())
+                .collect(),
+        ))
+    }
+
+    fn check_duplicate_definitions(
+        &self,
+        definitions: &[Definition],
+    ) -> Result<(), TypeInferenceError> {
+        let mut names = HashSet::new();
+
+        for definition in definitions {
+            if !names.insert(definition.name()) {
+                return Err(TypeInferenceError::DuplicateDefinition(
+                    definition.name().into(),
+                    definition.source_information().clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn resolve_type_aliases(&self, module: &Module) -> Result<Module, TypeInferenceError> {
+        let mut type_aliases = HashMap::<&str, &TypeAlias>::new();
+
+        for type_alias in module.type_aliases() {
+            if module
+                .type_definitions()
+                .iter()
+                .any(|type_definition| type_definition.name() == type_alias.name())
+                || type_aliases.insert(type_alias.name(), type_alias).is_some()
+            {
+                return Err(TypeInferenceError::DuplicateType(
+                    type_alias.name().into(),
+                    type_alias.source_information().clone(),
+                ));
+            }
+        }
+
+        for type_alias in module.type_aliases() {
+            self.resolve_type_alias(type_alias.type_(), &type_aliases, &[type_alias.name()])?;
+        }
+
+        let type_definitions = module
+            .type_definitions()
+            .iter()
+            .map(|type_definition| {
+                Ok(TypeDefinition::new(
+                    type_definition.name(),
+                    type_definition
+                        .constructors()
+                        .iter()
+                        .map(|constructor| {
+                            Ok(ConstructorDefinition::new(
+                                constructor.name(),
+                                constructor
+                                    .elements()
+                                    .iter()
+                                    .map(|type_| self.resolve_type_alias(type_, &type_aliases, &[]))
+                                    .collect::<Result<_, _>>()?,
+                                constructor.source_information().clone(),
+                            ))
+                        })
+                        .collect::<Result<_, _>>()?,
+                    type_definition.source_information().clone(),
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut result = Ok(());
+        let module = module.convert_definitions(&mut |definition| match self.resolve_type_alias(
+            definition.type_(),
+            &type_aliases,
+            &[],
+        ) {
+            Ok(type_) => match definition {
+                Definition::FunctionDefinition(function_definition) => FunctionDefinition::new(
+                    function_definition.name(),
+                    function_definition.arguments().to_vec(),
+                    function_definition.body().clone(),
+                    type_,
+                    function_definition.source_information().clone(),
+                )
+                .into(),
+                Definition::ValueDefinition(value_definition) => ValueDefinition::new(
+                    value_definition.name(),
+                    value_definition.body().clone(),
+                    type_,
+                    value_definition.source_information().clone(),
+                )
+                .into(),
+            },
+            Err(error) => {
+                if result.is_ok() {
+                    result = Err(error);
                 }
-                Definition::ValueDefinition(value_definition) => {
-                    variables.insert(value_definition.name(), value_definition.type_().clone());
+
+                definition.clone()
+            }
+        });
+
+        result?;
+
+        Ok(Module::with_type_definitions(
+            type_definitions,
+            module.definitions().to_vec(),
+        ))
+    }
+
+    fn resolve_type_alias(
+        &self,
+        type_: &Type,
+        type_aliases: &HashMap<&str, &TypeAlias>,
+        names: &[&str],
+    ) -> Result<Type, TypeInferenceError> {
+        let mut result = Ok(());
+        let type_ = type_.convert_types(&mut |type_| match type_ {
+            Type::Reference(reference) if result.is_ok() => {
+                match type_aliases.get(reference.name()) {
+                    Some(type_alias) if names.contains(&type_alias.name()) => {
+                        result = Err(TypeInferenceError::CircularTypeAlias(
+                            type_alias.name().into(),
+                            reference.source_information().clone(),
+                        ));
+
+                        type_.clone()
+                    }
+                    Some(type_alias) => {
+                        match self.resolve_type_alias(
+                            type_alias.type_(),
+                            type_aliases,
+                            &names
+                                .iter()
+                                .cloned()
+                                .chain(vec![type_alias.name()])
+                                .collect::<Vec<_>>(),
+                        ) {
+                            Ok(type_) => type_,
+                            Err(error) => {
+                                result = Err(error);
+
+                                type_.clone()
+                            }
+                        }
+                    }
+                    None => type_.clone(),
                 }
             }
-        }
+            _ => type_.clone(),
+        });
+
+        result.map(|_| type_)
+    }
+
+    fn register_type_definitions(
+        &mut self,
+        type_definitions: &[TypeDefinition],
+    ) -> Result<(), TypeInferenceError> {
+        for type_definition in type_definitions {
+            if !self.type_names.insert(type_definition.name().into()) {
+                return Err(TypeInferenceError::DuplicateType(
+                    type_definition.name().into(),
+                    type_definition.source_information().clone(),
+                ));
+            }
 
-        for definition in module.definitions() {
-            match definition {
-                Definition::FunctionDefinition(function_definition) => {
-                    self.infer_function_definition(function_definition, &variables)?;
+            for constructor in type_definition.constructors() {
+                if self
+                    .constructors
+                    .insert(
+                        constructor.name().into(),
+                        type_definition.constructor_type(constructor),
+                    )
+                    .is_some()
+                {
+                    return Err(TypeInferenceError::DuplicateConstructor(
+                        constructor.name().into(),
+                        constructor.source_information().clone(),
+                    ));
                 }
-                Definition::ValueDefinition(value_definition) => {
-                    self.infer_value_definition(value_definition, &variables)?;
+            }
+        }
+
+        for type_definition in type_definitions {
+            for constructor in type_definition.constructors() {
+                for type_ in constructor.elements() {
+                    if let Some(variable) = type_.variables().first() {
+                        return Err(TypeInferenceError::TypeNotFound(
+                            variable.name().unwrap_or_default().into(),
+                            variable.source_information().clone(),
+                        ));
+                    }
+
+                    self.check_type_references(type_)?;
                 }
-            };
+            }
         }
 
         Ok(())
     }
 
-    fn infer_function_definition(
+    fn check_signatures(&self, module: &Module) -> Result<(), TypeInferenceError> {
+        let mut result = Ok(());
+
+        module.convert_definitions(&mut |definition| {
+            if result.is_ok() {
+                result = self.check_type_references(definition.type_());
+            }
+
+            definition.clone()
+        });
+
+        result
+    }
+
+    fn check_type_references(&self, type_: &Type) -> Result<(), TypeInferenceError> {
+        match type_ {
+            Type::Function(function) => {
+                self.check_type_references(function.argument())?;
+                self.check_type_references(function.result())
+            }
+            Type::List(list) => self.check_type_references(list.element()),
+            Type::Record(record) => {
+                for type_ in record.elements().values() {
+                    self.check_type_references(type_)?;
+                }
+
+                Ok(())
+            }
+            Type::Tuple(tuple) => {
+                for type_ in tuple.elements() {
+                    self.check_type_references(type_)?;
+                }
+
+                Ok(())
+            }
+            Type::Reference(reference) => {
+                if self.type_names.contains(reference.name()) {
+                    Ok(())
+                } else {
+                    Err(TypeInferenceError::TypeNotFound(
+                        reference.name().into(),
+                        reference.source_information().clone(),
+                    ))
+                }
+            }
+            Type::Boolean(_)
+            | Type::Char(_)
+            | Type::Int(_)
+            | Type::Number(_)
+            | Type::String(_)
+            | Type::Variable(_) => Ok(()),
+        }
+    }
+
+    fn infer_definitions<'a>(
         &mut self,
-        function_definition: &FunctionDefinition,
-        variables: &HashMap<&str, Type>,
-    ) -> Result<(), TypeInferenceError> {
+        definitions: &[&'a Definition],
+        variables: &HashMap<&'a str, TypeScheme>,
+        recursive: bool,
+    ) -> Result<(Vec<Definition>, HashMap<&'a str, TypeScheme>), TypeInferenceError> {
+        let mut definition_variables = variables.clone();
+
+        if recursive {
+            for definition in definitions {
+                definition_variables.insert(definition.name(), self.declare(definition.type_()));
+            }
+        }
+
+        let definitions = definitions
+            .iter()
+            .map(|definition| {
+                Ok((
+                    *definition,
+                    match definition {
+                        Definition::FunctionDefinition(function_definition) => self
+                            .infer_function_definition(function_definition, &definition_variables)?
+                            .into(),
+                        Definition::ValueDefinition(value_definition) => self
+                            .infer_value_definition(value_definition, &definition_variables)?
+                            .into(),
+                    },
+                ))
+            })
+            .collect::<Result<Vec<(_, Definition)>, _>>()?;
+
+        self.reduce_equations()?;
+
+        let environment_variables = self.find_environment_variables(variables);
+        let mut variables = variables.clone();
+
+        for (definition, _) in &definitions {
+            self.check_declared_type(definition.type_(), &environment_variables)?;
+            variables.insert(
+                definition.name(),
+                self.generalize(definition, &environment_variables),
+            );
+        }
+
+        Ok((
+            definitions
+                .into_iter()
+                .map(|(_, definition)| definition)
+                .collect(),
+            variables,
+        ))
+    }
+
+    fn infer_function_definition<'a>(
+        &mut self,
+        function_definition: &'a FunctionDefinition,
+        variables: &HashMap<&'a str, TypeScheme>,
+    ) -> Result<FunctionDefinition, TypeInferenceError> {
         let source_information = function_definition.source_information();
         let mut variables = variables.clone();
         let mut type_ = function_definition.type_().clone();
@@ -73,137 +429,884 @@ impl TypeInferer {
                 .into(),
             ));
 
-            variables.insert(argument_name, argument_type);
+            variables.insert(argument_name, TypeScheme::new(vec![], argument_type));
 
             type_ = result_type;
         }
 
-        let body_type = self.infer_expression(function_definition.body(), &variables)?;
+        let (body, body_type) = self.infer_expression(function_definition.body(), &variables)?;
         self.equations.push(Equation::new(body_type, type_));
 
-        Ok(())
+        Ok(FunctionDefinition::new(
+            function_definition.name(),
+            function_definition.arguments().to_vec(),
+            body,
+            function_definition.type_().clone(),
+            source_information.clone(),
+        ))
     }
 
-    fn infer_value_definition(
+    fn infer_value_definition<'a>(
         &mut self,
-        value_definition: &ValueDefinition,
-        variables: &HashMap<&str, Type>,
-    ) -> Result<(), TypeInferenceError> {
-        let type_ = self.infer_expression(value_definition.body(), &variables)?;
+        value_definition: &'a ValueDefinition,
+        variables: &HashMap<&'a str, TypeScheme>,
+    ) -> Result<ValueDefinition, TypeInferenceError> {
+        let (body, type_) = self.infer_expression(value_definition.body(), &variables)?;
 
         self.equations
             .push(Equation::new(type_, value_definition.type_().clone()));
 
-        Ok(())
+        Ok(ValueDefinition::new(
+            value_definition.name(),
+            body,
+            value_definition.type_().clone(),
+            value_definition.source_information().clone(),
+        ))
     }
 
-    fn infer_expression(
+    fn infer_expression<'a>(
         &mut self,
-        expression: &Expression,
-        variables: &HashMap<&str, Type>,
-    ) -> Result<Type, TypeInferenceError> {
+        expression: &'a Expression,
+        variables: &HashMap<&'a str, TypeScheme>,
+    ) -> Result<(Expression, Type), TypeInferenceError> {
         match expression {
             Expression::Application(application) => {
-                let function = self.infer_expression(application.function(), variables)?;
-                let argument = self.infer_expression(application.argument(), variables)?;
+                let (function, function_type) =
+                    self.infer_expression(application.function(), variables)?;
+                let (argument, argument_type) =
+                    self.infer_expression(application.argument(), variables)?;
                 let result: Type =
                     types::Variable::new(application.source_information().clone()).into();
 
                 self.equations.push(Equation::new(
-                    function,
+                    function_type,
                     types::Function::new(
-                        argument,
+                        argument_type,
                         result.clone(),
                         application.source_information().clone(),
                     )
                     .into(),
                 ));
 
-                Ok(result)
+                Ok((
+                    Application::new(function, argument, application.source_information().clone())
+                        .into(),
+                    result,
+                ))
             }
-            Expression::Let(let_) => {
-                let mut variables = variables.clone();
+            Expression::Boolean(boolean) => Ok((
+                boolean.clone().into(),
+                types::Boolean::new(boolean.source_information().clone()).into(),
+            )),
+            Expression::Case(case) => {
+                let source_information = case.source_information();
+                let (argument, argument_type) =
+                    self.infer_expression(case.argument(), variables)?;
+                let type_: Type = types::Variable::new(source_information.clone()).into();
 
-                for definition in let_.definitions() {
-                    match definition {
-                        Definition::FunctionDefinition(function_definition) => {
-                            variables.insert(
-                                function_definition.name(),
-                                function_definition.type_().clone(),
-                            );
-                        }
-                        Definition::ValueDefinition(_) => {}
+                self.equations
+                    .push(Equation::new(argument_type.clone(), case.type_().clone()));
+
+                let alternatives = case
+                    .alternatives()
+                    .iter()
+                    .map(|alternative| {
+                        let mut variables = variables.clone();
+                        let pattern_type =
+                            self.infer_pattern(alternative.pattern(), &mut variables)?;
+                        self.equations
+                            .push(Equation::new(pattern_type, argument_type.clone()));
+
+                        let (expression, expression_type) =
+                            self.infer_expression(alternative.expression(), &variables)?;
+                        self.equations
+                            .push(Equation::new(expression_type, type_.clone()));
+
+                        Ok(Alternative::new(alternative.pattern().clone(), expression))
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok((
+                    Case::with_type(
+                        case.type_().clone(),
+                        argument,
+                        alternatives,
+                        source_information.clone(),
+                    )
+                    .into(),
+                    type_,
+                ))
+            }
+            Expression::CharLiteral(char_literal) => Ok((
+                char_literal.clone().into(),
+                types::Char::new(char_literal.source_information().clone()).into(),
+            )),
+            Expression::Constructor(constructor) => {
+                let type_ =
+                    self.infer_constructor(constructor.name(), constructor.source_information())?;
+
+                Ok((
+                    if type_.variables().is_empty() {
+                        constructor.clone()
+                    } else {
+                        Constructor::with_type(
+                            constructor.name(),
+                            type_.clone(),
+                            constructor.source_information().clone(),
+                        )
+                    }
+                    .into(),
+                    type_,
+                ))
+            }
+            Expression::BuiltInCall(built_in_call) => {
+                let (argument_types, type_) = self.infer_built_in_function(
+                    built_in_call.function(),
+                    built_in_call.source_information(),
+                );
+                let arguments = built_in_call
+                    .arguments()
+                    .iter()
+                    .zip(argument_types)
+                    .map(|(argument, type_)| {
+                        let (argument, argument_type) =
+                            self.infer_expression(argument, variables)?;
+
+                        self.equations.push(Equation::new(argument_type, type_));
+
+                        Ok(argument)
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok((
+                    BuiltInCall::new(
+                        built_in_call.function(),
+                        arguments,
+                        built_in_call.source_information().clone(),
+                    )
+                    .into(),
+                    type_,
+                ))
+            }
+            Expression::Conversion(conversion) => {
+                let source_information = conversion.source_information();
+                let type_ = match conversion.type_() {
+                    Type::Char(_) => types::Int::new(source_information.clone()).into(),
+                    Type::String(_) => {
+                        self.create_constrained_variable(Constraint::Showable, source_information)
                     }
+                    _ => self.create_constrained_variable(Constraint::Ordered, source_information),
+                };
+                let (expression, expression_type) =
+                    self.infer_expression(conversion.expression(), variables)?;
+
+                self.equations.push(Equation::new(expression_type, type_));
+
+                Ok((
+                    Conversion::new(
+                        conversion.type_().clone(),
+                        expression,
+                        conversion.source_information().clone(),
+                    )
+                    .into(),
+                    conversion.type_().clone(),
+                ))
+            }
+            Expression::If(if_) => {
+                let (condition, condition_type) =
+                    self.infer_expression(if_.condition(), variables)?;
+                self.equations.push(Equation::new(
+                    condition_type,
+                    types::Boolean::new(if_.source_information().clone()).into(),
+                ));
+
+                let (then, then_type) = self.infer_expression(if_.then(), variables)?;
+                let (else_, else_type) = self.infer_expression(if_.else_(), variables)?;
+                self.equations
+                    .push(Equation::new(then_type.clone(), else_type));
+
+                Ok((
+                    If::new(condition, then, else_, if_.source_information().clone()).into(),
+                    then_type,
+                ))
+            }
+            Expression::Lambda(lambda) => {
+                let source_information = lambda.source_information();
+                let mut variables = variables.clone();
+                let argument_types = lambda
+                    .arguments()
+                    .iter()
+                    .map(|argument| {
+                        let type_: Type = types::Variable::new(source_information.clone()).into();
+
+                        variables.insert(argument, TypeScheme::new(vec![], type_.clone()));
+
+                        type_
+                    })
+                    .collect::<Vec<_>>();
+
+                let (body, body_type) = self.infer_expression(lambda.body(), &variables)?;
+                let type_ = argument_types
+                    .into_iter()
+                    .rev()
+                    .fold(body_type, |result, argument| {
+                        types::Function::new(argument, result, source_information.clone()).into()
+                    });
+
+                self.equations
+                    .push(Equation::new(type_.clone(), lambda.type_().clone()));
+
+                Ok((
+                    Lambda::with_type(
+                        lambda.type_().clone(),
+                        lambda.arguments().to_vec(),
+                        body,
+                        source_information.clone(),
+                    )
+                    .into(),
+                    type_,
+                ))
+            }
+            Expression::Let(let_) => {
+                let value_definitions = let_
+                    .definitions()
+                    .iter()
+                    .filter(|definition| match definition {
+                        Definition::FunctionDefinition(_) => false,
+                        Definition::ValueDefinition(_) => true,
+                    })
+                    .collect::<Vec<_>>();
+
+                if !value_definitions.is_empty()
+                    && value_definitions.len() < let_.definitions().len()
+                {
+                    return Err(TypeInferenceError::CircularInitialization(
+                        value_definitions[0].source_information().clone(),
+                    ));
                 }
 
+                if let Some(definitions) = DefinitionSorter::new()
+                    .sort(&value_definitions)
+                    .into_iter()
+                    .find(|definitions| definitions.len() > 1)
+                {
+                    return Err(TypeInferenceError::CircularInitialization(
+                        definitions[0].source_information().clone(),
+                    ));
+                }
+
+                let (function_definitions, mut variables) = self.infer_definitions(
+                    &let_
+                        .definitions()
+                        .iter()
+                        .filter(|definition| match definition {
+                            Definition::FunctionDefinition(_) => true,
+                            Definition::ValueDefinition(_) => false,
+                        })
+                        .collect::<Vec<_>>(),
+                    variables,
+                    true,
+                )?;
+
+                let mut function_definitions = function_definitions.into_iter();
+                let mut definitions = vec![];
+
                 for definition in let_.definitions() {
                     match definition {
-                        Definition::FunctionDefinition(function_definition) => {
-                            self.infer_function_definition(function_definition, &variables)?;
-                        }
-                        Definition::ValueDefinition(value_definition) => {
-                            self.infer_value_definition(value_definition, &variables)?;
+                        Definition::FunctionDefinition(_) => definitions
+                            .push(function_definitions.next().expect("function definition")),
+                        Definition::ValueDefinition(_) => {
+                            let (value_definitions, value_variables) =
+                                self.infer_definitions(&[definition], &variables, false)?;
 
-                            variables
-                                .insert(value_definition.name(), value_definition.type_().clone());
+                            definitions.extend(value_definitions);
+                            variables = value_variables;
                         }
                     }
                 }
 
-                self.infer_expression(let_.expression(), &variables)
+                let (expression, type_) = self.infer_expression(let_.expression(), &variables)?;
+
+                Ok((Let::new(definitions, expression).into(), type_))
+            }
+            Expression::Match(_) => unreachable!(),
+            Expression::EmptyListFailure(empty_list_failure) => Ok((
+                empty_list_failure.clone().into(),
+                empty_list_failure.type_().clone(),
+            )),
+            Expression::MatchFailure(match_failure) => {
+                Ok((match_failure.clone().into(), match_failure.type_().clone()))
+            }
+            Expression::Negation(negation) => {
+                let type_ = self.create_constrained_variable(
+                    Constraint::Numeric,
+                    negation.source_information(),
+                );
+                let (expression, expression_type) =
+                    self.infer_expression(negation.expression(), variables)?;
+
+                self.equations
+                    .push(Equation::new(expression_type, type_.clone()));
+
+                Ok((
+                    Negation::new(expression, negation.source_information().clone()).into(),
+                    type_,
+                ))
             }
             Expression::Number(number) => {
-                Ok(types::Number::new(number.source_information().clone()).into())
+                let type_ = self.infer_number(number);
+
+                Ok((number.clone().into(), type_))
             }
             Expression::Operation(operation) => {
-                let type_: Type = types::Number::new(operation.source_information().clone()).into();
+                let source_information = operation.source_information();
+                let boolean_type: Type = types::Boolean::new(source_information.clone()).into();
+
+                let (lhs, lhs_type) = self.infer_expression(operation.lhs(), variables)?;
+                let (rhs, rhs_type) = self.infer_expression(operation.rhs(), variables)?;
+
+                let type_ = match operation.operator() {
+                    Operator::Add
+                    | Operator::Subtract
+                    | Operator::Multiply
+                    | Operator::Remainder => {
+                        let type_ = self
+                            .create_constrained_variable(Constraint::Numeric, source_information);
+
+                        self.equations.push(Equation::new(lhs_type, type_.clone()));
+                        self.equations.push(Equation::new(rhs_type, type_.clone()));
+
+                        type_
+                    }
+                    Operator::Divide | Operator::Power => {
+                        let number_type: Type =
+                            types::Number::new(source_information.clone()).into();
 
-                let lhs = self.infer_expression(operation.lhs(), variables)?;
-                self.equations.push(Equation::new(lhs, type_.clone()));
-                let rhs = self.infer_expression(operation.rhs(), variables)?;
-                self.equations.push(Equation::new(rhs, type_.clone()));
+                        self.equations
+                            .push(Equation::new(lhs_type, number_type.clone()));
+                        self.equations
+                            .push(Equation::new(rhs_type, number_type.clone()));
+
+                        number_type
+                    }
+                    Operator::FloorDivide
+                    | Operator::Modulo
+                    | Operator::BitwiseAnd
+                    | Operator::BitwiseOr
+                    | Operator::BitwiseXor
+                    | Operator::ShiftLeft
+                    | Operator::ShiftRight => {
+                        let int_type: Type = types::Int::new(source_information.clone()).into();
+
+                        self.equations
+                            .push(Equation::new(lhs_type, int_type.clone()));
+                        self.equations
+                            .push(Equation::new(rhs_type, int_type.clone()));
+
+                        int_type
+                    }
+                    Operator::Concatenate => {
+                        let string_type: Type =
+                            types::String::new(source_information.clone()).into();
+
+                        self.equations
+                            .push(Equation::new(lhs_type, string_type.clone()));
+                        self.equations
+                            .push(Equation::new(rhs_type, string_type.clone()));
+
+                        string_type
+                    }
+                    Operator::Equal | Operator::NotEqual => {
+                        let type_ = self
+                            .create_constrained_variable(Constraint::Equatable, source_information);
+
+                        self.equations.push(Equation::new(lhs_type, type_.clone()));
+                        self.equations.push(Equation::new(rhs_type, type_));
+
+                        boolean_type
+                    }
+                    Operator::LessThan
+                    | Operator::LessThanOrEqual
+                    | Operator::GreaterThan
+                    | Operator::GreaterThanOrEqual => {
+                        let type_ = self
+                            .create_constrained_variable(Constraint::Ordered, source_information);
 
-                Ok(type_)
+                        self.equations.push(Equation::new(lhs_type, type_.clone()));
+                        self.equations.push(Equation::new(rhs_type, type_));
+
+                        boolean_type
+                    }
+                    Operator::And | Operator::Or => {
+                        self.equations
+                            .push(Equation::new(lhs_type, boolean_type.clone()));
+                        self.equations
+                            .push(Equation::new(rhs_type, boolean_type.clone()));
+
+                        boolean_type
+                    }
+                };
+
+                Ok((
+                    Operation::new(*operation.operator(), lhs, rhs, source_information.clone())
+                        .into(),
+                    type_,
+                ))
+            }
+            Expression::Record(record) => {
+                let mut elements = BTreeMap::new();
+                let mut types = BTreeMap::new();
+
+                for (name, element) in record.elements() {
+                    let (element, type_) = self.infer_expression(element, variables)?;
+
+                    elements.insert(name.clone(), element);
+                    types.insert(name.clone(), type_);
+                }
+
+                let type_: Type =
+                    types::Record::new(types, record.source_information().clone()).into();
+
+                Ok((
+                    Record::with_type(type_.clone(), elements, record.source_information().clone())
+                        .into(),
+                    type_,
+                ))
+            }
+            Expression::RecordElement(record_element) => {
+                let (record, record_type) =
+                    self.infer_expression(record_element.record(), variables)?;
+                let type_: Type =
+                    types::Variable::new(record_element.source_information().clone()).into();
+
+                self.record_elements.push((
+                    record_type.clone(),
+                    record_element.name().into(),
+                    type_.clone(),
+                ));
+
+                Ok((
+                    RecordElement::with_type(
+                        record_type,
+                        record,
+                        record_element.name(),
+                        record_element.source_information().clone(),
+                    )
+                    .into(),
+                    type_,
+                ))
+            }
+            Expression::RecordUpdate(record_update) => {
+                let (record, type_) = self.infer_expression(record_update.record(), variables)?;
+                let mut elements = BTreeMap::new();
+
+                for (name, element) in record_update.elements() {
+                    let (element, element_type) = self.infer_expression(element, variables)?;
+
+                    self.record_elements
+                        .push((type_.clone(), name.clone(), element_type));
+                    elements.insert(name.clone(), element);
+                }
+
+                Ok((
+                    RecordUpdate::with_type(
+                        type_.clone(),
+                        record,
+                        elements,
+                        record_update.source_information().clone(),
+                    )
+                    .into(),
+                    type_,
+                ))
+            }
+            Expression::StringLiteral(string_literal) => Ok((
+                string_literal.clone().into(),
+                types::String::new(string_literal.source_information().clone()).into(),
+            )),
+            Expression::Tuple(tuple) => {
+                let mut elements = vec![];
+                let mut types = vec![];
+
+                for element in tuple.elements() {
+                    let (element, type_) = self.infer_expression(element, variables)?;
+
+                    elements.push(element);
+                    types.push(type_);
+                }
+
+                Ok((
+                    Tuple::new(elements, tuple.source_information().clone()).into(),
+                    types::Tuple::new(types, tuple.source_information().clone()).into(),
+                ))
             }
             Expression::Variable(variable) => {
-                variables.get(variable.name()).cloned().ok_or_else(|| {
+                if variable.name() == TO_STRING_FUNCTION_NAME
+                    && !variables.contains_key(variable.name())
+                {
+                    return self.infer_expression(
+                        &self.create_to_string_function(variable.source_information()),
+                        variables,
+                    );
+                }
+
+                let type_scheme = variables.get(variable.name()).ok_or_else(|| {
                     TypeInferenceError::VariableNotFound(
                         variable.name().into(),
                         variable.source_information().clone(),
                     )
-                })
+                })?;
+                let type_ = self.instantiate(type_scheme, variable.source_information().clone());
+
+                Ok((
+                    if type_scheme.variables().is_empty() {
+                        variable.clone()
+                    } else {
+                        Variable::with_type(
+                            variable.name(),
+                            type_.clone(),
+                            variable.source_information().clone(),
+                        )
+                    }
+                    .into(),
+                    type_,
+                ))
             }
         }
     }
 
-    fn reduce_equations(&mut self) -> Result<HashMap<usize, Type>, TypeInferenceError> {
-        let mut substitutions = HashMap::<usize, Type>::new();
+    fn infer_pattern<'a>(
+        &mut self,
+        pattern: &'a Pattern,
+        variables: &mut HashMap<&'a str, TypeScheme>,
+    ) -> Result<Type, TypeInferenceError> {
+        let source_information = pattern.source_information();
+
+        Ok(match pattern {
+            Pattern::Boolean(_) => types::Boolean::new(source_information.clone()).into(),
+            Pattern::Constructor(constructor_pattern) => {
+                let mut type_ =
+                    self.infer_constructor(constructor_pattern.name(), source_information)?;
+
+                for argument in constructor_pattern.arguments() {
+                    let argument_type = self.infer_pattern(argument, variables)?;
+                    let result_type: Type = types::Variable::new(source_information.clone()).into();
 
-        while let Some(equation) = self.equations.pop() {
-            let lhs = equation.lhs();
-            let rhs = equation.rhs();
+                    self.equations.push(Equation::new(
+                        type_,
+                        types::Function::new(
+                            argument_type,
+                            result_type.clone(),
+                            source_information.clone(),
+                        )
+                        .into(),
+                    ));
+
+                    type_ = result_type;
+                }
+
+                type_
+            }
+            Pattern::Number(number) => self.infer_number(number),
+            Pattern::Tuple(tuple_pattern) => types::Tuple::new(
+                tuple_pattern
+                    .elements()
+                    .iter()
+                    .map(|pattern| self.infer_pattern(pattern, variables))
+                    .collect::<Result<_, _>>()?,
+                source_information.clone(),
+            )
+            .into(),
+            Pattern::Variable(variable) => {
+                let type_: Type = types::Variable::new(source_information.clone()).into();
+
+                variables.insert(variable.name(), TypeScheme::new(vec![], type_.clone()));
+
+                type_
+            }
+            Pattern::Wildcard(_) => types::Variable::new(source_information.clone()).into(),
+        })
+    }
+
+    fn infer_number(&mut self, number: &Number) -> Type {
+        if let Type::Variable(variable) = number.type_() {
+            self.constrained_variables
+                .push((variable.clone(), Constraint::Numeric));
+        }
+
+        number.type_().clone()
+    }
+
+    fn create_constrained_variable(
+        &mut self,
+        constraint: Constraint,
+        source_information: &Rc<SourceInformation>,
+    ) -> Type {
+        let variable = types::Variable::new(source_information.clone());
+
+        self.constrained_variables
+            .push((variable.clone(), constraint));
+
+        variable.into()
+    }
+
+    fn create_to_string_function(&self, source_information: &Rc<SourceInformation>) -> Expression {
+        Lambda::new(
+            vec!["x".into()],
+            Conversion::new(
+                types::String::new(source_information.clone()),
+                Variable::new("x", source_information.clone()),
+                source_information.clone(),
+            ),
+            source_information.clone(),
+        )
+        .into()
+    }
+
+    fn infer_built_in_function(
+        &self,
+        function: BuiltInFunction,
+        source_information: &Rc<SourceInformation>,
+    ) -> (Vec<Type>, Type) {
+        let int_type: Type = types::Int::new(source_information.clone()).into();
+        let number_type: Type = types::Number::new(source_information.clone()).into();
+        let string_type: Type = types::String::new(source_information.clone()).into();
+
+        match function {
+            BuiltInFunction::Absolute
+            | BuiltInFunction::Ceiling
+            | BuiltInFunction::Cosine
+            | BuiltInFunction::Exponential
+            | BuiltInFunction::Floor
+            | BuiltInFunction::Logarithm
+            | BuiltInFunction::Sine
+            | BuiltInFunction::SquareRoot => (vec![number_type.clone()], number_type),
+            BuiltInFunction::PopCount => (vec![int_type.clone()], int_type),
+            BuiltInFunction::StringLength => (vec![string_type], int_type),
+            BuiltInFunction::StringSlice => (
+                vec![string_type.clone(), int_type.clone(), int_type],
+                string_type,
+            ),
+        }
+    }
+
+    fn find_constrained_variables(&self) -> HashMap<usize, Constraint> {
+        let mut variables = HashMap::new();
+
+        for (variable, constraint) in &self.constrained_variables {
+            if let Type::Variable(variable) =
+                Type::from(variable.clone()).substitute_variables(&self.substitutions)
+            {
+                variables.entry(variable.id()).or_insert(*constraint);
+            }
+        }
+
+        variables
+    }
+
+    fn find_record_element_variables(&self) -> HashSet<usize> {
+        self.record_elements
+            .iter()
+            .flat_map(|(record_type, _, type_)| {
+                record_type
+                    .substitute_variables(&self.substitutions)
+                    .variables()
+                    .into_iter()
+                    .chain(type_.substitute_variables(&self.substitutions).variables())
+                    .map(|variable| variable.id())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn default_constrained_types(&mut self) -> Result<(), TypeInferenceError> {
+        for constraint in &[
+            Constraint::Numeric,
+            Constraint::Ordered,
+            Constraint::Equatable,
+            Constraint::Showable,
+        ] {
+            for (variable, _) in self
+                .constrained_variables
+                .clone()
+                .into_iter()
+                .filter(|(_, other)| other == constraint)
+            {
+                match Type::from(variable.clone()).substitute_variables(&self.substitutions) {
+                    Type::Variable(substituted_variable) => {
+                        self.equations.push(Equation::new(
+                            substituted_variable.into(),
+                            constraint.default_type(variable.source_information()),
+                        ));
+                        self.reduce_equations()?;
+                    }
+                    type_ if constraint.accepts(&type_) => {}
+                    _ => return Err(constraint.error(variable.source_information())),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn default_list_element_types(&mut self) -> Result<(), TypeInferenceError> {
+        for variable in self.list_element_variables.clone() {
+            if let Type::Variable(substituted_variable) =
+                Type::from(variable.clone()).substitute_variables(&self.substitutions)
+            {
+                if !self
+                    .generalized_variables
+                    .contains(&substituted_variable.id())
+                {
+                    self.equations.push(Equation::new(
+                        substituted_variable.into(),
+                        types::Number::new(variable.source_information().clone()).into(),
+                    ));
+                    self.reduce_equations()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn infer_constructor(
+        &mut self,
+        name: &str,
+        source_information: &Rc<SourceInformation>,
+    ) -> Result<Type, TypeInferenceError> {
+        let mut list_type = || {
+            let element_type = types::Variable::new(source_information.clone());
+
+            self.list_element_variables.push(element_type.clone());
+
+            types::List::new(element_type, source_information.clone())
+        };
+
+        match name {
+            NIL_CONSTRUCTOR_NAME => Ok(list_type().into()),
+            CONS_CONSTRUCTOR_NAME => {
+                let list_type = list_type();
+
+                Ok(types::Function::new(
+                    list_type.element().clone(),
+                    types::Function::new(list_type.clone(), list_type, source_information.clone()),
+                    source_information.clone(),
+                )
+                .into())
+            }
+            _ => self.constructors.get(name).cloned().ok_or_else(|| {
+                TypeInferenceError::ConstructorNotFound(name.into(), source_information.clone())
+            }),
+        }
+    }
+
+    fn declare(&self, type_: &Type) -> TypeScheme {
+        TypeScheme::new(
+            type_
+                .variables()
+                .into_iter()
+                .filter(|variable| variable.name().is_some())
+                .map(|variable| variable.id())
+                .collect(),
+            type_.clone(),
+        )
+    }
+
+    fn instantiate(
+        &self,
+        type_scheme: &TypeScheme,
+        source_information: Rc<SourceInformation>,
+    ) -> Type {
+        type_scheme.type_().substitute_variables(
+            &type_scheme
+                .variables()
+                .iter()
+                .map(|id| (*id, types::Variable::new(source_information.clone()).into()))
+                .collect(),
+        )
+    }
+
+    fn generalize(
+        &mut self,
+        definition: &Definition,
+        environment_variables: &HashSet<usize>,
+    ) -> TypeScheme {
+        let type_ = definition.type_().substitute_variables(&self.substitutions);
+
+        let variables = match definition {
+            Definition::FunctionDefinition(_) => {
+                self.find_generalizable_variables(&type_, environment_variables)
+            }
+            Definition::ValueDefinition(value_definition) => match value_definition.body() {
+                Expression::Lambda(_) => {
+                    self.find_generalizable_variables(&type_, environment_variables)
+                }
+                _ => self
+                    .declare(definition.type_())
+                    .variables()
+                    .iter()
+                    .map(|id| match self.substitutions.get(id) {
+                        Some(Type::Variable(variable)) => variable.id(),
+                        _ => *id,
+                    })
+                    .collect(),
+            },
+        };
+
+        self.generalized_variables.extend(variables.iter().cloned());
+
+        TypeScheme::new(variables.into_iter().collect(), type_)
+    }
+
+    fn find_generalizable_variables(
+        &self,
+        type_: &Type,
+        environment_variables: &HashSet<usize>,
+    ) -> HashSet<usize> {
+        let constrained_variables = self.find_constrained_variables();
+        let record_element_variables = self.find_record_element_variables();
+
+        type_
+            .variables()
+            .into_iter()
+            .map(|variable| variable.id())
+            .filter(|id| {
+                !environment_variables.contains(id)
+                    && !constrained_variables.contains_key(id)
+                    && !record_element_variables.contains(id)
+            })
+            .collect()
+    }
+
+    fn reduce_equations(&mut self) -> Result<(), TypeInferenceError> {
+        while let Some(equation) = self.next_equation()? {
+            let lhs = &equation.lhs().substitute_variables(&self.substitutions);
+            let rhs = &equation.rhs().substitute_variables(&self.substitutions);
 
             match (lhs, rhs) {
                 (Type::Variable(variable), _) => {
                     if let Type::Variable(another_variable) = rhs {
                         if variable.id() == another_variable.id() {
-                            break;
+                            continue;
                         }
                     }
 
-                    for (_, substituted_type) in substitutions.iter_mut() {
-                        *substituted_type =
-                            substituted_type.clone().substitute_variable(variable, rhs);
+                    if rhs
+                        .variables()
+                        .iter()
+                        .any(|another_variable| variable.id() == another_variable.id())
+                    {
+                        return Err(TypeInferenceError::TypesNotMatched(
+                            lhs.source_information().clone(),
+                            rhs.source_information().clone(),
+                        ));
                     }
 
-                    for equation in self.equations.iter_mut() {
-                        *equation = Equation::new(
-                            equation.lhs().substitute_variable(variable, rhs),
-                            equation.rhs().substitute_variable(variable, rhs),
-                        )
+                    for (_, substituted_type) in self.substitutions.iter_mut() {
+                        *substituted_type = substituted_type.substitute_variable(variable, rhs);
                     }
 
-                    substitutions.insert(variable.id(), rhs.clone());
+                    self.substitutions.insert(variable.id(), rhs.clone());
                 }
                 (_, Type::Variable(_)) => {
                     self.equations.push(Equation::new(rhs.clone(), lhs.clone()))
@@ -218,7 +1321,35 @@ impl TypeInferer {
                         function2.result().clone(),
                     ));
                 }
+                (Type::List(list1), Type::List(list2)) => self.equations.push(Equation::new(
+                    list1.element().clone(),
+                    list2.element().clone(),
+                )),
+                (Type::Record(record1), Type::Record(record2))
+                    if record1.elements().keys().eq(record2.elements().keys()) =>
+                {
+                    for (type1, type2) in
+                        record1.elements().values().zip(record2.elements().values())
+                    {
+                        self.equations
+                            .push(Equation::new(type1.clone(), type2.clone()));
+                    }
+                }
+                (Type::Tuple(tuple1), Type::Tuple(tuple2))
+                    if tuple1.elements().len() == tuple2.elements().len() =>
+                {
+                    for (type1, type2) in tuple1.elements().iter().zip(tuple2.elements()) {
+                        self.equations
+                            .push(Equation::new(type1.clone(), type2.clone()));
+                    }
+                }
+                (Type::Boolean(_), Type::Boolean(_)) => {}
+                (Type::Char(_), Type::Char(_)) => {}
+                (Type::Int(_), Type::Int(_)) => {}
                 (Type::Number(_), Type::Number(_)) => {}
+                (Type::String(_), Type::String(_)) => {}
+                (Type::Reference(reference1), Type::Reference(reference2))
+                    if reference1.name() == reference2.name() => {}
                 (_, _) => {
                     return Err(TypeInferenceError::TypesNotMatched(
                         lhs.source_information().clone(),
@@ -228,6 +1359,161 @@ impl TypeInferer {
             }
         }
 
-        Ok(substitutions)
+        Ok(())
+    }
+
+    fn next_equation(&mut self) -> Result<Option<Equation>, TypeInferenceError> {
+        if self.equations.is_empty() {
+            self.reduce_record_elements()?;
+        }
+
+        Ok(self.equations.pop())
+    }
+
+    fn reduce_record_elements(&mut self) -> Result<(), TypeInferenceError> {
+        for (record_type, name, type_) in std::mem::take(&mut self.record_elements) {
+            match record_type.substitute_variables(&self.substitutions) {
+                Type::Record(record) => match record.elements().get(&name) {
+                    Some(element_type) => self
+                        .equations
+                        .push(Equation::new(type_, element_type.clone())),
+                    None => {
+                        return Err(TypeInferenceError::RecordElementNotFound(
+                            name,
+                            type_.source_information().clone(),
+                        ))
+                    }
+                },
+                Type::Variable(_) => self.record_elements.push((record_type, name, type_)),
+                substituted_type => {
+                    return Err(TypeInferenceError::TypesNotMatched(
+                        type_.source_information().clone(),
+                        substituted_type.source_information().clone(),
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_record_elements(&self) -> Result<(), TypeInferenceError> {
+        match self.record_elements.first() {
+            Some((_, _, type_)) => Err(TypeInferenceError::TypeAmbiguous(
+                type_.source_information().clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn find_environment_variables(&self, variables: &HashMap<&str, TypeScheme>) -> HashSet<usize> {
+        variables
+            .values()
+            .flat_map(|type_scheme| {
+                type_scheme
+                    .type_()
+                    .substitute_variables(&self.substitutions)
+                    .variables()
+                    .into_iter()
+                    .map(|variable| variable.id())
+                    .filter(|id| !type_scheme.variables().contains(id))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn check_declared_type(
+        &self,
+        type_: &Type,
+        environment_variables: &HashSet<usize>,
+    ) -> Result<(), TypeInferenceError> {
+        let mut variables = HashMap::<usize, &types::Variable>::new();
+        let constrained_variables = self.find_constrained_variables();
+
+        for variable in type_.variables() {
+            if variable.name().is_none() {
+                continue;
+            }
+
+            match Type::from(variable.clone()).substitute_variables(&self.substitutions) {
+                Type::Variable(substituted_variable)
+                    if constrained_variables.contains_key(&substituted_variable.id()) =>
+                {
+                    return Err(constrained_variables[&substituted_variable.id()]
+                        .error(variable.source_information()))
+                }
+                Type::Variable(substituted_variable)
+                    if !environment_variables.contains(&substituted_variable.id()) =>
+                {
+                    if let Some(another_variable) = variables.get(&substituted_variable.id()) {
+                        if another_variable.id() != variable.id() {
+                            return Err(TypeInferenceError::TypesNotMatched(
+                                another_variable.source_information().clone(),
+                                variable.source_information().clone(),
+                            ));
+                        }
+                    }
+
+                    variables.insert(substituted_variable.id(), variable);
+                }
+                substituted_type => {
+                    return Err(TypeInferenceError::TypesNotMatched(
+                        variable.source_information().clone(),
+                        substituted_type.source_information().clone(),
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_ambiguity(&self, module: &Module) -> Result<(), TypeInferenceError> {
+        let mut result = Ok(());
+
+        module.convert_expressions(&mut |expression| {
+            let typed_expression = match expression {
+                Expression::Constructor(constructor) => constructor
+                    .type_()
+                    .map(|type_| (type_, constructor.source_information())),
+                Expression::Variable(variable) => variable
+                    .type_()
+                    .map(|type_| (type_, variable.source_information())),
+                _ => None,
+            };
+
+            if let Some((type_, source_information)) = typed_expression {
+                if result.is_ok()
+                    && type_
+                        .variables()
+                        .iter()
+                        .any(|variable| !self.generalized_variables.contains(&variable.id()))
+                {
+                    result = Err(TypeInferenceError::TypeAmbiguous(
+                        source_information.clone(),
+                    ));
+                }
+            }
+
+            expression.clone()
+        });
+
+        module.convert_definitions(&mut |definition| {
+            if result.is_ok()
+                && definition
+                    .type_()
+                    .variables()
+                    .iter()
+                    .any(|variable| !self.generalized_variables.contains(&variable.id()))
+            {
+                result = Err(TypeInferenceError::TypeAmbiguous(
+                    definition.source_information().clone(),
+                ));
+            }
+
+            definition.clone()
+        });
+
+        result
     }
 }
@@ -0,0 +1,24 @@
+use crate::types::Type;
+
+#[derive(Clone, Debug)]
+pub struct TypeScheme {
+    variables: Vec<usize>,
+    type_: Type,
+}
+
+impl TypeScheme {
+    pub fn new(variables: Vec<usize>, type_: impl Into<Type>) -> Self {
+        Self {
+            variables,
+            type_: type_.into(),
+        }
+    }
+
+    pub fn variables(&self) -> &[usize] {
+        &self.variables
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+}
@@ -24,7 +24,7 @@ fn main() {
         .ok_or_else(|| invalid_input_error("no output file"))
         .unwrap_or_else(handle_error);
 
-    compile(
+    for warning in compile(
         &parse(
             &std::fs::read_to_string(input_filename).unwrap_or_else(handle_error),
             input_filename,
@@ -32,7 +32,10 @@ fn main() {
         .unwrap_or_else(handle_error),
         output_filename,
     )
-    .unwrap_or_else(handle_error);
+    .unwrap_or_else(handle_error)
+    {
+        eprintln!("{}", warning);
+    }
 }
 
 fn invalid_input_error(description: &str) -> std::io::Error {
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right,
+    None,
+}
@@ -1,22 +1,161 @@
+use super::associativity::Associativity;
+use super::fixity::Fixity;
 use super::input::Input;
+use super::let_binding::LetBinding;
+use super::parser_error::ParserError;
 use super::utilities::*;
-use crate::ast::*;
-use crate::debug::SourceInformation;
+use crate::ast::{self, *};
+use crate::debug::{Location, SourceInformation};
 use crate::types::{self, Type};
-use nom::{
-    branch::*, character::complete::*, combinator::*, error::*, multi::*, sequence::*, Err, IResult,
-};
+use nom::{branch::*, character::complete::*, combinator::*, error::*, multi::*, sequence::*, Err};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::rc::Rc;
 use std::str::FromStr;
 
-const KEYWORDS: &[&str] = &["in", "let"];
+type IResult<I, O> = nom::IResult<I, O, ParserError<I>>;
+
+const KEYWORDS: &[&str] = &[
+    "case",
+    "data",
+    "else",
+    "False",
+    "if",
+    "in",
+    "infix",
+    "infixl",
+    "infixr",
+    "let",
+    "of",
+    "otherwise",
+    "then",
+    "True",
+    "type",
+    "where",
+];
+const OPERATOR_CHARACTERS: &str = "!$%&*+./<=>?@^|-~:";
+const RESERVED_OPERATORS: &[&str] = &["=", ":", "->", "|"];
+const SECTION_LHS_NAME: &str = "section_lhs";
+const SECTION_RHS_NAME: &str = "section_rhs";
+const CLAUSE_ARGUMENT_NAME_PREFIX: &str = "clause_argument_";
 
 pub fn module(input: Input) -> IResult<Input, Module> {
-    terminated(
-        many0(terminated(typed_definition, line_break)),
-        tuple((convert_combinator(multispace0), eof)),
+    delimited(
+        blank_lines,
+        many0(terminated(
+            alt((
+                map(type_definition, |type_definition| {
+                    (Some(type_definition), None, None)
+                }),
+                map(fixity_declaration, |_| (None, None, None)),
+                map(type_alias, |type_alias| (None, Some(type_alias), None)),
+                map(definition, |definition| (None, None, Some(definition))),
+            )),
+            line_break,
+        )),
+        tuple((blank_lines, eof)),
+    )(input)
+    .map(|(input, items)| {
+        let mut type_definitions = vec![];
+        let mut type_aliases = vec![];
+        let mut definitions = vec![];
+
+        for (type_definition, type_alias, definition) in items {
+            type_definitions.extend(type_definition);
+            type_aliases.extend(type_alias);
+            definitions.extend(definition);
+        }
+
+        (
+            input,
+            Module::with_type_aliases(type_definitions, type_aliases, definitions),
+        )
+    })
+}
+
+fn type_definition(input: Input) -> IResult<Input, TypeDefinition> {
+    map(
+        tuple((
+            source_information,
+            keyword("data"),
+            capitalized_identifier,
+            keyword("="),
+            constructor_definition,
+            many0(preceded(keyword("|"), constructor_definition)),
+        )),
+        |(source_information, _, name, _, constructor, constructors)| {
+            TypeDefinition::new(
+                name,
+                [constructor].iter().chain(&constructors).cloned().collect(),
+                source_information,
+            )
+        },
+    )(input)
+}
+
+fn constructor_definition(input: Input) -> IResult<Input, ConstructorDefinition> {
+    map(
+        tuple((
+            source_information,
+            capitalized_identifier,
+            many0(atomic_type),
+        )),
+        |(source_information, name, elements)| {
+            ConstructorDefinition::new(name, elements, source_information)
+        },
+    )(input)
+}
+
+fn type_alias(input: Input) -> IResult<Input, TypeAlias> {
+    map(
+        tuple((
+            source_information,
+            keyword("type"),
+            capitalized_identifier,
+            keyword("="),
+            verify(type_, |type_: &Type| type_.variables().is_empty()),
+        )),
+        |(source_information, _, name, _, type_)| TypeAlias::new(name, type_, source_information),
     )(input)
-    .map(|(input, definitions)| (input, Module::new(definitions)))
+}
+
+fn fixity_declaration(original_input: Input) -> IResult<Input, ()> {
+    let (original_input, _) = blank(original_input)?;
+
+    tuple((
+        alt((
+            map(keyword("infixl"), |_| Associativity::Left),
+            map(keyword("infixr"), |_| Associativity::Right),
+            map(keyword("infix"), |_| Associativity::None),
+        )),
+        token(one_of("0123456789")),
+        operator,
+        many0(preceded(keyword(","), operator)),
+    ))(original_input.clone())
+    .and_then(|(input, (associativity, priority, operator, operators))| {
+        let fixity = Fixity::new(associativity, priority.to_digit(10).unwrap() as u8);
+
+        [operator]
+            .iter()
+            .chain(&operators)
+            .try_fold(input, |input, operator| {
+                if is_built_in_operator(operator) {
+                    Err(nom::Err::Failure(ParserError::BuiltInOperatorFixity(
+                        original_input.clone(),
+                    )))
+                } else if input.is_fixity_declared(operator) {
+                    Err(nom::Err::Failure(ParserError::DuplicateFixity(
+                        original_input.clone(),
+                    )))
+                } else if input.is_operator_used(operator) {
+                    Err(nom::Err::Failure(ParserError::FixityAfterUse(
+                        original_input.clone(),
+                    )))
+                } else {
+                    Ok(input.set_fixity(operator, fixity))
+                }
+            })
+            .map(|input| (input, ()))
+    })
 }
 
 fn typed_definition(input: Input) -> IResult<Input, Definition> {
@@ -43,24 +182,24 @@ fn definition(input: Input) -> IResult<Input, Definition> {
 fn function_definition(original_input: Input) -> IResult<Input, FunctionDefinition> {
     tuple((
         source_information,
-        identifier,
+        definition_name,
         keyword(":"),
-        type_,
+        signature,
         line_break,
-        identifier,
-        many1(identifier),
-        keyword("="),
-        body,
+        function_clauses,
     ))(original_input.clone())
     .and_then(
-        |(input, (source_information, name, _, type_, _, same_name, arguments, _, body))| {
+        |(input, (source_information, name, _, type_, _, (same_name, arguments, body)))| {
             if name == same_name {
                 Ok((
                     input,
                     FunctionDefinition::new(name, arguments, body, type_, source_information),
                 ))
             } else {
-                Err(nom::Err::Error((original_input, ErrorKind::Verify)))
+                Err(nom::Err::Error(ParserError::Nom(
+                    original_input,
+                    ErrorKind::Verify,
+                )))
             }
         },
     )
@@ -69,13 +208,13 @@ fn function_definition(original_input: Input) -> IResult<Input, FunctionDefiniti
 fn value_definition(original_input: Input) -> IResult<Input, ValueDefinition> {
     tuple((
         source_information,
-        identifier,
+        definition_name,
         keyword(":"),
-        type_,
+        signature,
         line_break,
-        identifier,
+        definition_name,
         keyword("="),
-        body,
+        definition_body,
     ))(original_input.clone())
     .and_then(
         |(input, (source_information, name, _, type_, _, same_name, _, body))| {
@@ -85,7 +224,10 @@ fn value_definition(original_input: Input) -> IResult<Input, ValueDefinition> {
                     ValueDefinition::new(name, body, type_, source_information),
                 ))
             } else {
-                Err(nom::Err::Error((original_input, ErrorKind::Verify)))
+                Err(nom::Err::Error(ParserError::Nom(
+                    original_input,
+                    ErrorKind::Verify,
+                )))
             }
         },
     )
@@ -93,14 +235,8 @@ fn value_definition(original_input: Input) -> IResult<Input, ValueDefinition> {
 
 fn untyped_function_definition(input: Input) -> IResult<Input, FunctionDefinition> {
     map(
-        tuple((
-            source_information,
-            identifier,
-            many1(identifier),
-            keyword("="),
-            body,
-        )),
-        |(source_information, name, arguments, _, body)| {
+        tuple((source_information, function_clauses)),
+        |(source_information, (name, arguments, body))| {
             let source_information = Rc::new(source_information);
 
             FunctionDefinition::new(
@@ -120,7 +256,12 @@ fn untyped_function_definition(input: Input) -> IResult<Input, FunctionDefinitio
 
 fn untyped_value_definition(input: Input) -> IResult<Input, ValueDefinition> {
     map(
-        tuple((source_information, identifier, keyword("="), body)),
+        tuple((
+            source_information,
+            definition_name,
+            keyword("="),
+            definition_body,
+        )),
         |(source_information, name, _, body)| {
             let source_information = Rc::new(source_information);
 
@@ -134,6 +275,140 @@ fn untyped_value_definition(input: Input) -> IResult<Input, ValueDefinition> {
     )(input)
 }
 
+fn function_clauses(input: Input) -> IResult<Input, (String, Vec<String>, Expression)> {
+    let (input, (source_information, (name, clause))) =
+        tuple((source_information, function_clause))(input)?;
+    let arity = clause.patterns().len();
+
+    let (input, clauses) = many0(preceded(tuple((line_break, aligned)), |input: Input| {
+        let (next_input, (other_name, clause)) = function_clause(input.clone())?;
+
+        if other_name != name {
+            Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Verify)))
+        } else if clause.patterns().len() != arity {
+            Err(nom::Err::Failure(ParserError::MismatchedClauseArguments(
+                input,
+            )))
+        } else {
+            Ok((next_input, clause))
+        }
+    }))(input)?;
+
+    let clauses = [clause].iter().chain(&clauses).cloned().collect::<Vec<_>>();
+
+    if let [clause] = clauses.as_slice() {
+        if let Some(arguments) = clause
+            .patterns()
+            .iter()
+            .map(|pattern| match pattern {
+                Pattern::Variable(variable) => Some(variable.name().into()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        {
+            if let [guard] = clause.guards() {
+                if guard.is_otherwise() {
+                    let body = guard.expression().clone();
+
+                    return Ok((
+                        input,
+                        (
+                            name,
+                            arguments,
+                            if clause.definitions().is_empty() {
+                                body
+                            } else {
+                                Let::new(clause.definitions().to_vec(), body).into()
+                            },
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    let arguments = (0..arity)
+        .map(|index| format!("{}{}", CLAUSE_ARGUMENT_NAME_PREFIX, index))
+        .collect::<Vec<_>>();
+
+    Ok((
+        input,
+        (
+            name,
+            arguments.clone(),
+            Match::new(arguments, clauses, source_information).into(),
+        ),
+    ))
+}
+
+fn function_clause(input: Input) -> IResult<Input, (String, Clause)> {
+    map(
+        tuple((
+            source_information,
+            alt((
+                tuple((definition_name, many1(atomic_pattern))),
+                map(
+                    tuple((atomic_pattern, user_operator, atomic_pattern)),
+                    |(lhs, operator, rhs)| (operator, vec![lhs, rhs]),
+                ),
+            )),
+            alt((
+                map(
+                    tuple((source_information, keyword("="), body)),
+                    |(source_information, _, body)| {
+                        vec![Guard::new(Boolean::new(true, source_information), body)]
+                    },
+                ),
+                many1(guard),
+            )),
+            opt(preceded(keyword("where"), block(definition))),
+        )),
+        |(source_information, (name, patterns), guards, definitions)| {
+            (
+                name,
+                Clause::new(
+                    patterns,
+                    guards,
+                    definitions.unwrap_or_default(),
+                    source_information,
+                ),
+            )
+        },
+    )(input)
+}
+
+fn guard(input: Input) -> IResult<Input, Guard> {
+    map(
+        tuple((
+            keyword("|"),
+            alt((
+                map(
+                    tuple((source_information, keyword("otherwise"))),
+                    |(source_information, _)| Boolean::new(true, source_information).into(),
+                ),
+                expression,
+            )),
+            keyword("="),
+            body,
+        )),
+        |(_, condition, _, expression)| Guard::new(condition, expression),
+    )(input)
+}
+
+fn definition_name(input: Input) -> IResult<Input, String> {
+    alt((identifier, parenthesesed(user_operator)))(input)
+}
+
+fn definition_body(input: Input) -> IResult<Input, Expression> {
+    map(
+        tuple((body, opt(preceded(keyword("where"), block(definition))))),
+        |(body, definitions)| match definitions {
+            Some(definitions) => Let::new(definitions, body).into(),
+            None => body,
+        },
+    )(input)
+}
+
 fn body(input: Input) -> IResult<Input, Expression> {
     let braces = input.braces();
 
@@ -141,1125 +416,4061 @@ fn body(input: Input) -> IResult<Input, Expression> {
         .map(|(input, expression)| (input.set_braces(braces), expression))
 }
 
-fn expression(input: Input) -> IResult<Input, Expression> {
-    alt((map(operation, Expression::Operation), term))(input)
+fn expression(original_input: Input) -> IResult<Input, Expression> {
+    let (original_input, _) = blank(original_input)?;
+    let (input, parts) = operation(original_input.clone())?;
+
+    reduce_operation(original_input, input, parts)
 }
 
 fn let_(input: Input) -> IResult<Input, Let> {
     map(
         tuple((
             keyword("let"),
-            definition,
-            many0(preceded(line_break, definition)),
-            opt(line_break),
+            let_block(let_binding),
             keyword("in"),
             expression,
         )),
-        |(_, definition, definitions, _, _, expression)| {
-            Let::new(
-                [definition]
-                    .iter()
-                    .chain(&definitions)
-                    .cloned()
-                    .collect::<Vec<_>>(),
-                expression,
-            )
+        |(_, bindings, _, expression)| {
+            let mut definitions = vec![];
+            let mut destructurings = vec![];
+
+            for binding in bindings {
+                match binding {
+                    LetBinding::Definition(definition) => definitions.push(definition),
+                    LetBinding::Destructuring(destructuring) => destructurings.push(destructuring),
+                }
+            }
+
+            Let::with_destructurings(definitions, destructurings, expression)
         },
     )(input)
 }
 
-fn application(input: Input) -> IResult<Input, Application> {
+fn let_binding(input: Input) -> IResult<Input, LetBinding> {
+    alt((
+        map(definition, LetBinding::Definition),
+        map(
+            tuple((
+                source_information,
+                tuple_pattern,
+                keyword("="),
+                definition_body,
+            )),
+            |(source_information, pattern, _, body)| {
+                LetBinding::Destructuring(Destructuring::new(pattern, body, source_information))
+            },
+        ),
+    ))(input)
+}
+
+fn case_(input: Input) -> IResult<Input, Case> {
     map(
         tuple((
             source_information,
-            atomic_expression,
-            many1(atomic_expression),
+            keyword("case"),
+            expression,
+            keyword("of"),
+            block(alternative),
         )),
-        |(source_information, function, mut arguments)| {
-            let source_information = Rc::new(source_information);
-            let mut drain = arguments.drain(..);
-            let mut application =
-                Application::new(function, drain.next().unwrap(), source_information.clone());
+        |(source_information, _, argument, _, alternatives)| {
+            Case::new(argument, alternatives, source_information)
+        },
+    )(input)
+}
+
+fn alternative(input: Input) -> IResult<Input, Alternative> {
+    map(
+        tuple((pattern, keyword("->"), body)),
+        |(pattern, _, expression)| Alternative::new(pattern, expression),
+    )(input)
+}
 
-            for argument in drain {
-                application = Application::new(application, argument, source_information.clone());
+fn pattern(input: Input) -> IResult<Input, Pattern> {
+    map(
+        tuple((
+            source_information,
+            alt((
+                map(constructor_pattern, |constructor_pattern| {
+                    constructor_pattern.into()
+                }),
+                atomic_pattern,
+            )),
+            opt(preceded(keyword(CONS_CONSTRUCTOR_NAME), pattern)),
+        )),
+        |(source_information, head, tail)| match tail {
+            Some(tail) => {
+                ConstructorPattern::new(CONS_CONSTRUCTOR_NAME, vec![head, tail], source_information)
+                    .into()
             }
+            None => head,
+        },
+    )(input)
+}
 
-            application
+fn constructor_pattern(input: Input) -> IResult<Input, ConstructorPattern> {
+    map(
+        tuple((
+            source_information,
+            capitalized_identifier,
+            many1(atomic_pattern),
+        )),
+        |(source_information, name, arguments)| {
+            ConstructorPattern::new(name, arguments, source_information)
         },
     )(input)
 }
 
-fn atomic_expression(input: Input) -> IResult<Input, Expression> {
+fn tuple_pattern(input: Input) -> IResult<Input, Pattern> {
+    map(
+        tuple((source_information, tupled(pattern))),
+        |(source_information, elements)| TuplePattern::new(elements, source_information).into(),
+    )(input)
+}
+
+fn atomic_pattern(input: Input) -> IResult<Input, Pattern> {
     alt((
         map(
-            tuple((source_information, number_literal)),
-            |(source_information, number)| Number::new(number, source_information).into(),
+            tuple((source_information, opt(keyword("-")), number)),
+            |(source_information, sign, number)| {
+                if sign.is_some() {
+                    number.negate(source_information)
+                } else {
+                    number
+                }
+                .into()
+            },
+        ),
+        map(
+            tuple((source_information, boolean_literal)),
+            |(source_information, boolean)| Boolean::new(boolean, source_information).into(),
+        ),
+        map(
+            tuple((source_information, keyword("_"))),
+            |(source_information, _)| Wildcard::new(source_information).into(),
         ),
         map(
             tuple((source_information, identifier)),
             |(source_information, identifier)| Variable::new(identifier, source_information).into(),
         ),
-        parenthesesed(expression),
+        map(
+            tuple((source_information, capitalized_identifier)),
+            |(source_information, name)| {
+                ConstructorPattern::new(name, vec![], source_information).into()
+            },
+        ),
+        map(
+            tuple((
+                source_information,
+                bracketed(separated_list(keyword(","), pattern)),
+            )),
+            |(source_information, elements)| {
+                elements.into_iter().rev().fold(
+                    ConstructorPattern::new(
+                        NIL_CONSTRUCTOR_NAME,
+                        vec![],
+                        source_information.clone(),
+                    )
+                    .into(),
+                    |list, element| {
+                        ConstructorPattern::new(
+                            CONS_CONSTRUCTOR_NAME,
+                            vec![element, list],
+                            source_information.clone(),
+                        )
+                        .into()
+                    },
+                )
+            },
+        ),
+        parenthesized_or_tupled(pattern, |elements, source_information| {
+            TuplePattern::new(elements, source_information).into()
+        }),
     ))(input)
 }
 
-fn term(input: Input) -> IResult<Input, Expression> {
-    alt((
-        map(application, |application| application.into()),
-        map(let_, |let_| let_.into()),
-        atomic_expression,
-    ))(input)
+fn if_(input: Input) -> IResult<Input, If> {
+    map(
+        tuple((
+            source_information,
+            keyword("if"),
+            expression,
+            keyword("then"),
+            expression,
+            keyword("else"),
+            expression,
+        )),
+        |(source_information, _, condition, _, then, _, else_)| {
+            If::new(condition, then, else_, source_information)
+        },
+    )(input)
 }
 
-fn operation(input: Input) -> IResult<Input, Operation> {
-    tuple((term, many1(tuple((source_information, operator, term)))))(input).map(
-        |(input, (lhs, pairs))| {
-            (
-                input,
-                reduce_operations(
-                    lhs,
-                    pairs
-                        .into_iter()
-                        .map(|(source_information, operator, term)| {
-                            (operator, term, source_information)
-                        })
-                        .collect(),
-                ),
-            )
+fn lambda(input: Input) -> IResult<Input, Lambda> {
+    map(
+        tuple((
+            source_information,
+            keyword("\\"),
+            many1(identifier),
+            keyword("->"),
+            expression,
+        )),
+        |(source_information, _, arguments, _, body)| {
+            Lambda::new(arguments, body, source_information)
         },
-    )
+    )(input)
 }
 
-fn operator(input: Input) -> IResult<Input, Operator> {
-    alt((
-        create_operator("+", Operator::Add),
-        create_operator("-", Operator::Subtract),
-        create_operator("*", Operator::Multiply),
-        create_operator("/", Operator::Divide),
-    ))(input)
-}
+fn application(input: Input) -> IResult<Input, Expression> {
+    map(
+        tuple((
+            source_information,
+            atomic_expression,
+            many0(atomic_expression),
+        )),
+        |(source_information, function, arguments)| {
+            let source_information = Rc::new(source_information);
 
-fn create_operator<'a>(
-    literal: &'static str,
-    operator: Operator,
-) -> impl Fn(Input<'a>) -> IResult<Input<'a>, Operator> {
-    move |input| keyword(literal)(input).map(|(input, _)| (input, operator))
+            arguments
+                .into_iter()
+                .fold(function, |application, argument| {
+                    Application::new(application, argument, source_information.clone()).into()
+                })
+        },
+    )(input)
 }
 
-fn number_literal(input: Input) -> IResult<Input, f64> {
+fn atomic_expression(input: Input) -> IResult<Input, Expression> {
     map(
-        token(tuple((
-            opt(tag("-")),
-            many1(one_of("123456789")),
-            opt(tuple((tag("."), many1(convert_combinator(digit1))))),
-        ))),
-        |(sign, head, tail)| {
-            (if sign.is_some() { -1.0 } else { 1.0 })
-                * f64::from_str(
-                    &[
-                        head.iter().collect(),
-                        tail.map(|(_, digits)| [".", &digits.concat()].concat())
-                            .unwrap_or_else(|| "".into()),
-                    ]
-                    .concat(),
-                )
-                .unwrap()
+        tuple((
+            source_information,
+            unqualified_atomic_expression,
+            many0(preceded(tag("."), identifier)),
+        )),
+        |(source_information, expression, names)| {
+            let source_information = Rc::new(source_information);
+
+            names.into_iter().fold(expression, |record, name| {
+                RecordElement::new(record, name, source_information.clone()).into()
+            })
         },
     )(input)
 }
 
-fn identifier(original_input: Input) -> IResult<Input, String> {
-    token(tuple((
-        convert_combinator(alpha1),
-        convert_combinator(alphanumeric0),
-    )))(original_input.clone())
-    .map(|(input, (head, tail))| (input, format!("{}{}", head, tail)))
-    .and_then(|(input, identifier)| {
-        if KEYWORDS.iter().any(|keyword| &identifier == keyword) {
-            Err(nom::Err::Error((original_input, ErrorKind::Verify)))
-        } else {
-            Ok((input, identifier))
-        }
-    })
+fn unqualified_atomic_expression(input: Input) -> IResult<Input, Expression> {
+    alt((
+        map(number, Expression::from),
+        string,
+        map(char_literal, Expression::from),
+        map(
+            tuple((source_information, identifier)),
+            |(source_information, identifier)| Variable::new(identifier, source_information).into(),
+        ),
+        map(
+            tuple((source_information, boolean_literal)),
+            |(source_information, boolean)| Boolean::new(boolean, source_information).into(),
+        ),
+        map(
+            tuple((source_information, capitalized_identifier)),
+            |(source_information, name)| Constructor::new(name, source_information).into(),
+        ),
+        list_literal,
+        parenthesized_expression,
+        record_update,
+        record_literal,
+    ))(input)
 }
 
-fn type_(input: Input) -> IResult<Input, Type> {
-    alt((function_type, atomic_type))(input)
+fn record_literal(input: Input) -> IResult<Input, Expression> {
+    map(
+        tuple((source_information, braced(record_elements("=", expression)))),
+        |(source_information, elements)| Record::new(elements, source_information).into(),
+    )(input)
 }
 
-fn function_type(input: Input) -> IResult<Input, Type> {
-    tuple((source_information, atomic_type, keyword("->"), type_))(input).map(
-        |(input, (source_information, argument, _, result))| {
-            (
-                input,
-                types::Function::new(argument, result, source_information).into(),
-            )
+fn record_update(input: Input) -> IResult<Input, Expression> {
+    map(
+        tuple((
+            source_information,
+            braced(tuple((
+                atomic_expression,
+                keyword("|"),
+                record_elements("=", expression),
+            ))),
+        )),
+        |(source_information, (record, _, elements))| {
+            RecordUpdate::new(record, elements, source_information).into()
         },
-    )
+    )(input)
 }
 
-fn atomic_type(input: Input) -> IResult<Input, Type> {
-    alt((number_type, parenthesesed_type))(input)
+fn list_literal(input: Input) -> IResult<Input, Expression> {
+    map(
+        tuple((
+            source_information,
+            bracketed(separated_list(keyword(","), expression)),
+        )),
+        |(source_information, elements)| create_list(elements, source_information),
+    )(input)
 }
 
-fn parenthesesed_type(input: Input) -> IResult<Input, Type> {
-    parenthesesed(type_)(input)
-}
+fn parenthesized_expression(input: Input) -> IResult<Input, Expression> {
+    let (input, source_information) = source_information(input)?;
+    let (input, _) = left_parenthesis(input)?;
 
-fn parenthesesed<'a, T>(
-    combinator: impl Fn(Input) -> IResult<Input, T>,
-) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
-    delimited(left_parenthesis, combinator, right_parenthesis)
+    match section(input.clone(), source_information.clone()) {
+        Err(nom::Err::Error(_)) => parenthesized_operation(input, source_information),
+        result => result,
+    }
 }
 
-fn left_parenthesis(input: Input) -> IResult<Input, ()> {
-    keyword("(")(input)
+fn section(input: Input, source_information: SourceInformation) -> IResult<Input, Expression> {
+    alt((
+        map(terminated(user_operator, right_parenthesis), |operator| {
+            Variable::new(operator, source_information.clone()).into()
+        }),
+        map(terminated(operator, right_parenthesis), |operator| {
+            create_section(
+                vec![SECTION_LHS_NAME.into(), SECTION_RHS_NAME.into()],
+                &operator,
+                Variable::new(SECTION_LHS_NAME, source_information.clone()),
+                Variable::new(SECTION_RHS_NAME, source_information.clone()),
+                source_information.clone(),
+            )
+        }),
+        map(
+            terminated(
+                tuple((verify(operator, |operator: &str| operator != "-"), operand)),
+                right_parenthesis,
+            ),
+            |(operator, rhs)| {
+                create_section(
+                    vec![SECTION_LHS_NAME.into()],
+                    &operator,
+                    Variable::new(SECTION_LHS_NAME, source_information.clone()),
+                    rhs,
+                    source_information.clone(),
+                )
+            },
+        ),
+    ))(input)
 }
 
-fn right_parenthesis(input: Input) -> IResult<Input, ()> {
-    keyword(")")(input)
+fn parenthesized_operation(
+    original_input: Input,
+    source_information: SourceInformation,
+) -> IResult<Input, Expression> {
+    let (input, (negation, lhs, pairs)) = operation(original_input.clone())?;
+
+    if pairs.is_empty() {
+        if let Ok((input, operator)) = terminated(operator, right_parenthesis)(input.clone()) {
+            let lhs = match negation {
+                Some(source_information) => Negation::new(lhs, source_information).into(),
+                None => lhs,
+            };
+
+            return Ok((
+                input,
+                create_section(
+                    vec![SECTION_RHS_NAME.into()],
+                    &operator,
+                    lhs,
+                    Variable::new(SECTION_RHS_NAME, source_information.clone()),
+                    source_information,
+                ),
+            ));
+        }
+    }
+
+    let (input, first) = reduce_operation(original_input, input, (negation, lhs, pairs))?;
+    let (input, rest) =
+        terminated(many0(preceded(keyword(","), expression)), right_parenthesis)(input)?;
+
+    Ok((
+        input,
+        if rest.is_empty() {
+            first
+        } else {
+            ast::Tuple::new(
+                vec![first].into_iter().chain(rest).collect(),
+                source_information,
+            )
+            .into()
+        },
+    ))
 }
 
-fn number_type(input: Input) -> IResult<Input, Type> {
-    map(
-        tuple((source_information, keyword("Number"))),
-        |(source_information, _)| types::Number::new(source_information).into(),
-    )(input)
+fn create_section(
+    arguments: Vec<String>,
+    operator: &str,
+    lhs: impl Into<Expression>,
+    rhs: impl Into<Expression>,
+    source_information: SourceInformation,
+) -> Expression {
+    Lambda::new(
+        arguments,
+        create_operation(operator, lhs.into(), rhs.into(), source_information.clone()),
+        source_information,
+    )
+    .into()
 }
 
-fn keyword<'a>(keyword: &'static str) -> impl Fn(Input<'a>) -> IResult<Input<'a>, ()> {
-    nullify(token(tag(keyword)))
+fn term(input: Input) -> IResult<Input, Expression> {
+    alt((
+        application,
+        map(let_, |let_| let_.into()),
+        map(if_, |if_| if_.into()),
+        map(case_, |case| case.into()),
+        map(lambda, |lambda| lambda.into()),
+    ))(input)
 }
 
-fn nullify<'a, T>(
-    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
-) -> impl Fn(Input<'a>) -> IResult<Input<'a>, ()> {
-    move |input| combinator(input).map(|(input, _)| (input, ()))
+fn operation(
+    input: Input,
+) -> IResult<Input, (Option<SourceInformation>, Expression, Vec<OperationPair>)> {
+    tuple((
+        opt(negation_sign),
+        operand,
+        many0(map(
+            tuple((source_information, operator, opt(negation_sign), operand)),
+            |(source_information, operator, negation, operand)| {
+                (operator, negation, operand, source_information)
+            },
+        )),
+    ))(input)
 }
 
-fn token<'a, T>(
-    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
-) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
-    preceded(blank, combinator)
+fn reduce_operation<'a>(
+    original_input: Input<'a>,
+    input: Input<'a>,
+    (negation, lhs, pairs): (Option<SourceInformation>, Expression, Vec<OperationPair>),
+) -> IResult<Input<'a>, Expression> {
+    reduce_operations(negation, lhs, pairs, &|operator| input.fixity(operator))
+        .map(|operation| (input.clone(), operation))
+        .ok_or(nom::Err::Failure(ParserError::ConflictingFixities(
+            original_input,
+        )))
 }
 
-fn blank(input: Input) -> IResult<Input, ()> {
-    nullify(many0(one_of(if input.braces() > 0 {
-        " \t\n"
-    } else {
-        " \t"
-    })))(input)
+fn operand(input: Input) -> IResult<Input, Expression> {
+    alt((map(negation, Expression::Negation), term))(input)
 }
 
-fn line_break(input: Input) -> IResult<Input, ()> {
-    alt((
-        nullify(many1(preceded(
-            white_space,
-            convert_character_combinator(newline),
-        ))),
-        token(eof),
-    ))(input)
+fn negation(input: Input) -> IResult<Input, Negation> {
+    map(
+        tuple((negation_sign, operand)),
+        |(source_information, operand)| Negation::new(operand, source_information),
+    )(input)
 }
 
-fn white_space(input: Input) -> IResult<Input, ()> {
-    nullify(many0(one_of(" \t")))(input)
+fn negation_sign(input: Input) -> IResult<Input, SourceInformation> {
+    terminated(
+        source_information,
+        verify(operator, |operator: &str| operator == "-"),
+    )(input)
 }
 
-fn eof(input: Input) -> IResult<Input, ()> {
-    if input.source() == "" {
-        Ok((input, ()))
-    } else {
-        Err(nom::Err::Error((input, ErrorKind::Eof)))
-    }
+fn operator(input: Input) -> IResult<Input, String> {
+    map(
+        verify(
+            token(convert_combinator(nom::bytes::complete::is_a(
+                OPERATOR_CHARACTERS,
+            ))),
+            |operator: &str| !RESERVED_OPERATORS.contains(&operator),
+        ),
+        |operator| operator.into(),
+    )(input)
 }
 
-fn tag<'a>(tag: &'static str) -> impl Fn(Input<'a>) -> IResult<Input<'a>, &str> {
-    convert_combinator(nom::bytes::complete::tag(tag))
+fn user_operator(input: Input) -> IResult<Input, String> {
+    verify(operator, |operator: &str| !is_built_in_operator(operator))(input)
 }
 
-fn one_of<'a>(characters: &'static str) -> impl Fn(Input<'a>) -> IResult<Input<'a>, char> {
-    convert_character_combinator(nom::character::complete::one_of(characters))
+fn boolean_literal(input: Input) -> IResult<Input, bool> {
+    map(
+        verify(word, |word: &str| word == "True" || word == "False"),
+        |word| word == "True",
+    )(input)
 }
 
-fn source_information(original_input: Input) -> IResult<Input, SourceInformation> {
-    convert_combinator(nom::character::complete::space0)(original_input.clone()).map(
-        |(input, _)| {
-            let source_information =
-                SourceInformation::new(input.filename(), input.location(), input.line());
-            (original_input, source_information)
+fn number(input: Input) -> IResult<Input, Number> {
+    map(
+        tuple((
+            source_information,
+            peek(preceded(blank, convert_combinator(raw_number_literal))),
+            number_literal,
+        )),
+        |(source_information, literal, number)| match convert_integer_literal(literal) {
+            Some(integer) => Number::from_integer(integer, source_information),
+            None => Number::with_type(
+                types::Number::new(source_information.clone()),
+                number,
+                source_information,
+            ),
         },
-    )
+    )(input)
 }
 
-fn convert_combinator<'a>(
-    combinator: impl Fn(&'a str) -> IResult<&'a str, &str>,
-) -> impl Fn(Input<'a>) -> IResult<Input<'a>, &str> {
-    move |input| {
-        let braces = input.braces();
-        let location = input.location();
+fn number_literal(original_input: Input) -> IResult<Input, f64> {
+    let (input, _) = blank(original_input)?;
+    let (rest, literal) = convert_combinator(raw_number_literal)(input.clone())?;
+    let number = convert_number_literal(literal).ok_or(nom::Err::Failure(
+        ParserError::NumberLiteralOutOfRange(input),
+    ))?;
 
-        combinator(input.source())
-            .map(|(source, string)| {
-                (
-                    {
-                        input.set(
-                            source,
-                            braces + string.matches('(').count() - string.matches(')').count(),
-                            string.chars().fold(location, |location, character| {
-                                if character == '\n' {
-                                    location.increment_line_number()
-                                } else {
-                                    location.increment_column_number()
-                                }
-                            }),
-                        )
-                    },
-                    string,
-                )
-            })
-            .map_err(|error| convert_error(error, &input))
-    }
+    Ok((rest, number))
 }
 
-fn convert_character_combinator<'a>(
-    combinator: impl Fn(&'a str) -> IResult<&'a str, char>,
-) -> impl Fn(Input<'a>) -> IResult<Input<'a>, char> {
-    move |input| {
-        let braces = input.braces();
-        let location = input.location();
+fn raw_number_literal(source: &str) -> IResult<&str, &str> {
+    terminated(
+        alt((
+            recognize(tuple((
+                nom::bytes::complete::tag_no_case("0x"),
+                separated_digits(|character| character.is_ascii_hexdigit()),
+            ))),
+            recognize(tuple((
+                nom::bytes::complete::tag_no_case("0b"),
+                separated_digits(|character| character == '0' || character == '1'),
+            ))),
+            recognize(tuple((
+                separated_digits(|character| character.is_ascii_digit()),
+                opt(tuple((
+                    nom::character::complete::char('.'),
+                    separated_digits(|character| character.is_ascii_digit()),
+                ))),
+                opt(tuple((
+                    nom::character::complete::one_of("eE"),
+                    opt(nom::character::complete::one_of("+-")),
+                    separated_digits(|character| character.is_ascii_digit()),
+                ))),
+            ))),
+        )),
+        not(alt((alphanumeric1, nom::bytes::complete::tag("_")))),
+    )(source)
+}
 
-        combinator(input.source())
-            .map(|(source, character)| {
-                (
-                    match character {
-                        '\n' => input.set(source, braces, location.increment_line_number()),
-                        '(' => input.set(source, braces + 1, location.increment_column_number()),
-                        ')' => input.set(source, braces - 1, location.increment_column_number()),
-                        _ => input.set(source, braces, location.increment_column_number()),
-                    },
-                    character,
-                )
-            })
-            .map_err(|error| convert_error(error, &input))
-    }
+fn separated_digits<'a>(
+    is_digit: impl Fn(char) -> bool + Copy,
+) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    recognize(tuple((
+        nom::bytes::complete::take_while1(is_digit),
+        many0(tuple((
+            nom::character::complete::char('_'),
+            nom::bytes::complete::take_while1(is_digit),
+        ))),
+    )))
 }
 
-fn convert_error<'a>(
-    error: Err<(&'a str, ErrorKind)>,
-    input: &Input<'a>,
-) -> Err<(Input<'a>, ErrorKind)> {
-    match error {
-        Err::Error((_, kind)) => Err::Error((input.clone(), kind)),
-        Err::Failure((_, kind)) => Err::Failure((input.clone(), kind)),
-        Err::Incomplete(needed) => Err::Incomplete(needed),
+fn convert_integer_literal(literal: &str) -> Option<i64> {
+    let literal = literal.replace('_', "").to_lowercase();
+
+    if let Some(digits) = literal.strip_prefix("0x") {
+        i64::from_str_radix(digits, 16).ok()
+    } else if let Some(digits) = literal.strip_prefix("0b") {
+        i64::from_str_radix(digits, 2).ok()
+    } else if literal.contains(['.', 'e']) {
+        None
+    } else {
+        i64::from_str(&literal).ok()
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::{
-        application, blank, expression, function_definition, identifier, keyword, let_, line_break,
-        module, number_literal, number_type, source_information, type_, value_definition, Input,
-    };
-    use crate::ast::*;
-    use crate::debug::*;
-    use crate::types::{self, Type};
-    use nom::error::*;
-
-    #[test]
-    fn parse_blank() {
-        let input = Input::new("", "");
+fn convert_number_literal(literal: &str) -> Option<f64> {
+    let literal = literal.replace('_', "").to_lowercase();
+
+    if let Some(digits) = literal.strip_prefix("0x") {
+        u64::from_str_radix(digits, 16)
+            .ok()
+            .map(|number| number as f64)
+    } else if let Some(digits) = literal.strip_prefix("0b") {
+        u64::from_str_radix(digits, 2)
+            .ok()
+            .map(|number| number as f64)
+    } else {
+        f64::from_str(&literal)
+            .ok()
+            .filter(|number| number.is_finite())
+    }
+}
 
-        assert_eq!(
-            blank(input.clone()),
-            Ok((input.set("", 0, Location::default()), ()))
-        );
+fn string(input: Input) -> IResult<Input, Expression> {
+    let (input, source_information) = source_information(input)?;
+    let source_information = Rc::new(source_information);
+    let (input, parts) = delimited(
+        token(tag("\"")),
+        many0(string_part(source_information.clone())),
+        tag("\""),
+    )(input)?;
+    let mut parts = parts.into_iter().rev();
+
+    Ok((
+        input,
+        match parts.next() {
+            Some(last) => parts.fold(last, |rhs, lhs| {
+                Operation::new(Operator::Concatenate, lhs, rhs, source_information.clone()).into()
+            }),
+            None => StringLiteral::new("", source_information).into(),
+        },
+    ))
+}
 
-        let input = Input::new(" ", "");
+fn string_part<'a>(
+    source_information: Rc<SourceInformation>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, Expression> {
+    move |input| {
+        alt((
+            map(string_characters, |characters| {
+                StringLiteral::new(characters, source_information.clone()).into()
+            }),
+            map(
+                delimited(tag("${"), expression, token(tag("}"))),
+                |expression| {
+                    Conversion::new(
+                        types::String::new(source_information.clone()),
+                        expression,
+                        source_information.clone(),
+                    )
+                    .into()
+                },
+            ),
+        ))(input)
+    }
+}
 
-        assert_eq!(
-            blank(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 2)), ()))
-        );
+fn string_characters(original_input: Input) -> IResult<Input, String> {
+    let (input, characters) =
+        convert_comment_combinator(raw_string_characters)(original_input.clone())?;
+    let characters = unescape_characters(characters).ok_or(nom::Err::Failure(
+        ParserError::InvalidEscapeSequence(original_input),
+    ))?;
 
-        let input = Input::new("\t", "");
+    Ok((input, characters))
+}
 
-        assert_eq!(
-            blank(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 2)), ()))
-        );
+fn raw_string_characters(source: &str) -> IResult<&str, &str> {
+    recognize(many1(alt((
+        nom::bytes::complete::is_not("\"\\$\n"),
+        recognize(tuple((
+            nom::character::complete::char('$'),
+            not(nom::character::complete::char('{')),
+        ))),
+        raw_escape_sequence,
+    ))))(source)
+}
 
-        let input = Input::new("  ", "");
+fn char_literal(input: Input) -> IResult<Input, CharLiteral> {
+    map(
+        tuple((source_information, character)),
+        |(source_information, character)| CharLiteral::new(character, source_information),
+    )(input)
+}
 
-        assert_eq!(
-            blank(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 3)), ()))
-        );
+fn character(original_input: Input) -> IResult<Input, char> {
+    let (input, _) = blank(original_input)?;
+    let (rest, literal) = convert_comment_combinator(raw_char_literal)(input.clone())?;
+    let character = unescape_characters(&literal[1..literal.len() - 1])
+        .and_then(|characters| characters.chars().next())
+        .ok_or(nom::Err::Failure(ParserError::InvalidEscapeSequence(input)))?;
 
-        let input = Input::new("", "");
+    Ok((rest, character))
+}
 
-        assert_eq!(
-            blank(input.set("\n", 1, Location::default())),
-            Ok((input.set("", 1, Location::new(2, 1)), ()))
-        );
+fn raw_char_literal(source: &str) -> IResult<&str, &str> {
+    recognize(delimited(
+        nom::character::complete::char('\''),
+        alt((
+            recognize(nom::character::complete::none_of("'\\\n")),
+            raw_escape_sequence,
+        )),
+        nom::character::complete::char('\''),
+    ))(source)
+}
 
-        let input = Input::new("\n", "");
+fn raw_escape_sequence(source: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        nom::character::complete::char('\\'),
+        alt((
+            recognize(delimited(
+                nom::bytes::complete::tag("u{"),
+                hex_digit1,
+                nom::character::complete::char('}'),
+            )),
+            recognize(anychar),
+        )),
+    )))(source)
+}
 
-        assert_eq!(
-            blank(input.clone()),
-            Ok((input.set("\n", 0, Location::default()), ()))
-        );
+fn unescape_characters(characters: &str) -> Option<String> {
+    let mut string = String::new();
+    let mut characters = characters.chars();
+
+    while let Some(character) = characters.next() {
+        string.push(if character == '\\' {
+            match characters.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '\\' => '\\',
+                '"' => '"',
+                '\'' => '\'',
+                '$' => '$',
+                'u' => {
+                    let digits = characters
+                        .by_ref()
+                        .skip(1)
+                        .take_while(|character| *character != '}')
+                        .collect::<String>();
+
+                    std::char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?
+                }
+                _ => return None,
+            }
+        } else {
+            character
+        });
     }
 
-    #[test]
-    fn parse_number_type() {
-        let input = Input::new("Number", "");
+    Some(string)
+}
 
-        assert_eq!(
-            number_type(input.clone()),
-            Ok((
-                input.set("", 0, Location::new(1, 7)),
-                types::Number::new(SourceInformation::dummy()).into()
-            ))
-        );
+fn identifier(input: Input) -> IResult<Input, String> {
+    verify(raw_identifier, |identifier: &str| {
+        identifier.starts_with(|character: char| character.is_lowercase())
+    })(input)
+}
 
-        let input = Input::new("Numbe", "");
+fn capitalized_identifier(input: Input) -> IResult<Input, String> {
+    verify(raw_identifier, |identifier: &str| {
+        identifier.starts_with(|character: char| character.is_uppercase())
+    })(input)
+}
 
-        assert_eq!(
-            number_type(input.clone()),
-            Err(nom::Err::Error((
-                input.set("Numbe", 0, Location::default()),
-                ErrorKind::Tag
+fn raw_identifier(original_input: Input) -> IResult<Input, String> {
+    word(original_input.clone()).and_then(|(input, identifier)| {
+        if KEYWORDS.iter().any(|keyword| &identifier == keyword) {
+            Err(nom::Err::Error(ParserError::Nom(
+                original_input,
+                ErrorKind::Verify,
             )))
-        );
-    }
+        } else {
+            Ok((input, identifier))
+        }
+    })
+}
 
-    #[test]
-    fn parse_type() {
-        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
-        let input = Input::new("Number", "");
+fn word(input: Input) -> IResult<Input, String> {
+    map(
+        token(tuple((
+            convert_combinator(alpha1),
+            convert_combinator(alphanumeric0),
+        ))),
+        |(head, tail)| format!("{}{}", head, tail),
+    )(input)
+}
 
-        assert_eq!(
-            type_(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 7)), number_type.clone()))
-        );
+fn signature(input: Input) -> IResult<Input, Type> {
+    map(type_, |type_| {
+        let mut variables = HashMap::<&str, Type>::new();
+        let mut substitutions = HashMap::<usize, Type>::new();
+
+        for variable in type_.variables() {
+            if let Some(name) = variable.name() {
+                match variables.get(name) {
+                    Some(type_) => {
+                        substitutions.insert(variable.id(), type_.clone());
+                    }
+                    None => {
+                        variables.insert(name, variable.clone().into());
+                    }
+                }
+            }
+        }
 
-        let input = Input::new("(Number)", "");
+        type_.substitute_variables(&substitutions)
+    })(input)
+}
 
-        assert_eq!(
-            type_(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 9)), number_type.clone()))
+fn type_(input: Input) -> IResult<Input, Type> {
+    map(
+        tuple((
+            source_information,
+            applied_type,
+            opt(preceded(keyword("->"), type_)),
+        )),
+        |(source_information, argument, result)| match result {
+            Some(result) => types::Function::new(argument, result, source_information).into(),
+            None => argument,
+        },
+    )(input)
+}
+
+fn applied_type(input: Input) -> IResult<Input, Type> {
+    alt((list_type, atomic_type))(input)
+}
+
+fn list_type(input: Input) -> IResult<Input, Type> {
+    map(
+        tuple((
+            source_information,
+            verify(capitalized_identifier, |name: &str| name == "List"),
+            atomic_type,
+        )),
+        |(source_information, _, element)| types::List::new(element, source_information).into(),
+    )(input)
+}
+
+fn atomic_type(input: Input) -> IResult<Input, Type> {
+    alt((
+        boolean_type,
+        char_type,
+        int_type,
+        number_type,
+        string_type,
+        reference_type,
+        type_variable,
+        record_type,
+        parenthesized_or_tupled(type_, |elements, source_information| {
+            types::Tuple::new(elements, source_information).into()
+        }),
+    ))(input)
+}
+
+fn record_type(input: Input) -> IResult<Input, Type> {
+    map(
+        tuple((source_information, braced(record_elements(":", type_)))),
+        |(source_information, elements)| types::Record::new(elements, source_information).into(),
+    )(input)
+}
+
+fn reference_type(input: Input) -> IResult<Input, Type> {
+    map(
+        tuple((source_information, capitalized_identifier)),
+        |(source_information, name)| types::Reference::new(name, source_information).into(),
+    )(input)
+}
+
+fn type_variable(input: Input) -> IResult<Input, Type> {
+    map(
+        tuple((source_information, identifier)),
+        |(source_information, name)| types::Variable::with_name(name, source_information).into(),
+    )(input)
+}
+
+fn parenthesesed<'a, T>(
+    combinator: impl Fn(Input) -> IResult<Input, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
+    delimited(left_parenthesis, combinator, right_parenthesis)
+}
+
+fn parenthesized_or_tupled<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+    tuple_constructor: impl Fn(Vec<T>, SourceInformation) -> T,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
+    map(
+        tuple((
+            source_information,
+            delimited(
+                left_parenthesis,
+                separated_nonempty_list(keyword(","), combinator),
+                right_parenthesis,
+            ),
+        )),
+        move |(source_information, mut elements)| {
+            if elements.len() == 1 {
+                elements.remove(0)
+            } else {
+                tuple_constructor(elements, source_information)
+            }
+        },
+    )
+}
+
+fn tupled<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, Vec<T>> {
+    verify(
+        delimited(
+            left_parenthesis,
+            separated_nonempty_list(keyword(","), combinator),
+            right_parenthesis,
+        ),
+        |elements: &[T]| elements.len() > 1,
+    )
+}
+
+fn bracketed<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
+    delimited(left_bracket, combinator, right_bracket)
+}
+
+fn braced<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
+    delimited(left_brace, combinator, right_brace)
+}
+
+fn record_elements<'a, T>(
+    separator: &'static str,
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, BTreeMap<String, T>> {
+    map(
+        verify(
+            separated_nonempty_list(
+                keyword(","),
+                tuple((identifier, preceded(keyword(separator), combinator))),
+            ),
+            |elements: &[(String, T)]| {
+                elements
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<HashSet<_>>()
+                    .len()
+                    == elements.len()
+            },
+        ),
+        |elements| elements.into_iter().collect(),
+    )
+}
+
+fn left_brace(input: Input) -> IResult<Input, ()> {
+    keyword("{")(input)
+}
+
+fn right_brace(input: Input) -> IResult<Input, ()> {
+    keyword("}")(input)
+}
+
+fn left_bracket(input: Input) -> IResult<Input, ()> {
+    keyword("[")(input)
+}
+
+fn right_bracket(input: Input) -> IResult<Input, ()> {
+    keyword("]")(input)
+}
+
+fn left_parenthesis(input: Input) -> IResult<Input, ()> {
+    keyword("(")(input)
+}
+
+fn right_parenthesis(input: Input) -> IResult<Input, ()> {
+    keyword(")")(input)
+}
+
+fn char_type(input: Input) -> IResult<Input, Type> {
+    map(
+        verify(
+            tuple((source_information, capitalized_identifier)),
+            |(_, name)| name == "Char",
+        ),
+        |(source_information, _)| types::Char::new(source_information).into(),
+    )(input)
+}
+
+fn boolean_type(input: Input) -> IResult<Input, Type> {
+    map(
+        verify(
+            tuple((source_information, capitalized_identifier)),
+            |(_, name)| name == "Bool" || name == "Boolean",
+        ),
+        |(source_information, _)| types::Boolean::new(source_information).into(),
+    )(input)
+}
+
+fn int_type(input: Input) -> IResult<Input, Type> {
+    map(
+        verify(
+            tuple((source_information, capitalized_identifier)),
+            |(_, name)| name == "Int",
+        ),
+        |(source_information, _)| types::Int::new(source_information).into(),
+    )(input)
+}
+
+fn string_type(input: Input) -> IResult<Input, Type> {
+    map(
+        verify(
+            tuple((source_information, capitalized_identifier)),
+            |(_, name)| name == "String",
+        ),
+        |(source_information, _)| types::String::new(source_information).into(),
+    )(input)
+}
+
+fn number_type(input: Input) -> IResult<Input, Type> {
+    map(
+        verify(
+            tuple((source_information, capitalized_identifier)),
+            |(_, name)| name == "Number",
+        ),
+        |(source_information, _)| types::Number::new(source_information).into(),
+    )(input)
+}
+
+fn keyword<'a>(keyword: &'static str) -> impl Fn(Input<'a>) -> IResult<Input<'a>, ()> {
+    nullify(token(tag(keyword)))
+}
+
+fn nullify<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, ()> {
+    move |input| combinator(input).map(|(input, _)| (input, ()))
+}
+
+fn token<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, T> {
+    move |input| {
+        preceded(blank, &combinator)(input.clone()).inspect_err(|_| {
+            if let Ok((next_input, _)) = blank_lines(input.clone()) {
+                input.record_failure(next_input.location());
+            }
+        })
+    }
+}
+
+fn block<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, Vec<T>> {
+    move |input| {
+        let braces = input.braces();
+        let indentation = input.indentation();
+
+        blank(input)
+            .and_then(|(input, _)| {
+                let column = input.location().column_number();
+
+                separated_nonempty_list(tuple((line_break, aligned)), &combinator)(
+                    input.set_braces(0).set_indentation(column),
+                )
+            })
+            .map(|(input, items)| (input.set_braces(braces).set_indentation(indentation), items))
+    }
+}
+
+fn let_block<'a, T>(
+    combinator: impl Fn(Input<'a>) -> IResult<Input<'a>, T>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, Vec<T>> {
+    move |input| {
+        alt((
+            terminated(block(&combinator), peek(keyword("in"))),
+            delimited(
+                not(line_break),
+                separated_nonempty_list(tuple((line_break, unindented)), &combinator),
+                opt(line_break),
+            ),
+        ))(input)
+    }
+}
+
+fn aligned(input: Input) -> IResult<Input, ()> {
+    white_space(input).and_then(|(input, _)| {
+        if input.location().column_number() == input.indentation() && input.source() != "" {
+            Ok((input, ()))
+        } else {
+            Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Verify)))
+        }
+    })
+}
+
+fn unindented(input: Input) -> IResult<Input, ()> {
+    white_space(input).and_then(|(input, _)| {
+        if input.location().column_number() <= input.indentation() && input.source() != "" {
+            Ok((input, ()))
+        } else {
+            Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Verify)))
+        }
+    })
+}
+
+fn blank(input: Input) -> IResult<Input, ()> {
+    nullify(many0(alt((
+        nullify(one_of(if input.braces() > 0 { " \t\n" } else { " \t" })),
+        comment,
+        line_continuation,
+    ))))(input)
+}
+
+fn line_continuation(input: Input) -> IResult<Input, ()> {
+    tuple((
+        many1(preceded(white_space, convert_character_combinator(newline))),
+        white_space,
+    ))(input.clone())
+    .and_then(|(next_input, _)| {
+        if next_input.location().column_number() > next_input.indentation() {
+            Ok((next_input, ()))
+        } else {
+            Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Verify)))
+        }
+    })
+}
+
+fn blank_lines(input: Input) -> IResult<Input, ()> {
+    nullify(many0(alt((nullify(one_of(" \t\n")), comment))))(input)
+}
+
+fn line_break(input: Input) -> IResult<Input, ()> {
+    alt((
+        nullify(many1(preceded(
+            white_space,
+            convert_character_combinator(newline),
+        ))),
+        token(eof),
+    ))(input)
+}
+
+fn white_space(input: Input) -> IResult<Input, ()> {
+    nullify(many0(alt((nullify(one_of(" \t")), comment))))(input)
+}
+
+fn comment(input: Input) -> IResult<Input, ()> {
+    nullify(convert_comment_combinator(alt((
+        raw_block_comment,
+        raw_line_comment,
+    ))))(input)
+}
+
+fn raw_line_comment(source: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        nom::character::complete::char('#'),
+        not(nom::character::complete::char('|')),
+        not_line_ending,
+    )))(source)
+}
+
+fn raw_block_comment(source: &str) -> IResult<&str, &str> {
+    recognize(delimited(
+        nom::bytes::complete::tag("#|"),
+        many0(alt((
+            raw_block_comment,
+            recognize(preceded(
+                not(alt((
+                    nom::bytes::complete::tag("#|"),
+                    nom::bytes::complete::tag("|#"),
+                ))),
+                anychar,
+            )),
+        ))),
+        nom::bytes::complete::tag("|#"),
+    ))(source)
+}
+
+fn eof(input: Input) -> IResult<Input, ()> {
+    if input.source() == "" {
+        Ok((input, ()))
+    } else {
+        Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Eof)))
+    }
+}
+
+fn tag<'a>(tag: &'static str) -> impl Fn(Input<'a>) -> IResult<Input<'a>, &str> {
+    convert_combinator(nom::bytes::complete::tag(tag))
+}
+
+fn one_of<'a>(characters: &'static str) -> impl Fn(Input<'a>) -> IResult<Input<'a>, char> {
+    convert_character_combinator(nom::character::complete::one_of(characters))
+}
+
+fn source_information(original_input: Input) -> IResult<Input, SourceInformation> {
+    blank(original_input.clone()).map(|(input, _)| {
+        let source_information =
+            SourceInformation::new(input.filename(), input.location(), input.line());
+        (original_input, source_information)
+    })
+}
+
+fn convert_combinator<'a>(
+    combinator: impl Fn(&'a str) -> IResult<&'a str, &str>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, &str> {
+    move |input| {
+        let braces = input.braces();
+        let location = input.location();
+
+        combinator(input.source())
+            .map(|(source, string)| {
+                (
+                    {
+                        input.set(
+                            source,
+                            braces + string.matches(&['(', '[', '{'][..]).count()
+                                - string.matches(&[')', ']', '}'][..]).count(),
+                            advance_location(location, string),
+                        )
+                    },
+                    string,
+                )
+            })
+            .map_err(|error| convert_error(error, &input))
+    }
+}
+
+fn convert_comment_combinator<'a>(
+    combinator: impl Fn(&'a str) -> IResult<&'a str, &str>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, &str> {
+    move |input| {
+        let braces = input.braces();
+        let location = input.location();
+
+        combinator(input.source())
+            .map(|(source, string)| {
+                (
+                    input.set(source, braces, advance_location(location, string)),
+                    string,
+                )
+            })
+            .map_err(|error| convert_error(error, &input))
+    }
+}
+
+fn advance_location(location: Location, string: &str) -> Location {
+    string.chars().fold(location, |location, character| {
+        if character == '\n' {
+            location.increment_line_number()
+        } else {
+            location.increment_column_number()
+        }
+    })
+}
+
+fn convert_character_combinator<'a>(
+    combinator: impl Fn(&'a str) -> IResult<&'a str, char>,
+) -> impl Fn(Input<'a>) -> IResult<Input<'a>, char> {
+    move |input| {
+        let braces = input.braces();
+        let location = input.location();
+
+        combinator(input.source())
+            .map(|(source, character)| {
+                (
+                    match character {
+                        '\n' => input.set(source, braces, location.increment_line_number()),
+                        '(' | '[' | '{' => {
+                            input.set(source, braces + 1, location.increment_column_number())
+                        }
+                        ')' | ']' | '}' => {
+                            input.set(source, braces - 1, location.increment_column_number())
+                        }
+                        _ => input.set(source, braces, location.increment_column_number()),
+                    },
+                    character,
+                )
+            })
+            .map_err(|error| convert_error(error, &input))
+    }
+}
+
+fn convert_error<'a>(
+    error: Err<ParserError<&'a str>>,
+    input: &Input<'a>,
+) -> Err<ParserError<Input<'a>>> {
+    match error {
+        Err::Error(error) => Err::Error(error.with_input(input.clone())),
+        Err::Failure(error) => Err::Failure(error.with_input(input.clone())),
+        Err::Incomplete(needed) => Err::Incomplete(needed),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        application, blank, boolean_literal, boolean_type, capitalized_identifier, case_,
+        char_literal, char_type, comment, expression, fixity_declaration, function_definition,
+        identifier, if_, int_type, keyword, lambda, let_, line_break, module, number,
+        number_literal, number_type, operator, pattern, signature, source_information, string,
+        string_type, type_, type_alias, type_definition, type_variable, value_definition,
+        Associativity, Fixity, Input, ParserError,
+    };
+    use crate::ast::*;
+    use crate::debug::*;
+    use crate::types::{self, Type};
+    use nom::error::*;
+
+    #[test]
+    fn parse_blank() {
+        let input = Input::new("", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("", 0, Location::default()), ()))
+        );
+
+        let input = Input::new(" ", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 2)), ()))
+        );
+
+        let input = Input::new("\t", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 2)), ()))
+        );
+
+        let input = Input::new("  ", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 3)), ()))
+        );
+
+        let input = Input::new("", "");
+
+        assert_eq!(
+            blank(input.set("\n", 1, Location::default())),
+            Ok((input.set("", 1, Location::new(2, 1)), ()))
+        );
+
+        let input = Input::new("\n", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("\n", 0, Location::default()), ()))
+        );
+    }
+
+    #[test]
+    fn parse_comment() {
+        let input = Input::new("# foo", "");
+
+        assert_eq!(
+            comment(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 6)), ()))
+        );
+
+        let input = Input::new("# foo\n", "");
+
+        assert_eq!(
+            comment(input.clone()),
+            Ok((input.set("\n", 0, Location::new(1, 6)), ()))
+        );
+
+        let input = Input::new("#| foo\nbar |#", "");
+
+        assert_eq!(
+            comment(input.clone()),
+            Ok((input.set("", 0, Location::new(2, 7)), ()))
+        );
+
+        let input = Input::new("#| foo #| bar |# baz |#", "");
+
+        assert_eq!(
+            comment(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 24)), ()))
+        );
+
+        let input = Input::new("#| (foo |#", "");
+
+        assert_eq!(
+            comment(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 11)), ()))
+        );
+
+        let input = Input::new("#| foo #| bar |#", "");
+
+        assert_eq!(
+            comment(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Not)))
+        );
+    }
+
+    #[test]
+    fn parse_blank_with_comments() {
+        let input = Input::new(" # foo", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 7)), ()))
+        );
+
+        let input = Input::new(" # foo\n", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("\n", 0, Location::new(1, 7)), ()))
+        );
+
+        let input = Input::new("", "");
+
+        assert_eq!(
+            blank(input.set(" # foo\n #| bar |# x", 1, Location::default())),
+            Ok((input.set("x", 1, Location::new(2, 12)), ()))
+        );
+    }
+
+    #[test]
+    fn parse_blank_with_line_continuation() {
+        let input = Input::new("\n x", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("x", 0, Location::new(2, 2)), ()))
+        );
+
+        let input = Input::new(" # foo\n\n  x", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("x", 0, Location::new(3, 3)), ()))
+        );
+
+        let input = Input::new("\nx", "");
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("\nx", 0, Location::default()), ()))
+        );
+
+        let input = Input::new("\n  x", "").set_indentation(3);
+
+        assert_eq!(
+            blank(input.clone()),
+            Ok((input.set("\n  x", 0, Location::default()), ()))
+        );
+    }
+
+    #[test]
+    fn parse_number_type() {
+        let input = Input::new("Number", "");
+
+        assert_eq!(
+            number_type(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 7)),
+                types::Number::new(SourceInformation::dummy()).into()
+            ))
+        );
+
+        let input = Input::new("Numbe", "");
+
+        assert_eq!(
+            number_type(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("Numbe", 0, Location::default()),
+                ErrorKind::Verify
+            )))
+        );
+
+        assert_eq!(
+            type_(Input::new("Numbers -> Number", "")).map(|(_, type_)| type_),
+            Ok(types::Function::new(
+                types::Reference::new("Numbers", SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_int_type() {
+        let input = Input::new("Int", "");
+
+        assert_eq!(
+            int_type(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 4)),
+                types::Int::new(SourceInformation::dummy()).into()
+            ))
+        );
+
+        assert_eq!(
+            type_(Input::new("Interval", "")).map(|(_, type_)| type_),
+            Ok(types::Reference::new("Interval", SourceInformation::dummy()).into())
+        );
+    }
+
+    #[test]
+    fn parse_char_type() {
+        let input = Input::new("Char", "");
+
+        assert_eq!(
+            char_type(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 5)),
+                types::Char::new(SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_list_type() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let input = Input::new("List Number", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 12)),
+                types::List::new(number_type.clone(), SourceInformation::dummy()).into()
+            ))
+        );
+
+        assert_eq!(
+            type_(Input::new("List (List a) -> Number", "")).map(|(_, type_)| type_),
+            Ok(types::Function::new(
+                types::List::new(
+                    types::List::new(
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                ),
+                number_type,
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_tuple_type() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+
+        assert_eq!(
+            type_(Input::new("(Number, List a)", "")).map(|(_, type_)| type_),
+            Ok(types::Tuple::new(
+                vec![
+                    number_type.clone(),
+                    types::List::new(
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()
+                ],
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+
+        assert_eq!(
+            type_(Input::new("(Number, Number) -> (Number)", "")).map(|(_, type_)| type_),
+            Ok(types::Function::new(
+                types::Tuple::new(
+                    vec![number_type.clone(), number_type.clone()],
+                    SourceInformation::dummy()
+                ),
+                number_type,
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_record_type() {
+        assert_eq!(
+            type_(Input::new("{ x : Number, y : List a }", "")).map(|(_, type_)| type_),
+            Ok(types::Record::new(
+                vec![
+                    (
+                        "x".into(),
+                        types::Number::new(SourceInformation::dummy()).into()
+                    ),
+                    (
+                        "y".into(),
+                        types::List::new(
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    )
+                ]
+                .into_iter()
+                .collect(),
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+
+        assert!(type_(Input::new("{ x : Number, x : Number }", "")).is_err());
+    }
+
+    #[test]
+    fn parse_type_alias() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let record_type: Type = types::Record::new(
+            vec![("x".into(), number_type)].into_iter().collect(),
+            SourceInformation::dummy(),
+        )
+        .into();
+
+        assert_eq!(
+            type_alias(Input::new("type Point = { x : Number }", "")).map(|(_, alias)| alias),
+            Ok(TypeAlias::new(
+                "Point",
+                record_type,
+                SourceInformation::dummy()
+            ))
+        );
+
+        assert!(type_alias(Input::new("type Foo = List a", "")).is_err());
+    }
+
+    #[test]
+    fn parse_string_type() {
+        let input = Input::new("String", "");
+
+        assert_eq!(
+            string_type(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 7)),
+                types::String::new(SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_boolean_type() {
+        let input = Input::new("Boolean", "");
+
+        assert_eq!(
+            boolean_type(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 8)),
+                types::Boolean::new(SourceInformation::dummy()).into()
+            ))
+        );
+
+        let input = Input::new("Bool", "");
+
+        assert_eq!(
+            boolean_type(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 5)),
+                types::Boolean::new(SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_type() {
+        let number_type: Type = types::Number::new(SourceInformation::dummy()).into();
+        let input = Input::new("Number", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 7)), number_type.clone()))
+        );
+
+        let input = Input::new("(Number)", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 9)), number_type.clone()))
+        );
+
+        let input = Input::new("( Number )", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 11)), number_type.clone()))
+        );
+
+        let input = Input::new("Number -> Number", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 17)),
+                types::Function::new(
+                    number_type.clone(),
+                    number_type.clone(),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("Number -> Number -> Number", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 27)),
+                Type::Function(types::Function::new(
+                    number_type.clone(),
+                    Type::Function(types::Function::new(
+                        number_type.clone(),
+                        number_type.clone(),
+                        SourceInformation::dummy()
+                    )),
+                    SourceInformation::dummy()
+                ))
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_type_variable() {
+        let input = Input::new("a", "");
+
+        assert_eq!(
+            type_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 2)),
+                types::Variable::with_name("a", SourceInformation::dummy()).into()
+            ))
+        );
+
+        let input = Input::new("Foo", "");
+
+        assert_eq!(
+            type_variable(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(input, ErrorKind::Verify)))
+        );
+    }
+
+    #[test]
+    fn parse_signature() {
+        let (_, type_) = signature(Input::new("a -> b -> a", "")).unwrap();
+        let variables = type_.variables();
+
+        assert_eq!(variables.len(), 3);
+        assert_eq!(variables[0].id(), variables[2].id());
+        assert_ne!(variables[0].id(), variables[1].id());
+        assert_eq!(variables[0].name(), Some("a"));
+        assert_eq!(variables[1].name(), Some("b"));
+    }
+
+    #[test]
+    fn parse_keyword() {
+        let input = Input::new("foo", "");
+
+        assert_eq!(
+            keyword("foo")(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 4)), ()))
+        );
+
+        let input = Input::new("fo", "");
+
+        assert_eq!(
+            keyword("foo")(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("fo", 0, Location::default()),
+                ErrorKind::Tag
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_identifier() {
+        let input = Input::new("foo", "");
+
+        assert_eq!(
+            identifier(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 4)), "foo".into()))
+        );
+
+        let input = Input::new("x1", "");
+
+        assert_eq!(
+            identifier(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 3)), "x1".into()))
+        );
+
+        let input = Input::new("1st", "");
+
+        assert_eq!(
+            identifier(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("1st", 0, Location::default()),
+                ErrorKind::Alpha
+            )))
+        );
+
+        let input = Input::new("let", "");
+
+        assert_eq!(
+            identifier(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("let", 0, Location::default()),
+                ErrorKind::Verify
+            )))
+        );
+
+        let input = Input::new("in", "");
+
+        assert_eq!(
+            identifier(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("in", 0, Location::default()),
+                ErrorKind::Verify
+            )))
+        );
+
+        let input = Input::new("Foo", "");
+
+        assert!(identifier(input.clone()).is_err());
+        assert_eq!(
+            capitalized_identifier(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 4)), "Foo".into()))
+        );
+        assert!(capitalized_identifier(Input::new("foo", "")).is_err());
+    }
+
+    #[test]
+    fn parse_number_literal() {
+        let input = Input::new("1", "");
+
+        assert_eq!(
+            number_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 2)), 1.0))
+        );
+
+        let input = Input::new("0", "");
+
+        assert_eq!(
+            number_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 2)), 0.0))
+        );
+
+        let input = Input::new("01", "");
+
+        assert_eq!(
+            number_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 3)), 1.0))
+        );
+
+        let input = Input::new("-1", "");
+
+        assert_eq!(
+            number_literal(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("-1", 0, Location::default()),
+                ErrorKind::TakeWhile1
+            )))
+        );
+
+        let input = Input::new("42", "");
+
+        assert_eq!(
+            number_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 3)), 42.0))
+        );
+
+        let input = Input::new("3.14", "");
+
+        assert_eq!(
+            number_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 5)), 3.14))
+        );
+
+        for (source, number) in &[
+            ("100", 100.0),
+            ("0.5", 0.5),
+            ("1e3", 1e3),
+            ("1E+3", 1e3),
+            ("1e-3", 1e-3),
+            ("2.5e2", 2.5e2),
+            ("0x2a", 42.0),
+            ("0XFF", 255.0),
+            ("0b101010", 42.0),
+            ("1_000_000", 1e6),
+            ("0xff_ff", 65535.0),
+            ("0b1010_1010", 170.0),
+            ("1_0.2_5", 10.25),
+        ] {
+            let input = Input::new(source, "");
+
+            assert_eq!(
+                number_literal(input.clone()),
+                Ok((
+                    input.set("", 0, Location::new(1, source.len() + 1)),
+                    *number
+                ))
+            );
+        }
+
+        for source in &["1_", "1__0", "_1", "1e", "0x", "0b2", "1a"] {
+            let input = Input::new(source, "");
+
+            assert!(matches!(
+                number_literal(input.clone()),
+                Err(nom::Err::Error(_))
+            ));
+        }
+
+        for source in &["1e400", "0x10000000000000000"] {
+            let input = Input::new(source, "");
+
+            assert_eq!(
+                number_literal(input.clone()),
+                Err(nom::Err::Failure(ParserError::NumberLiteralOutOfRange(
+                    input
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_number() {
+        for source in &["42", "0x2a", "0b101010", "1_000"] {
+            assert!(matches!(
+                number(Input::new(source, "")).map(|(_, number)| number.type_().clone()),
+                Ok(Type::Variable(_))
+            ));
+        }
+
+        for source in &["4.2", "1e3", "2.5E-1", "9223372036854775808"] {
+            assert!(matches!(
+                number(Input::new(source, "")).map(|(_, number)| number.type_().clone()),
+                Ok(Type::Number(_))
+            ));
+        }
+
+        for (source, integer) in &[
+            ("9007199254740993", 9007199254740993),
+            ("0x7fff_ffff_ffff_ffff", i64::MAX),
+        ] {
+            assert_eq!(
+                number(Input::new(source, "")).map(|(_, number)| number.integer()),
+                Ok(Some(*integer))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_string() {
+        for (source, value) in &[
+            (r#""""#, ""),
+            (r#""foo bar""#, "foo bar"),
+            (r#""$5 (""#, "$5 ("),
+            (r#""\n\t\r\\\"\$""#, "\n\t\r\\\"$"),
+            (r#""h\u{e9}llo \u{1F600}""#, "h\u{e9}llo \u{1F600}"),
+        ] {
+            assert_eq!(
+                string(Input::new(source, "")).map(|(_, expression)| expression),
+                Ok(StringLiteral::new(*value, SourceInformation::dummy()).into())
+            );
+        }
+
+        assert_eq!(
+            string(Input::new(r#""x = ${x + 1}!""#, "")).map(|(_, expression)| expression),
+            Ok(Operation::new(
+                Operator::Concatenate,
+                StringLiteral::new("x = ", SourceInformation::dummy()),
+                Operation::new(
+                    Operator::Concatenate,
+                    Conversion::new(
+                        types::String::new(SourceInformation::dummy()),
+                        Operation::new(
+                            Operator::Add,
+                            Variable::new("x", SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    StringLiteral::new("!", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into())
+        );
+
+        for source in &[r#""foo"#, "\"foo\nbar\""] {
+            assert!(matches!(
+                string(Input::new(source, "")),
+                Err(nom::Err::Error(_))
+            ));
+        }
+
+        for source in &[r#""\q""#, r#""\u{d800}""#] {
+            assert!(matches!(
+                string(Input::new(source, "")),
+                Err(nom::Err::Failure(ParserError::InvalidEscapeSequence(_)))
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_char_literal() {
+        for (source, value) in &[
+            ("'a'", 'a'),
+            ("'\"'", '"'),
+            ("'\\n'", '\n'),
+            ("'\\''", '\''),
+            ("'\\u{1F9A5}'", '\u{1F9A5}'),
+        ] {
+            assert_eq!(
+                char_literal(Input::new(source, "")).map(|(_, char_literal)| char_literal),
+                Ok(CharLiteral::new(*value, SourceInformation::dummy()))
+            );
+        }
+
+        for source in &["''", "'ab'", "'a", "'\n'"] {
+            assert!(matches!(
+                char_literal(Input::new(source, "")),
+                Err(nom::Err::Error(_))
+            ));
+        }
+
+        assert!(matches!(
+            char_literal(Input::new("'\\q'", "")),
+            Err(nom::Err::Failure(ParserError::InvalidEscapeSequence(_)))
+        ));
+
+        assert_eq!(
+            expression(Input::new("f 'a' 'b'", "")).map(|(_, expression)| expression),
+            Ok(Application::new(
+                Application::new(
+                    Variable::new("f", SourceInformation::dummy()),
+                    CharLiteral::new('a', SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                CharLiteral::new('b', SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_concatenation() {
+        assert_eq!(
+            expression(Input::new(r#""a" ++ "b" ++ "c""#, "")).map(|(_, expression)| expression),
+            Ok(Operation::new(
+                Operator::Concatenate,
+                StringLiteral::new("a", SourceInformation::dummy()),
+                Operation::new(
+                    Operator::Concatenate,
+                    StringLiteral::new("b", SourceInformation::dummy()),
+                    StringLiteral::new("c", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_boolean_literal() {
+        let input = Input::new("True", "");
+
+        assert_eq!(
+            boolean_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 5)), true))
+        );
+
+        let input = Input::new("False", "");
+
+        assert_eq!(
+            boolean_literal(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 6)), false))
+        );
+
+        let input = Input::new("True", "");
+
+        assert_eq!(
+            identifier(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("True", 0, Location::default()),
+                ErrorKind::Verify
+            )))
+        );
+
+        let input = Input::new("Trueish", "");
+
+        assert_eq!(
+            boolean_literal(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("Trueish", 0, Location::default()),
+                ErrorKind::Verify
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_operation() {
+        let input = Input::new("1 + 2", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 6)),
+                Operation::new(
+                    Operator::Add,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 * 2", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 6)),
+                Operation::new(
+                    Operator::Multiply,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 * 2 - 3", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 10)),
+                Operation::new(
+                    Operator::Subtract,
+                    Operation::new(
+                        Operator::Multiply,
+                        Number::new(1.0, SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(3.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 + 2 * 3", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 10)),
+                Operation::new(
+                    Operator::Add,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Operation::new(
+                        Operator::Multiply,
+                        Number::new(2.0, SourceInformation::dummy()),
+                        Number::new(3.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 % 2 ** 3 ** 4", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 16)),
+                Operation::new(
+                    Operator::Remainder,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Operation::new(
+                        Operator::Power,
+                        Number::new(2.0, SourceInformation::dummy()),
+                        Operation::new(
+                            Operator::Power,
+                            Number::new(3.0, SourceInformation::dummy()),
+                            Number::new(4.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 .|. 2 .&. 3 == 4", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 19)),
+                Operation::new(
+                    Operator::Equal,
+                    Operation::new(
+                        Operator::BitwiseOr,
+                        Number::new(1.0, SourceInformation::dummy()),
+                        Operation::new(
+                            Operator::BitwiseAnd,
+                            Number::new(2.0, SourceInformation::dummy()),
+                            Number::new(3.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(4.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 * 2 - 3 / 4", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 14)),
+                Operation::new(
+                    Operator::Subtract,
+                    Operation::new(
+                        Operator::Multiply,
+                        Number::new(1.0, SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    Operation::new(
+                        Operator::Divide,
+                        Number::new(3.0, SourceInformation::dummy()),
+                        Number::new(4.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_left_associative_operations() {
+        for (source, operator) in &[
+            ("1 - 2 - 3", Operator::Subtract),
+            ("1 / 2 / 3", Operator::Divide),
+        ] {
+            let input = Input::new(source, "");
+
+            assert_eq!(
+                expression(input.clone()),
+                Ok((
+                    input.set("", 0, Location::new(1, 10)),
+                    Operation::new(
+                        *operator,
+                        Operation::new(
+                            *operator,
+                            Number::new(1.0, SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        Number::new(3.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()
+                ))
+            );
+        }
+
+        let input = Input::new("1 - 2 * 3 + 4", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 14)),
+                Operation::new(
+                    Operator::Add,
+                    Operation::new(
+                        Operator::Subtract,
+                        Number::new(1.0, SourceInformation::dummy()),
+                        Operation::new(
+                            Operator::Multiply,
+                            Number::new(2.0, SourceInformation::dummy()),
+                            Number::new(3.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(4.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_negation() {
+        let input = Input::new("-1", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 3)),
+                Negation::new(
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("-f x", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 5)),
+                Negation::new(
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Variable::new("x", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("1 - -x * 2", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 11)),
+                Operation::new(
+                    Operator::Subtract,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Negation::new(
+                        Operation::new(
+                            Operator::Multiply,
+                            Variable::new("x", SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("-x ** 2 + 1", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 12)),
+                Operation::new(
+                    Operator::Add,
+                    Negation::new(
+                        Operation::new(
+                            Operator::Power,
+                            Variable::new("x", SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_comparison_operations() {
+        let input = Input::new("1 + 2 < 3", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 10)),
+                Operation::new(
+                    Operator::LessThan,
+                    Operation::new(
+                        Operator::Add,
+                        Number::new(1.0, SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(3.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        for (source, operator) in &[
+            ("1 == 2", Operator::Equal),
+            ("1 /= 2", Operator::NotEqual),
+            ("1 < 2", Operator::LessThan),
+            ("1 <= 2", Operator::LessThanOrEqual),
+            ("1 > 2", Operator::GreaterThan),
+            ("1 >= 2", Operator::GreaterThanOrEqual),
+        ] {
+            let input = Input::new(source, "");
+
+            assert_eq!(
+                expression(input.clone()),
+                Ok((
+                    input.set("", 0, Location::new(1, source.len() + 1)),
+                    Operation::new(
+                        *operator,
+                        Number::new(1.0, SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_user_defined_operations() {
+        let input = Input::new("1 + 2 <> 3", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 11)),
+                Operation::new(
+                    Operator::Add,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Application::new(
+                        Application::new(
+                            Variable::new("<>", SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        Number::new(3.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input =
+            Input::new("1 + 2 <> 3", "").set_fixity("<>", Fixity::new(Associativity::Left, 1));
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 11)),
+                Application::new(
+                    Application::new(
+                        Variable::new("<>", SourceInformation::dummy()),
+                        Operation::new(
+                            Operator::Add,
+                            Number::new(1.0, SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(3.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("(<>)", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 5)),
+                Variable::new("<>", SourceInformation::dummy()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_sections() {
+        let input = Input::new("(+)", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 4)),
+                Lambda::new(
+                    vec!["section_lhs".into(), "section_rhs".into()],
+                    Operation::new(
+                        Operator::Add,
+                        Variable::new("section_lhs", SourceInformation::dummy()),
+                        Variable::new("section_rhs", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("(2 *)", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 6)),
+                Lambda::new(
+                    vec!["section_rhs".into()],
+                    Operation::new(
+                        Operator::Multiply,
+                        Number::new(2.0, SourceInformation::dummy()),
+                        Variable::new("section_rhs", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("(/ 2)", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 6)),
+                Lambda::new(
+                    vec!["section_lhs".into()],
+                    Operation::new(
+                        Operator::Divide,
+                        Variable::new("section_lhs", SourceInformation::dummy()),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("(x <>)", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 7)),
+                Lambda::new(
+                    vec!["section_rhs".into()],
+                    Application::new(
+                        Application::new(
+                            Variable::new("<>", SourceInformation::dummy()),
+                            Variable::new("x", SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        Variable::new("section_rhs", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("(- 2)", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 6)),
+                Negation::new(
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_fixity_declaration() {
+        let input = Input::new("infixr 3 <>, |>", "");
+
+        assert_eq!(
+            fixity_declaration(input.clone()),
+            Ok((
+                input
+                    .set("", 0, Location::new(1, 16))
+                    .set_fixity("<>", Fixity::new(Associativity::Right, 3))
+                    .set_fixity("|>", Fixity::new(Associativity::Right, 3)),
+                ()
+            ))
+        );
+
+        for (source, associativity) in &[
+            ("infixl 7 <>", Associativity::Left),
+            ("infixr 7 <>", Associativity::Right),
+            ("infix 7 <>", Associativity::None),
+        ] {
+            let input = Input::new(source, "");
+
+            assert_eq!(
+                fixity_declaration(input.clone()),
+                Ok((
+                    input
+                        .set("", 0, Location::new(1, source.len() + 1))
+                        .set_fixity("<>", Fixity::new(*associativity, 7)),
+                    ()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn fail_to_parse_invalid_fixity_declarations() {
+        let input = Input::new("infixl 6 <>", "");
+
+        assert!(matches!(
+            fixity_declaration(Input::new("infixl 6 +", "")),
+            Err(nom::Err::Failure(ParserError::BuiltInOperatorFixity(_)))
+        ));
+        assert!(matches!(
+            fixity_declaration(input.set_fixity("<>", Fixity::new(Associativity::Left, 6))),
+            Err(nom::Err::Failure(ParserError::DuplicateFixity(_)))
+        ));
+
+        input.fixity("<>");
+
+        assert!(matches!(
+            fixity_declaration(input),
+            Err(nom::Err::Failure(ParserError::FixityAfterUse(_)))
+        ));
+    }
+
+    #[test]
+    fn fail_to_parse_reserved_operators() {
+        for source in &["=", ":", "->", "|"] {
+            let input = Input::new(source, "");
+
+            assert!(operator(input).is_err());
+        }
+    }
+
+    #[test]
+    fn parse_boolean_operations() {
+        let input = Input::new("x || y && 1 < 2", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 16)),
+                Operation::new(
+                    Operator::Or,
+                    Variable::new("x", SourceInformation::dummy()),
+                    Operation::new(
+                        Operator::And,
+                        Variable::new("y", SourceInformation::dummy()),
+                        Operation::new(
+                            Operator::LessThan,
+                            Number::new(1.0, SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+
+        let input = Input::new("x && y || True", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 15)),
+                Operation::new(
+                    Operator::Or,
+                    Operation::new(
+                        Operator::And,
+                        Variable::new("x", SourceInformation::dummy()),
+                        Variable::new("y", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    Boolean::new(true, SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_line_break() {
+        let input = Input::new("\n", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(2, 1)), ()))
+        );
+
+        let input = Input::new(" \n", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(2, 1)), ()))
+        );
+
+        let input = Input::new("\n\n", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(3, 1)), ()))
+        );
+
+        let input = Input::new("\n \n", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(3, 1)), ()))
+        );
+
+        // EOF
+
+        let input = Input::new("", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 1)), ()))
+        );
+
+        let input = Input::new(" ", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 2)), ()))
+        );
+
+        let input = Input::new(" # foo\n# bar\n", "");
+
+        assert_eq!(
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(3, 1)), ()))
         );
 
-        let input = Input::new("( Number )", "");
+        let input = Input::new(" # foo", "");
 
         assert_eq!(
-            type_(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 11)), number_type.clone()))
+            line_break(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 7)), ()))
         );
+    }
 
-        let input = Input::new("Number -> Number", "");
+    #[test]
+    fn parse_module() {
+        let input = Input::new("", "");
 
         assert_eq!(
-            type_(input.clone()),
-            Ok((
-                input.set("", 0, Location::new(1, 17)),
-                types::Function::new(
-                    number_type.clone(),
-                    number_type.clone(),
-                    SourceInformation::dummy()
-                )
-                .into()
-            ))
+            module(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 1)), Module::new(vec![])))
         );
 
-        let input = Input::new("Number -> Number -> Number", "");
+        let input = Input::new(" ", "");
 
         assert_eq!(
-            type_(input.clone()),
-            Ok((
-                input.set("", 0, Location::new(1, 27)),
-                Type::Function(types::Function::new(
-                    number_type.clone(),
-                    Type::Function(types::Function::new(
-                        number_type.clone(),
-                        number_type.clone(),
-                        SourceInformation::dummy()
-                    )),
-                    SourceInformation::dummy()
-                ))
-            ))
+            module(input.clone()),
+            Ok((input.set("", 0, Location::new(1, 2)), Module::new(vec![])))
         );
-    }
 
-    #[test]
-    fn parse_keyword() {
-        let input = Input::new("foo", "");
+        let input = Input::new("\n", "");
 
         assert_eq!(
-            keyword("foo")(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 4)), ()))
+            module(input.clone()),
+            Ok((input.set("", 0, Location::new(2, 1)), Module::new(vec![])))
         );
 
-        let input = Input::new("fo", "");
+        let input = Input::new("x", "");
 
         assert_eq!(
-            keyword("foo")(input.clone()),
-            Err(nom::Err::Error((
-                input.set("fo", 0, Location::default()),
-                ErrorKind::Tag
+            module(input.clone()),
+            Err(nom::Err::Error(ParserError::Nom(
+                input.set("x", 0, Location::default()),
+                ErrorKind::Eof
             )))
         );
-    }
 
-    #[test]
-    fn parse_identifier() {
-        let input = Input::new("foo", "");
+        let input = Input::new("# foo\n#| bar\n |#\n", "");
 
         assert_eq!(
-            identifier(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 4)), "foo".into()))
+            module(input.clone()),
+            Ok((input.set("", 0, Location::new(4, 1)), Module::new(vec![])))
         );
 
-        let input = Input::new("x1", "");
+        let input = Input::new(
+            "# foo\nx : Number # bar\n#| baz |#\nx = (1 # qux\n)\n# quux",
+            "",
+        );
 
         assert_eq!(
-            identifier(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 3)), "x1".into()))
+            module(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(6, 7)),
+                Module::new(vec![ValueDefinition::new(
+                    "x",
+                    Number::new(1.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()])
+            ))
         );
 
-        let input = Input::new("1st", "");
+        let input = Input::new("f x = x\ny = f 1\n", "");
 
         assert_eq!(
-            identifier(input.clone()),
-            Err(nom::Err::Error((
-                input.set("1st", 0, Location::default()),
-                ErrorKind::Alpha
-            )))
+            module(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(3, 1)),
+                Module::new(vec![
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Variable::new(SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    )
+                    .into(),
+                    ValueDefinition::new(
+                        "y",
+                        Application::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()
+                ])
+            ))
         );
+    }
 
-        let input = Input::new("let", "");
+    #[test]
+    fn parse_function_definition() {
+        let input = Input::new("f : Number -> Number\nf x = x", "");
 
         assert_eq!(
-            identifier(input.clone()),
-            Err(nom::Err::Error((
-                input.set("let", 0, Location::default()),
-                ErrorKind::Verify
-            )))
+            function_definition(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(2, 8)),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
         );
 
-        let input = Input::new("in", "");
+        let input = Input::new("f : (\n  Number ->\n  Number\n)\nf x = x", "");
 
         assert_eq!(
-            identifier(input.clone()),
-            Err(nom::Err::Error((
-                input.set("in", 0, Location::default()),
-                ErrorKind::Verify
-            )))
+            function_definition(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(5, 8)),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
         );
-    }
 
-    #[test]
-    fn parse_number_literal() {
-        let input = Input::new("1", "");
+        let input = Input::new("f : ((Number -> Number))\nf x = x", "");
 
         assert_eq!(
-            number_literal(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 2)), 1.0))
+            function_definition(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(2, 8)),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
         );
+    }
 
-        let input = Input::new("01", "");
+    #[test]
+    fn parse_value_definition() {
+        let input = Input::new("x : Number\nx = 42", "");
 
         assert_eq!(
-            number_literal(input.clone()),
-            Err(nom::Err::Error((
-                input.set("01", 0, Location::default()),
-                ErrorKind::OneOf
-            )))
+            value_definition(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(2, 7)),
+                ValueDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+            ))
         );
+    }
 
-        let input = Input::new("-1", "");
+    #[test]
+    fn parse_where_blocks() {
+        let input = Input::new("f : Number -> Number\nf x = y\n  where\n    y = x", "");
 
         assert_eq!(
-            number_literal(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 3)), -1.0))
+            function_definition(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(4, 10)),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Let::new(
+                        vec![ValueDefinition::new(
+                            "y",
+                            Variable::new("x", SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()],
+                        Variable::new("y", SourceInformation::dummy())
+                    ),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+            ))
         );
 
-        let input = Input::new("42", "");
+        let input = Input::new("x : Number\nx = y where y = 42\n            z = 0", "");
 
         assert_eq!(
-            number_literal(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 3)), 42.0))
+            value_definition(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(3, 18)),
+                ValueDefinition::new(
+                    "x",
+                    Let::new(
+                        vec![
+                            ValueDefinition::new(
+                                "y",
+                                Number::new(42.0, SourceInformation::dummy()),
+                                types::Variable::new(SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            )
+                            .into(),
+                            ValueDefinition::new(
+                                "z",
+                                Number::new(0.0, SourceInformation::dummy()),
+                                types::Variable::new(SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            )
+                            .into()
+                        ],
+                        Variable::new("y", SourceInformation::dummy())
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+            ))
         );
 
-        let input = Input::new("3.14", "");
+        let input = Input::new("x : Number\nx = y\nwhere y = 42", "");
 
         assert_eq!(
-            number_literal(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 5)), 3.14))
+            value_definition(input.clone()),
+            Ok((
+                input.set("\nwhere y = 42", 0, Location::new(2, 6)),
+                ValueDefinition::new(
+                    "x",
+                    Variable::new("y", SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+            ))
         );
     }
 
     #[test]
-    fn parse_operation() {
-        let input = Input::new("1 + 2", "");
+    fn parse_function_clauses() {
+        let input = Input::new(
+            "f : Number -> Number\nf 0 = 1\nf n\n  | n > 0 = n\n  | otherwise = m\n  where\n    m = 0",
+            "",
+        );
 
         assert_eq!(
-            expression(input.clone()),
+            function_definition(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 6)),
-                Operation::new(
-                    Operator::Add,
-                    Number::new(1.0, SourceInformation::dummy()),
-                    Number::new(2.0, SourceInformation::dummy()),
+                input.set("", 0, Location::new(7, 10)),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["clause_argument_0".into()],
+                    Match::new(
+                        vec!["clause_argument_0".into()],
+                        vec![
+                            Clause::new(
+                                vec![Number::new(0.0, SourceInformation::dummy()).into()],
+                                vec![Guard::new(
+                                    Boolean::new(true, SourceInformation::dummy()),
+                                    Number::new(1.0, SourceInformation::dummy())
+                                )],
+                                vec![],
+                                SourceInformation::dummy()
+                            ),
+                            Clause::new(
+                                vec![Variable::new("n", SourceInformation::dummy()).into()],
+                                vec![
+                                    Guard::new(
+                                        Operation::new(
+                                            Operator::GreaterThan,
+                                            Variable::new("n", SourceInformation::dummy()),
+                                            Number::new(0.0, SourceInformation::dummy()),
+                                            SourceInformation::dummy()
+                                        ),
+                                        Variable::new("n", SourceInformation::dummy())
+                                    ),
+                                    Guard::new(
+                                        Boolean::new(true, SourceInformation::dummy()),
+                                        Variable::new("m", SourceInformation::dummy())
+                                    )
+                                ],
+                                vec![ValueDefinition::new(
+                                    "m",
+                                    Number::new(0.0, SourceInformation::dummy()),
+                                    types::Variable::new(SourceInformation::dummy()),
+                                    SourceInformation::dummy()
+                                )
+                                .into()],
+                                SourceInformation::dummy()
+                            )
+                        ],
+                        SourceInformation::dummy()
+                    ),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
                     SourceInformation::dummy()
                 )
-                .into()
             ))
         );
 
-        let input = Input::new("1 * 2", "");
+        let input = Input::new("f : Number -> Number\nf x = x\ng x = x", "");
 
         assert_eq!(
-            expression(input.clone()),
+            function_definition(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 6)),
-                Operation::new(
-                    Operator::Multiply,
-                    Number::new(1.0, SourceInformation::dummy()),
-                    Number::new(2.0, SourceInformation::dummy()),
+                input.set("\ng x = x", 0, Location::new(2, 8)),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
                     SourceInformation::dummy()
                 )
-                .into()
             ))
         );
+    }
 
-        let input = Input::new("1 * 2 - 3", "");
+    #[test]
+    fn fail_to_parse_function_clauses_with_different_numbers_of_arguments() {
+        assert!(matches!(
+            function_definition(Input::new(
+                "f : Number -> Number -> Number\nf 0 y = y\nf x = x",
+                ""
+            )),
+            Err(nom::Err::Failure(ParserError::MismatchedClauseArguments(_)))
+        ));
+    }
+
+    #[test]
+    fn parse_application() {
+        let input = Input::new("f x", "");
 
         assert_eq!(
             expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 10)),
-                Operation::new(
-                    Operator::Subtract,
-                    Operation::new(
-                        Operator::Multiply,
-                        Number::new(1.0, SourceInformation::dummy()),
-                        Number::new(2.0, SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    ),
-                    Number::new(3.0, SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 4)),
+                Application::new(
+                    Variable::new("f", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
                     SourceInformation::dummy()
                 )
                 .into()
             ))
         );
 
-        let input = Input::new("1 + 2 * 3", "");
+        let input = Input::new("f x y", "");
 
         assert_eq!(
             expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 10)),
-                Operation::new(
-                    Operator::Add,
-                    Number::new(1.0, SourceInformation::dummy()),
-                    Operation::new(
-                        Operator::Multiply,
-                        Number::new(2.0, SourceInformation::dummy()),
-                        Number::new(3.0, SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 6)),
+                Application::new(
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Variable::new("x", SourceInformation::dummy()),
                         SourceInformation::dummy()
                     ),
-                    SourceInformation::dummy(),
+                    Variable::new("y", SourceInformation::dummy()),
+                    SourceInformation::dummy()
                 )
                 .into()
             ))
         );
 
-        let input = Input::new("1 * 2 - 3 / 4", "");
+        let input = Input::new("f", "");
 
         assert_eq!(
-            expression(input.clone()),
+            application(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 14)),
-                Operation::new(
-                    Operator::Subtract,
-                    Operation::new(
-                        Operator::Multiply,
-                        Number::new(1.0, SourceInformation::dummy()),
-                        Number::new(2.0, SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    ),
-                    Operation::new(
-                        Operator::Divide,
-                        Number::new(3.0, SourceInformation::dummy()),
-                        Number::new(4.0, SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    ),
-                    SourceInformation::dummy()
-                )
-                .into()
+                input.set("", 0, Location::new(1, 2)),
+                Variable::new("f", SourceInformation::dummy()).into()
             ))
         );
     }
 
     #[test]
-    fn parse_line_break() {
-        let input = Input::new("\n", "");
-
-        assert_eq!(
-            line_break(input.clone()),
-            Ok((input.set("", 0, Location::new(2, 1)), ()))
-        );
-
-        let input = Input::new(" \n", "");
+    fn parse_let() {
+        let input = Input::new("let x = 42\nin x", "");
 
         assert_eq!(
-            line_break(input.clone()),
-            Ok((input.set("", 0, Location::new(2, 1)), ()))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(2, 5)),
+                Let::new(
+                    vec![ValueDefinition::new(
+                        "x",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("x", SourceInformation::dummy())
+                )
+            ))
         );
 
-        let input = Input::new("\n\n", "");
+        let input = Input::new("let x = 42 in x", "");
 
         assert_eq!(
-            line_break(input.clone()),
-            Ok((input.set("", 0, Location::new(3, 1)), ()))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 16)),
+                Let::new(
+                    vec![ValueDefinition::new(
+                        "x",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("x", SourceInformation::dummy())
+                )
+            ))
         );
 
-        let input = Input::new("\n \n", "");
+        let input = Input::new("let x = 42\ny = 42\nin x", "");
 
         assert_eq!(
-            line_break(input.clone()),
-            Ok((input.set("", 0, Location::new(3, 1)), ()))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(3, 5)),
+                Let::new(
+                    vec![
+                        ValueDefinition::new(
+                            "x",
+                            Number::new(42.0, SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into(),
+                        ValueDefinition::new(
+                            "y",
+                            Number::new(42.0, SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    Variable::new("x", SourceInformation::dummy())
+                )
+            ))
         );
 
-        // EOF
-
-        let input = Input::new("", "");
+        let input = Input::new("let x : Number\nx = 42\nin x", "");
 
         assert_eq!(
-            line_break(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 1)), ()))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(3, 5)),
+                Let::new(
+                    vec![ValueDefinition::new(
+                        "x",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("x", SourceInformation::dummy())
+                )
+            ))
         );
 
-        let input = Input::new(" ", "");
+        let input = Input::new("let f x = x\nin f", "");
 
         assert_eq!(
-            line_break(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 2)), ()))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(2, 5)),
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Variable::new(SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("f", SourceInformation::dummy())
+                )
+            ))
         );
-    }
 
-    #[test]
-    fn parse_module() {
-        let input = Input::new("", "");
+        let input = Input::new("let f : Number -> Number\nf x = x\nin f", "");
 
         assert_eq!(
-            module(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 1)), Module::new(vec![])))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(3, 5)),
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "f",
+                        vec!["x".into()],
+                        Variable::new("x", SourceInformation::dummy()),
+                        types::Function::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            types::Number::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("f", SourceInformation::dummy())
+                )
+            ))
         );
 
-        let input = Input::new(" ", "");
+        let input = Input::new("let f x = x\ng x = x\nin x", "");
 
         assert_eq!(
-            module(input.clone()),
-            Ok((input.set("", 0, Location::new(1, 2)), Module::new(vec![])))
+            let_(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(3, 5)),
+                Let::new(
+                    vec![
+                        FunctionDefinition::new(
+                            "f",
+                            vec!["x".into()],
+                            Variable::new("x", SourceInformation::dummy()),
+                            types::Function::new(
+                                types::Variable::new(SourceInformation::dummy()),
+                                types::Variable::new(SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            ),
+                            SourceInformation::dummy()
+                        )
+                        .into(),
+                        FunctionDefinition::new(
+                            "g",
+                            vec!["x".into()],
+                            Variable::new("x", SourceInformation::dummy()),
+                            types::Function::new(
+                                types::Variable::new(SourceInformation::dummy()),
+                                types::Variable::new(SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            ),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    Variable::new("x", SourceInformation::dummy())
+                )
+            ))
         );
 
-        let input = Input::new("\n", "");
+        let input = Input::new("let x = 42\n   y = 42\n in x", "");
 
-        assert_eq!(
-            module(input.clone()),
-            Ok((input.set("", 0, Location::new(2, 1)), Module::new(vec![])))
-        );
+        assert!(let_(input).is_err());
 
-        let input = Input::new("x", "");
+        let input = Input::new("let x = 42\n      y = 42\n in x", "");
 
-        assert_eq!(
-            module(input.clone()),
-            Err(nom::Err::Error((
-                input.set("x", 0, Location::default()),
-                ErrorKind::Eof
-            )))
-        );
+        assert!(let_(input).is_err());
     }
 
     #[test]
-    fn parse_function_definition() {
-        let input = Input::new("f : Number -> Number\nf x = x", "");
+    fn parse_let_as_expression() {
+        let input = Input::new("let x = 42\nin x", "");
 
         assert_eq!(
-            function_definition(input.clone()),
+            expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(2, 8)),
-                FunctionDefinition::new(
-                    "f",
-                    vec!["x".into()],
-                    Variable::new("x", SourceInformation::dummy()),
-                    types::Function::new(
-                        types::Number::new(SourceInformation::dummy()),
-                        types::Number::new(SourceInformation::dummy()),
+                input.set("", 0, Location::new(2, 5)),
+                Let::new(
+                    vec![ValueDefinition::new(
+                        "x",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Variable::new(SourceInformation::dummy()),
                         SourceInformation::dummy()
-                    ),
-                    SourceInformation::dummy()
+                    )
+                    .into()],
+                    Variable::new("x", SourceInformation::dummy())
                 )
+                .into()
             ))
         );
 
-        let input = Input::new("f : (\n  Number ->\n  Number\n)\nf x = x", "");
+        let input = Input::new("(\nlet\nx = 42\ny = 42\nin x\n)", "");
 
         assert_eq!(
-            function_definition(input.clone()),
+            expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(5, 8)),
-                FunctionDefinition::new(
-                    "f",
-                    vec!["x".into()],
-                    Variable::new("x", SourceInformation::dummy()),
-                    types::Function::new(
-                        types::Number::new(SourceInformation::dummy()),
-                        types::Number::new(SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    ),
-                    SourceInformation::dummy()
+                input.set("", 0, Location::new(6, 2)),
+                Let::new(
+                    vec![
+                        ValueDefinition::new(
+                            "x",
+                            Number::new(42.0, SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into(),
+                        ValueDefinition::new(
+                            "y",
+                            Number::new(42.0, SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    Variable::new("x", SourceInformation::dummy())
                 )
+                .into()
             ))
         );
+    }
 
-        let input = Input::new("f : ((Number -> Number))\nf x = x", "");
+    #[test]
+    fn parse_destructuring_let() {
+        let value_definition = |name, expression: Expression| -> Definition {
+            ValueDefinition::new(
+                name,
+                expression,
+                types::Variable::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let variable = |name: &str| Variable::new(name, SourceInformation::dummy());
+        let tuple_pattern = |names: &[&str]| {
+            TuplePattern::new(
+                names.iter().map(|name| variable(name).into()).collect(),
+                SourceInformation::dummy(),
+            )
+        };
+
+        let destructuring = |names: &[&str], expression: Expression| {
+            Destructuring::new(tuple_pattern(names), expression, SourceInformation::dummy())
+        };
 
         assert_eq!(
-            function_definition(input.clone()),
-            Ok((
-                input.set("", 0, Location::new(2, 8)),
-                FunctionDefinition::new(
-                    "f",
-                    vec!["x".into()],
-                    Variable::new("x", SourceInformation::dummy()),
-                    types::Function::new(
-                        types::Number::new(SourceInformation::dummy()),
-                        types::Number::new(SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    ),
-                    SourceInformation::dummy()
-                )
+            let_(Input::new("let (x, y) = z\n in x", "")).map(|(_, let_)| let_),
+            Ok(Let::with_destructurings(
+                vec![],
+                vec![destructuring(&["x", "y"], variable("z").into())],
+                variable("x")
             ))
         );
+
+        assert_eq!(
+            let_(Input::new(
+                "let a = 1\n    (x, y) = f a\n    b = x\n    c = y\n in b",
+                ""
+            ))
+            .map(|(_, let_)| let_),
+            Ok(Let::with_destructurings(
+                vec![
+                    value_definition("a", Number::new(1.0, SourceInformation::dummy()).into()),
+                    value_definition("b", variable("x").into()),
+                    value_definition("c", variable("y").into())
+                ],
+                vec![destructuring(
+                    &["x", "y"],
+                    Application::new(variable("f"), variable("a"), SourceInformation::dummy())
+                        .into()
+                )],
+                variable("b")
+            ))
+        );
+
+        assert_eq!(
+            expression(Input::new("let (x, y) = z in x", "")).map(|(_, let_)| let_),
+            Ok(Let::with_destructurings(
+                vec![],
+                vec![destructuring(&["x", "y"], variable("z").into())],
+                variable("x")
+            )
+            .into())
+        );
     }
 
     #[test]
-    fn parse_value_definition() {
-        let input = Input::new("x : Number\nx = 42", "");
+    fn parse_let_in_definition() {
+        let input = Input::new("x : Number\nx = (let y = 42\nin y)", "");
 
         assert_eq!(
             value_definition(input.clone()),
             Ok((
-                input.set("", 0, Location::new(2, 7)),
+                input.set("", 0, Location::new(3, 6)),
                 ValueDefinition::new(
                     "x",
-                    Number::new(42.0, SourceInformation::dummy()),
+                    Let::new(
+                        vec![ValueDefinition::new(
+                            "y",
+                            Number::new(42.0, SourceInformation::dummy()),
+                            types::Variable::new(SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()],
+                        Variable::new("y", SourceInformation::dummy())
+                    ),
                     types::Number::new(SourceInformation::dummy()),
                     SourceInformation::dummy()
                 )
+                .into()
             ))
         );
     }
 
     #[test]
-    fn parse_application() {
-        let input = Input::new("f x", "");
+    fn parse_if() {
+        let input = Input::new("if True then 1 else 2", "");
 
         assert_eq!(
-            expression(input.clone()),
+            if_(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 4)),
-                Application::new(
-                    Variable::new("f", SourceInformation::dummy()),
-                    Variable::new("x", SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 22)),
+                If::new(
+                    Boolean::new(true, SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
                     SourceInformation::dummy()
                 )
-                .into()
             ))
         );
 
-        let input = Input::new("f x y", "");
+        let input = Input::new("if x < 1 then 1 else if x < 2 then 2 else 3", "");
 
         assert_eq!(
             expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 6)),
-                Application::new(
-                    Application::new(
-                        Variable::new("f", SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 44)),
+                If::new(
+                    Operation::new(
+                        Operator::LessThan,
                         Variable::new("x", SourceInformation::dummy()),
+                        Number::new(1.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    If::new(
+                        Operation::new(
+                            Operator::LessThan,
+                            Variable::new("x", SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        Number::new(2.0, SourceInformation::dummy()),
+                        Number::new(3.0, SourceInformation::dummy()),
                         SourceInformation::dummy()
                     ),
-                    Variable::new("y", SourceInformation::dummy()),
                     SourceInformation::dummy()
                 )
                 .into()
             ))
         );
 
-        let input = Input::new("f", "");
-
-        assert_eq!(
-            application(input.clone()),
-            Err(nom::Err::Error((
-                input.set("", 0, Location::new(1, 2)),
-                ErrorKind::Tag
-            )))
-        );
-    }
-
-    #[test]
-    fn parse_let() {
-        let input = Input::new("let x = 42\nin x", "");
+        let input = Input::new("(if True\nthen 1\nelse 2)", "");
 
         assert_eq!(
-            let_(input.clone()),
+            expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(2, 5)),
-                Let::new(
-                    vec![ValueDefinition::new(
-                        "x",
-                        Number::new(42.0, SourceInformation::dummy()),
-                        types::Variable::new(SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    )
-                    .into()],
-                    Variable::new("x", SourceInformation::dummy())
+                input.set("", 0, Location::new(3, 8)),
+                If::new(
+                    Boolean::new(true, SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
                 )
+                .into()
             ))
         );
+    }
 
-        let input = Input::new("let x = 42 in x", "");
+    #[test]
+    fn parse_lambda() {
+        let input = Input::new("\\x y -> x + y", "");
 
         assert_eq!(
-            let_(input.clone()),
+            lambda(input.clone()),
             Ok((
-                input.set("", 0, Location::new(1, 16)),
-                Let::new(
-                    vec![ValueDefinition::new(
-                        "x",
-                        Number::new(42.0, SourceInformation::dummy()),
-                        types::Variable::new(SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 14)),
+                Lambda::new(
+                    vec!["x".into(), "y".into()],
+                    Operation::new(
+                        Operator::Add,
+                        Variable::new("x", SourceInformation::dummy()),
+                        Variable::new("y", SourceInformation::dummy()),
                         SourceInformation::dummy()
-                    )
-                    .into()],
-                    Variable::new("x", SourceInformation::dummy())
+                    ),
+                    SourceInformation::dummy()
                 )
             ))
         );
 
-        let input = Input::new("let x = 42\ny = 42\nin x", "");
+        let input = Input::new("f (\\x -> x) 1", "");
 
         assert_eq!(
-            let_(input.clone()),
+            expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(3, 5)),
-                Let::new(
-                    vec![
-                        ValueDefinition::new(
-                            "x",
-                            Number::new(42.0, SourceInformation::dummy()),
-                            types::Variable::new(SourceInformation::dummy()),
-                            SourceInformation::dummy()
-                        )
-                        .into(),
-                        ValueDefinition::new(
-                            "y",
-                            Number::new(42.0, SourceInformation::dummy()),
-                            types::Variable::new(SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 14)),
+                Application::new(
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Lambda::new(
+                            vec!["x".into()],
+                            Variable::new("x", SourceInformation::dummy()),
                             SourceInformation::dummy()
-                        )
-                        .into()
-                    ],
-                    Variable::new("x", SourceInformation::dummy())
-                )
-            ))
-        );
-
-        let input = Input::new("let x : Number\nx = 42\nin x", "");
-
-        assert_eq!(
-            let_(input.clone()),
-            Ok((
-                input.set("", 0, Location::new(3, 5)),
-                Let::new(
-                    vec![ValueDefinition::new(
-                        "x",
-                        Number::new(42.0, SourceInformation::dummy()),
-                        types::Number::new(SourceInformation::dummy()),
+                        ),
                         SourceInformation::dummy()
-                    )
-                    .into()],
-                    Variable::new("x", SourceInformation::dummy())
+                    ),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy()
                 )
+                .into()
             ))
         );
 
-        let input = Input::new("let f x = x\nin f", "");
+        assert!(lambda(Input::new("\\ -> x", "")).is_err());
+    }
+
+    #[test]
+    fn parse_type_definition() {
+        let input = Input::new("data Shape = Circle Number | Rect Number Number", "");
 
         assert_eq!(
-            let_(input.clone()),
+            type_definition(input.clone()),
             Ok((
-                input.set("", 0, Location::new(2, 5)),
-                Let::new(
-                    vec![FunctionDefinition::new(
-                        "f",
-                        vec!["x".into()],
-                        Variable::new("x", SourceInformation::dummy()),
-                        types::Function::new(
-                            types::Variable::new(SourceInformation::dummy()),
-                            types::Variable::new(SourceInformation::dummy()),
+                input.set("", 0, Location::new(1, 48)),
+                TypeDefinition::new(
+                    "Shape",
+                    vec![
+                        ConstructorDefinition::new(
+                            "Circle",
+                            vec![types::Number::new(SourceInformation::dummy()).into()],
                             SourceInformation::dummy()
                         ),
-                        SourceInformation::dummy()
-                    )
-                    .into()],
-                    Variable::new("f", SourceInformation::dummy())
+                        ConstructorDefinition::new(
+                            "Rect",
+                            vec![
+                                types::Number::new(SourceInformation::dummy()).into(),
+                                types::Number::new(SourceInformation::dummy()).into()
+                            ],
+                            SourceInformation::dummy()
+                        )
+                    ],
+                    SourceInformation::dummy()
                 )
             ))
         );
 
-        let input = Input::new("let f : Number -> Number\nf x = x\nin f", "");
+        let input = Input::new(
+            "data List
+  = Cons Number List
+  | Nil",
+            "",
+        );
 
         assert_eq!(
-            let_(input.clone()),
+            type_definition(input.clone()),
             Ok((
-                input.set("", 0, Location::new(3, 5)),
-                Let::new(
-                    vec![FunctionDefinition::new(
-                        "f",
-                        vec!["x".into()],
-                        Variable::new("x", SourceInformation::dummy()),
-                        types::Function::new(
-                            types::Number::new(SourceInformation::dummy()),
-                            types::Number::new(SourceInformation::dummy()),
+                input.set("", 0, Location::new(3, 8)),
+                TypeDefinition::new(
+                    "List",
+                    vec![
+                        ConstructorDefinition::new(
+                            "Cons",
+                            vec![
+                                types::Number::new(SourceInformation::dummy()).into(),
+                                types::Reference::new("List", SourceInformation::dummy()).into()
+                            ],
                             SourceInformation::dummy()
                         ),
-                        SourceInformation::dummy()
-                    )
-                    .into()],
-                    Variable::new("f", SourceInformation::dummy())
+                        ConstructorDefinition::new("Nil", vec![], SourceInformation::dummy())
+                    ],
+                    SourceInformation::dummy()
                 )
             ))
         );
 
-        let input = Input::new("let f x = x\ng x = x\nin x", "");
+        let input = Input::new("data shape = Circle", "");
+
+        assert!(type_definition(input).is_err());
+    }
+
+    #[test]
+    fn parse_case() {
+        let input = Input::new(
+            "case x of
+  Circle r -> r
+  Rect w h -> w * h",
+            "",
+        );
 
         assert_eq!(
-            let_(input.clone()),
+            case_(input.clone()),
             Ok((
-                input.set("", 0, Location::new(3, 5)),
-                Let::new(
+                input.set("", 0, Location::new(3, 20)),
+                Case::new(
+                    Variable::new("x", SourceInformation::dummy()),
                     vec![
-                        FunctionDefinition::new(
-                            "f",
-                            vec!["x".into()],
-                            Variable::new("x", SourceInformation::dummy()),
-                            types::Function::new(
-                                types::Variable::new(SourceInformation::dummy()),
-                                types::Variable::new(SourceInformation::dummy()),
+                        Alternative::new(
+                            ConstructorPattern::new(
+                                "Circle",
+                                vec![Variable::new("r", SourceInformation::dummy()).into()],
                                 SourceInformation::dummy()
                             ),
-                            SourceInformation::dummy()
-                        )
-                        .into(),
-                        FunctionDefinition::new(
-                            "g",
-                            vec!["x".into()],
-                            Variable::new("x", SourceInformation::dummy()),
-                            types::Function::new(
-                                types::Variable::new(SourceInformation::dummy()),
-                                types::Variable::new(SourceInformation::dummy()),
+                            Variable::new("r", SourceInformation::dummy())
+                        ),
+                        Alternative::new(
+                            ConstructorPattern::new(
+                                "Rect",
+                                vec![
+                                    Variable::new("w", SourceInformation::dummy()).into(),
+                                    Variable::new("h", SourceInformation::dummy()).into()
+                                ],
                                 SourceInformation::dummy()
                             ),
-                            SourceInformation::dummy()
+                            Operation::new(
+                                Operator::Multiply,
+                                Variable::new("w", SourceInformation::dummy()),
+                                Variable::new("h", SourceInformation::dummy()),
+                                SourceInformation::dummy()
+                            )
                         )
-                        .into()
                     ],
-                    Variable::new("x", SourceInformation::dummy())
+                    SourceInformation::dummy()
                 )
             ))
         );
-    }
 
-    #[test]
-    fn parse_let_as_expression() {
-        let input = Input::new("let x = 42\nin x", "");
+        let input = Input::new(
+            "(case x of True -> 1
+           False -> 2)",
+            "",
+        );
 
         assert_eq!(
             expression(input.clone()),
             Ok((
-                input.set("", 0, Location::new(2, 5)),
-                Let::new(
-                    vec![ValueDefinition::new(
-                        "x",
-                        Number::new(42.0, SourceInformation::dummy()),
-                        types::Variable::new(SourceInformation::dummy()),
-                        SourceInformation::dummy()
-                    )
-                    .into()],
-                    Variable::new("x", SourceInformation::dummy())
+                input.set("", 0, Location::new(2, 23)),
+                Case::new(
+                    Variable::new("x", SourceInformation::dummy()),
+                    vec![
+                        Alternative::new(
+                            Boolean::new(true, SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy())
+                        ),
+                        Alternative::new(
+                            Boolean::new(false, SourceInformation::dummy()),
+                            Number::new(2.0, SourceInformation::dummy())
+                        )
+                    ],
+                    SourceInformation::dummy()
                 )
                 .into()
             ))
         );
+    }
 
-        let input = Input::new("(\nlet\nx = 42\ny = 42\nin x\n)", "");
+    #[test]
+    fn parse_pattern() {
+        let input = Input::new("Cons x (Cons _ Nil)", "");
 
         assert_eq!(
-            expression(input.clone()),
+            pattern(input.clone()),
             Ok((
-                input.set("", 0, Location::new(6, 2)),
-                Let::new(
+                input.set("", 0, Location::new(1, 20)),
+                ConstructorPattern::new(
+                    "Cons",
                     vec![
-                        ValueDefinition::new(
-                            "x",
-                            Number::new(42.0, SourceInformation::dummy()),
-                            types::Variable::new(SourceInformation::dummy()),
-                            SourceInformation::dummy()
-                        )
-                        .into(),
-                        ValueDefinition::new(
-                            "y",
-                            Number::new(42.0, SourceInformation::dummy()),
-                            types::Variable::new(SourceInformation::dummy()),
+                        Variable::new("x", SourceInformation::dummy()).into(),
+                        ConstructorPattern::new(
+                            "Cons",
+                            vec![
+                                Wildcard::new(SourceInformation::dummy()).into(),
+                                ConstructorPattern::new("Nil", vec![], SourceInformation::dummy())
+                                    .into()
+                            ],
                             SourceInformation::dummy()
                         )
                         .into()
                     ],
-                    Variable::new("x", SourceInformation::dummy())
+                    SourceInformation::dummy()
                 )
                 .into()
             ))
         );
+
+        let input = Input::new("42", "");
+
+        assert_eq!(
+            pattern(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 3)),
+                Number::new(42.0, SourceInformation::dummy()).into()
+            ))
+        );
+
+        let input = Input::new("-42", "");
+
+        assert_eq!(
+            pattern(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 4)),
+                Number::new(-42.0, SourceInformation::dummy()).into()
+            ))
+        );
+
+        assert_eq!(
+            pattern(Input::new("Trueish", "")).map(|(_, pattern)| pattern),
+            Ok(ConstructorPattern::new("Trueish", vec![], SourceInformation::dummy()).into())
+        );
+
+        assert_eq!(
+            expression(Input::new("Falsey", "")).map(|(_, expression)| expression),
+            Ok(Constructor::new("Falsey", SourceInformation::dummy()).into())
+        );
     }
 
     #[test]
-    fn parse_let_in_definition() {
-        let input = Input::new("x : Number\nx = (let y = 42\nin y)", "");
+    fn parse_list_patterns() {
+        let nil =
+            || ConstructorPattern::new(NIL_CONSTRUCTOR_NAME, vec![], SourceInformation::dummy());
+        let cons = |head: Pattern, tail: Pattern| -> Pattern {
+            ConstructorPattern::new(
+                CONS_CONSTRUCTOR_NAME,
+                vec![head, tail],
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let variable = |name| Variable::new(name, SourceInformation::dummy()).into();
+
+        for (source, target) in [
+            ("[]", nil().into()),
+            ("x :: xs", cons(variable("x"), variable("xs"))),
+            (
+                "x :: y :: _",
+                cons(
+                    variable("x"),
+                    cons(
+                        variable("y"),
+                        Wildcard::new(SourceInformation::dummy()).into(),
+                    ),
+                ),
+            ),
+            (
+                "[x, 1]",
+                cons(
+                    variable("x"),
+                    cons(
+                        Number::new(1.0, SourceInformation::dummy()).into(),
+                        nil().into(),
+                    ),
+                ),
+            ),
+            ("(x :: xs)", cons(variable("x"), variable("xs"))),
+        ] {
+            assert_eq!(
+                pattern(Input::new(source, "")).map(|(_, pattern)| pattern),
+                Ok(target)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_tuple_patterns() {
+        let variable = |name| Variable::new(name, SourceInformation::dummy()).into();
 
         assert_eq!(
-            value_definition(input.clone()),
-            Ok((
-                input.set("", 0, Location::new(3, 6)),
-                ValueDefinition::new(
+            pattern(Input::new("(x, _)", "")).map(|(_, pattern)| pattern),
+            Ok(TuplePattern::new(
+                vec![
+                    variable("x"),
+                    Wildcard::new(SourceInformation::dummy()).into()
+                ],
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+
+        assert_eq!(
+            pattern(Input::new("((x, y), Nil)", "")).map(|(_, pattern)| pattern),
+            Ok(TuplePattern::new(
+                vec![
+                    TuplePattern::new(
+                        vec![variable("x"), variable("y")],
+                        SourceInformation::dummy()
+                    )
+                    .into(),
+                    ConstructorPattern::new("Nil", vec![], SourceInformation::dummy()).into()
+                ],
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_list_literal() {
+        let nil = || Constructor::new(NIL_CONSTRUCTOR_NAME, SourceInformation::dummy());
+        let cons = |head: Expression, tail: Expression| -> Expression {
+            Application::new(
+                Application::new(
+                    Constructor::new(CONS_CONSTRUCTOR_NAME, SourceInformation::dummy()),
+                    head,
+                    SourceInformation::dummy(),
+                ),
+                tail,
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let number = |value| Number::new(value, SourceInformation::dummy()).into();
+
+        for (source, target) in [
+            ("[]", nil().into()),
+            ("[ ]", nil().into()),
+            ("[1]", cons(number(1.0), nil().into())),
+            ("[1, 2]", cons(number(1.0), cons(number(2.0), nil().into()))),
+            (
+                "[\n  1,\n  2\n]",
+                cons(number(1.0), cons(number(2.0), nil().into())),
+            ),
+        ] {
+            assert_eq!(
+                expression(Input::new(source, "")).map(|(_, expression)| expression),
+                Ok(target)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_cons_operations() {
+        let cons = |head: Expression, tail: Expression| -> Expression {
+            Application::new(
+                Application::new(
+                    Constructor::new(CONS_CONSTRUCTOR_NAME, SourceInformation::dummy()),
+                    head,
+                    SourceInformation::dummy(),
+                ),
+                tail,
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+        let variable = |name| Variable::new(name, SourceInformation::dummy()).into();
+
+        assert_eq!(
+            expression(Input::new("x :: xs", "")).map(|(_, expression)| expression),
+            Ok(cons(variable("x"), variable("xs")))
+        );
+
+        assert_eq!(
+            expression(Input::new("1 + x :: y :: []", "")).map(|(_, expression)| expression),
+            Ok(cons(
+                Operation::new(
+                    Operator::Add,
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                cons(
+                    variable("y"),
+                    Constructor::new(NIL_CONSTRUCTOR_NAME, SourceInformation::dummy()).into()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_tuple_literal() {
+        let number = |value| Number::new(value, SourceInformation::dummy()).into();
+
+        for (source, elements) in [
+            ("(1, 2)", vec![number(1.0), number(2.0)]),
+            ("( 1 , 2 , 3 )", vec![number(1.0), number(2.0), number(3.0)]),
+            ("(\n  1,\n  2\n)", vec![number(1.0), number(2.0)]),
+            (
+                "(f 1, [])",
+                vec![
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        number(1.0),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    Constructor::new(NIL_CONSTRUCTOR_NAME, SourceInformation::dummy()).into(),
+                ],
+            ),
+        ] {
+            assert_eq!(
+                expression(Input::new(source, "")).map(|(_, expression)| expression),
+                Ok(Tuple::new(elements, SourceInformation::dummy()).into())
+            );
+        }
+
+        assert_eq!(
+            expression(Input::new("(1)", "")).map(|(_, expression)| expression),
+            Ok(number(1.0))
+        );
+    }
+
+    #[test]
+    fn parse_record_literal() {
+        let number = |value| Number::new(value, SourceInformation::dummy()).into();
+
+        for source in &["{ x = 1, y = 2 }", "{x=1,y=2}", "{\n  x = 1,\n  y = 2\n}"] {
+            assert_eq!(
+                expression(Input::new(source, "")).map(|(_, expression)| expression),
+                Ok(Record::new(
+                    vec![("x".into(), number(1.0)), ("y".into(), number(2.0))]
+                        .into_iter()
+                        .collect(),
+                    SourceInformation::dummy()
+                )
+                .into())
+            );
+        }
+
+        assert!(expression(Input::new("{ x = 1, x = 2 }", "")).is_err());
+    }
+
+    #[test]
+    fn parse_record_update() {
+        assert_eq!(
+            expression(Input::new("{ p | x = 1 }", "")).map(|(_, expression)| expression),
+            Ok(RecordUpdate::new(
+                Variable::new("p", SourceInformation::dummy()),
+                vec![(
+                    "x".into(),
+                    Number::new(1.0, SourceInformation::dummy()).into()
+                )]
+                .into_iter()
+                .collect(),
+                SourceInformation::dummy()
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn parse_record_elements() {
+        let variable = |name| Variable::new(name, SourceInformation::dummy());
+        let record_element = |record: Expression, name| -> Expression {
+            RecordElement::new(record, name, SourceInformation::dummy()).into()
+        };
+
+        for (source, expected) in [
+            ("p.x", record_element(variable("p").into(), "x")),
+            (
+                "p.x.y",
+                record_element(record_element(variable("p").into(), "x"), "y"),
+            ),
+            (
+                "f p.x",
+                Application::new(
+                    variable("f"),
+                    record_element(variable("p").into(), "x"),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ),
+            (
+                "(f p).x",
+                record_element(
+                    Application::new(variable("f"), variable("p"), SourceInformation::dummy())
+                        .into(),
                     "x",
-                    Let::new(
-                        vec![ValueDefinition::new(
-                            "y",
-                            Number::new(42.0, SourceInformation::dummy()),
-                            types::Variable::new(SourceInformation::dummy()),
-                            SourceInformation::dummy()
-                        )
-                        .into()],
-                        Variable::new("y", SourceInformation::dummy())
-                    ),
-                    types::Number::new(SourceInformation::dummy()),
+                ),
+            ),
+        ] {
+            assert_eq!(
+                expression(Input::new(source, "")).map(|(_, expression)| expression),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_constructor() {
+        let input = Input::new("Circle 1", "");
+
+        assert_eq!(
+            expression(input.clone()),
+            Ok((
+                input.set("", 0, Location::new(1, 9)),
+                Application::new(
+                    Constructor::new("Circle", SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
                     SourceInformation::dummy()
                 )
                 .into()
@@ -5,12 +5,18 @@ use std::fmt::Display;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParseError {
+    message: String,
     source_information: Box<SourceInformation>,
 }
 
 impl ParseError {
     pub fn new(input: &Input) -> Self {
+        Self::with_message(input, "Failed to parse")
+    }
+
+    pub fn with_message(input: &Input, message: impl Into<String>) -> Self {
         ParseError {
+            message: message.into(),
             source_information: SourceInformation::new(
                 input.filename(),
                 input.location(),
@@ -25,8 +31,8 @@ impl Display for ParseError {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         write!(
             formatter,
-            "ParseError: Failed to parse\n{}",
-            self.source_information,
+            "ParseError: {}\n{}",
+            self.message, self.source_information,
         )
     }
 }
@@ -0,0 +1,24 @@
+use super::associativity::Associativity;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fixity {
+    associativity: Associativity,
+    priority: u8,
+}
+
+impl Fixity {
+    pub const fn new(associativity: Associativity, priority: u8) -> Self {
+        Self {
+            associativity,
+            priority,
+        }
+    }
+
+    pub fn associativity(&self) -> Associativity {
+        self.associativity
+    }
+
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
+}
@@ -1,13 +1,20 @@
+use super::fixity::Fixity;
+use super::state::State;
+use super::utilities::{default_fixities, DEFAULT_FIXITY};
 use crate::debug::Location;
+use std::collections::HashMap;
 use std::rc::Rc;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Input<'a> {
     source: &'a str,
     braces: usize,
+    indentation: usize,
     location: Location,
     filename: &'a str,
     lines: Rc<[&'a str]>,
+    fixities: Rc<HashMap<String, Fixity>>,
+    state: Rc<State>,
 }
 
 impl<'a> Input<'a> {
@@ -15,9 +22,12 @@ impl<'a> Input<'a> {
         Self {
             source,
             braces: 0,
+            indentation: 1,
             location: Location::default(),
             filename,
             lines: source.split('\n').collect::<Vec<_>>().into(),
+            fixities: default_fixities().into(),
+            state: State::new().into(),
         }
     }
 
@@ -25,9 +35,12 @@ impl<'a> Input<'a> {
         Self {
             source,
             braces,
+            indentation: self.indentation,
             location,
             filename: self.filename,
             lines: self.lines.clone(),
+            fixities: self.fixities.clone(),
+            state: self.state.clone(),
         }
     }
 
@@ -39,6 +52,10 @@ impl<'a> Input<'a> {
         self.braces
     }
 
+    pub fn indentation(&self) -> usize {
+        self.indentation
+    }
+
     pub fn location(&self) -> Location {
         self.location
     }
@@ -54,4 +71,49 @@ impl<'a> Input<'a> {
     pub fn set_braces(&self, braces: usize) -> Self {
         self.set(self.source, braces, self.location)
     }
+
+    pub fn set_indentation(&self, indentation: usize) -> Self {
+        Self {
+            indentation,
+            ..self.clone()
+        }
+    }
+
+    pub fn record_failure(&self, location: Location) {
+        self.state.record_failure(location);
+    }
+
+    pub fn furthest_failure(&self) -> Self {
+        self.record_failure(self.location);
+
+        self.set(self.source, self.braces, self.state.furthest_failure())
+    }
+
+    pub fn fixity(&self, operator: &str) -> Fixity {
+        self.state.record_operator_use(operator);
+
+        self.fixities
+            .get(operator)
+            .copied()
+            .unwrap_or(DEFAULT_FIXITY)
+    }
+
+    pub fn is_fixity_declared(&self, operator: &str) -> bool {
+        self.fixities.contains_key(operator)
+    }
+
+    pub fn is_operator_used(&self, operator: &str) -> bool {
+        self.state.is_operator_used(operator)
+    }
+
+    pub fn set_fixity(&self, operator: impl Into<String>, fixity: Fixity) -> Self {
+        let mut fixities = self.fixities.as_ref().clone();
+
+        fixities.insert(operator.into(), fixity);
+
+        Self {
+            fixities: fixities.into(),
+            ..self.clone()
+        }
+    }
 }
@@ -0,0 +1,7 @@
+use crate::ast::{Definition, Destructuring};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LetBinding {
+    Definition(Definition),
+    Destructuring(Destructuring),
+}
@@ -1,6 +1,11 @@
+mod associativity;
 mod combinators;
 mod error;
+mod fixity;
 mod input;
+mod let_binding;
+mod parser_error;
+mod state;
 mod utilities;
 
 use error::ParseError;
@@ -11,8 +16,8 @@ pub fn parse(source: &str, filename: &str) -> Result<crate::ast::Module, error::
     combinators::module(Input::new(source, filename))
         .map(|(_, module)| module)
         .map_err(|error| match error {
-            Err::Error((input, _)) => ParseError::new(&input),
-            Err::Failure((input, _)) => ParseError::new(&input),
+            Err::Error(error) => ParseError::new(&error.input().furthest_failure()),
+            Err::Failure(error) => ParseError::with_message(error.input(), error.message()),
             Err::Incomplete(_) => ParseError::new(&Input::new(source, filename)),
         })
 }
@@ -126,4 +131,224 @@ mod test {
             .into()]))
         );
     }
+
+    #[test]
+    fn parse_user_defined_operators() {
+        let create_application = |lhs: Expression, rhs: Expression| -> Expression {
+            Application::new(
+                Application::new(
+                    Variable::new("<>", SourceInformation::dummy()),
+                    lhs,
+                    SourceInformation::dummy(),
+                ),
+                rhs,
+                SourceInformation::dummy(),
+            )
+            .into()
+        };
+
+        assert_eq!(
+            parse(
+                indoc!(
+                    "
+                    infixr 5 <>
+
+                    x <> y = x
+
+                    z = 1 <> 2 <> 3
+                    "
+                ),
+                ""
+            ),
+            Ok(Module::new(vec![
+                FunctionDefinition::new(
+                    "<>",
+                    vec!["x".into(), "y".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Variable::new(SourceInformation::dummy()),
+                        types::Variable::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                ValueDefinition::new(
+                    "z",
+                    create_application(
+                        Number::new(1.0, SourceInformation::dummy()).into(),
+                        create_application(
+                            Number::new(2.0, SourceInformation::dummy()).into(),
+                            Number::new(3.0, SourceInformation::dummy()).into()
+                        )
+                    ),
+                    types::Variable::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ]))
+        );
+
+        assert_eq!(
+            parse(
+                "(<>) : Number -> Number -> Number
+(<>) x y = x",
+                ""
+            ),
+            Ok(Module::new(vec![FunctionDefinition::new(
+                "<>",
+                vec!["x".into(), "y".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                ),
+                SourceInformation::dummy()
+            )
+            .into()]))
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_operators_with_conflicting_fixities() {
+        assert!(parse(
+            "infixr 5 <>
+z = 1 <> 2 * 3",
+            ""
+        )
+        .unwrap_err()
+        .to_string()
+        .contains("Fixities of operators conflict"));
+        assert!(parse(
+            "infix 5 <>
+z = 1 <> 2 <> 3",
+            ""
+        )
+        .unwrap_err()
+        .to_string()
+        .contains("Fixities of operators conflict"));
+    }
+
+    #[test]
+    fn parse_layout() {
+        assert_eq!(
+            parse(
+                indoc!(
+                    "
+                    main : Number -> Number
+                    main x =
+                      let
+                        f y = case y of
+                          0 -> 1
+                          _ ->
+                            y
+                              + 1
+                        z = 2
+                      in
+                        if x > 0
+                          then f x
+                          else z
+                    "
+                ),
+                ""
+            ),
+            parse(
+                indoc!(
+                    "
+                    main : Number -> Number
+                    main x = (let f y = (case y of 0 -> 1
+                                                   _ -> (y + 1))
+                                  z = 2
+                              in (if x > 0 then f x else z))
+                    "
+                ),
+                ""
+            )
+        );
+    }
+
+    #[test]
+    fn parse_deeply_nested_parentheses() {
+        let nest = |source: &str| format!("{}{}{}", "(".repeat(24), source, ")".repeat(24));
+
+        assert!(parse(
+            &format!(
+                "f : {} -> Number\nf x = case x of\n  {} -> {}\n  _ -> {}",
+                nest("Number"),
+                nest("y"),
+                nest("y"),
+                nest("-x + f (1, 2) * (- 1)")
+            ),
+            ""
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn fail_to_parse_misindented_lines() {
+        assert!(parse(
+            indoc!(
+                "
+                main : Number -> Number
+                main x =
+                  let
+                    y = 40
+                   z = 2
+                  in
+                    y + z
+                "
+            ),
+            "main.sl"
+        )
+        .unwrap_err()
+        .to_string()
+        .contains("main.sl:5:4:"));
+        assert!(parse(
+            indoc!(
+                "
+                main : Number -> Number
+                main x =
+                42
+                "
+            ),
+            "main.sl"
+        )
+        .unwrap_err()
+        .to_string()
+        .contains("main.sl:3:1:"));
+    }
+
+    #[test]
+    fn fail_to_parse_clauses_with_different_numbers_of_arguments() {
+        let message = parse(
+            indoc!(
+                "
+                f : Number -> Number -> Number
+                f 0 y = y
+                f x = x
+                "
+            ),
+            "main.sl",
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(message.contains("Clauses of a function have different numbers of arguments"));
+        assert!(message.contains("main.sl:3:1:"));
+    }
+
+    #[test]
+    fn fail_to_parse_invalid_escape_sequences() {
+        let message = parse("x = \"foo\\qbar\"", "main.sl")
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("Invalid escape sequence"));
+        assert!(message.contains("main.sl:1:6:"));
+    }
 }
@@ -0,0 +1,66 @@
+use nom::error::{ErrorKind, ParseError};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParserError<I> {
+    BuiltInOperatorFixity(I),
+    ConflictingFixities(I),
+    DuplicateFixity(I),
+    FixityAfterUse(I),
+    InvalidEscapeSequence(I),
+    MismatchedClauseArguments(I),
+    Nom(I, ErrorKind),
+    NumberLiteralOutOfRange(I),
+}
+
+impl<I> ParserError<I> {
+    pub fn input(&self) -> &I {
+        match self {
+            Self::BuiltInOperatorFixity(input)
+            | Self::ConflictingFixities(input)
+            | Self::DuplicateFixity(input)
+            | Self::FixityAfterUse(input)
+            | Self::InvalidEscapeSequence(input)
+            | Self::MismatchedClauseArguments(input)
+            | Self::Nom(input, _)
+            | Self::NumberLiteralOutOfRange(input) => input,
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::BuiltInOperatorFixity(_) => "Fixity of built-in operator cannot be changed",
+            Self::ConflictingFixities(_) => "Fixities of operators conflict",
+            Self::DuplicateFixity(_) => "Fixity of operator is declared more than once",
+            Self::FixityAfterUse(_) => "Fixity of operator is declared after its use",
+            Self::InvalidEscapeSequence(_) => "Invalid escape sequence",
+            Self::MismatchedClauseArguments(_) => {
+                "Clauses of a function have different numbers of arguments"
+            }
+            Self::Nom(_, _) => "Failed to parse",
+            Self::NumberLiteralOutOfRange(_) => "Number literal is out of range",
+        }
+    }
+
+    pub fn with_input<J>(self, input: J) -> ParserError<J> {
+        match self {
+            Self::BuiltInOperatorFixity(_) => ParserError::BuiltInOperatorFixity(input),
+            Self::ConflictingFixities(_) => ParserError::ConflictingFixities(input),
+            Self::DuplicateFixity(_) => ParserError::DuplicateFixity(input),
+            Self::FixityAfterUse(_) => ParserError::FixityAfterUse(input),
+            Self::InvalidEscapeSequence(_) => ParserError::InvalidEscapeSequence(input),
+            Self::MismatchedClauseArguments(_) => ParserError::MismatchedClauseArguments(input),
+            Self::Nom(_, kind) => ParserError::Nom(input, kind),
+            Self::NumberLiteralOutOfRange(_) => ParserError::NumberLiteralOutOfRange(input),
+        }
+    }
+}
+
+impl<I> ParseError<I> for ParserError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self::Nom(input, kind)
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
@@ -0,0 +1,33 @@
+use crate::debug::Location;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct State {
+    furthest_failure: Cell<Location>,
+    used_operators: RefCell<HashSet<String>>,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn furthest_failure(&self) -> Location {
+        self.furthest_failure.get()
+    }
+
+    pub fn record_failure(&self, location: Location) {
+        if location > self.furthest_failure.get() {
+            self.furthest_failure.set(location);
+        }
+    }
+
+    pub fn is_operator_used(&self, operator: &str) -> bool {
+        self.used_operators.borrow().contains(operator)
+    }
+
+    pub fn record_operator_use(&self, operator: &str) {
+        self.used_operators.borrow_mut().insert(operator.into());
+    }
+}
@@ -1,35 +1,205 @@
+use super::associativity::Associativity;
+use super::fixity::Fixity;
 use crate::ast::*;
 use crate::debug::SourceInformation;
-use std::collections::VecDeque;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::rc::Rc;
+
+pub const DEFAULT_FIXITY: Fixity = Fixity::new(Associativity::Left, 9);
+const CONS_FIXITY: Fixity = Fixity::new(Associativity::Right, 3);
+
+const BUILT_IN_OPERATORS: &[(&str, Operator, Fixity)] = &[
+    ("||", Operator::Or, Fixity::new(Associativity::Left, 1)),
+    ("&&", Operator::And, Fixity::new(Associativity::Left, 2)),
+    ("==", Operator::Equal, Fixity::new(Associativity::Left, 3)),
+    (
+        "/=",
+        Operator::NotEqual,
+        Fixity::new(Associativity::Left, 3),
+    ),
+    ("<", Operator::LessThan, Fixity::new(Associativity::Left, 3)),
+    (
+        "<=",
+        Operator::LessThanOrEqual,
+        Fixity::new(Associativity::Left, 3),
+    ),
+    (
+        ">",
+        Operator::GreaterThan,
+        Fixity::new(Associativity::Left, 3),
+    ),
+    (
+        ">=",
+        Operator::GreaterThanOrEqual,
+        Fixity::new(Associativity::Left, 3),
+    ),
+    ("+", Operator::Add, Fixity::new(Associativity::Left, 4)),
+    ("-", Operator::Subtract, Fixity::new(Associativity::Left, 4)),
+    (
+        "++",
+        Operator::Concatenate,
+        Fixity::new(Associativity::Right, 4),
+    ),
+    ("*", Operator::Multiply, Fixity::new(Associativity::Left, 5)),
+    ("/", Operator::Divide, Fixity::new(Associativity::Left, 5)),
+    (
+        "%",
+        Operator::Remainder,
+        Fixity::new(Associativity::Left, 5),
+    ),
+    (
+        ".|.",
+        Operator::BitwiseOr,
+        Fixity::new(Associativity::Left, 6),
+    ),
+    (
+        ".&.",
+        Operator::BitwiseAnd,
+        Fixity::new(Associativity::Left, 7),
+    ),
+    ("**", Operator::Power, Fixity::new(Associativity::Right, 8)),
+];
+
+pub fn default_fixities() -> HashMap<String, Fixity> {
+    BUILT_IN_OPERATORS
+        .iter()
+        .map(|(symbol, _, fixity)| ((*symbol).into(), *fixity))
+        .chain(vec![(CONS_CONSTRUCTOR_NAME.into(), CONS_FIXITY)])
+        .collect()
+}
+
+pub fn is_built_in_operator(symbol: &str) -> bool {
+    symbol == CONS_CONSTRUCTOR_NAME || built_in_operator(symbol).is_some()
+}
+
+pub fn built_in_operator(symbol: &str) -> Option<Operator> {
+    BUILT_IN_OPERATORS
+        .iter()
+        .find(|(other, _, _)| *other == symbol)
+        .map(|(_, operator, _)| *operator)
+}
+
+pub type OperationPair = (
+    String,
+    Option<SourceInformation>,
+    Expression,
+    SourceInformation,
+);
 
 pub fn reduce_operations(
+    negation: Option<SourceInformation>,
     lhs: Expression,
-    mut pairs: VecDeque<(Operator, Expression, SourceInformation)>,
-) -> Operation {
-    let (operator, rhs, source_information) = pairs.pop_front().unwrap();
-
-    if pairs.is_empty() {
-        Operation::new(operator, lhs, rhs, source_information)
-    } else if operator_priority(operator) > operator_priority(pairs[0].0) {
-        reduce_operations(
-            Expression::Operation(Operation::new(operator, lhs, rhs, source_information)),
-            pairs,
-        )
-    } else {
-        Operation::new(
-            operator,
-            lhs,
-            reduce_operations(rhs, pairs),
+    pairs: Vec<OperationPair>,
+    fixity: &impl Fn(&str) -> Fixity,
+) -> Option<Expression> {
+    let mut pairs = pairs.into_iter().peekable();
+    let lhs = reduce_operand(negation, lhs, &mut pairs, 0, fixity)?;
+
+    reduce_operations_with_priority(lhs, &mut pairs, None, 0, fixity)
+}
+
+fn reduce_operand(
+    negation: Option<SourceInformation>,
+    operand: Expression,
+    pairs: &mut Peekable<impl Iterator<Item = OperationPair>>,
+    minimum_priority: u8,
+    fixity: &impl Fn(&str) -> Fixity,
+) -> Option<Expression> {
+    Some(match negation {
+        Some(source_information) => Negation::new(
+            reduce_operations_with_priority(
+                operand,
+                pairs,
+                None,
+                minimum_priority.max(fixity("-").priority() + 1),
+                fixity,
+            )?,
             source_information,
         )
+        .into(),
+        None => operand,
+    })
+}
+
+fn reduce_operations_with_priority(
+    mut lhs: Expression,
+    pairs: &mut Peekable<impl Iterator<Item = OperationPair>>,
+    mut previous_fixity: Option<Fixity>,
+    minimum_priority: u8,
+    fixity: &impl Fn(&str) -> Fixity,
+) -> Option<Expression> {
+    while let Some((operator, negation, rhs, source_information)) =
+        pairs.next_if(|(operator, _, _, _)| fixity(operator).priority() >= minimum_priority)
+    {
+        let operator_fixity = fixity(&operator);
+
+        if let Some(previous_fixity) = previous_fixity {
+            if previous_fixity.priority() == operator_fixity.priority()
+                && (previous_fixity.associativity() != operator_fixity.associativity()
+                    || operator_fixity.associativity() == Associativity::None)
+            {
+                return None;
+            }
+        }
+
+        let rhs_priority = match operator_fixity.associativity() {
+            Associativity::Right => operator_fixity.priority(),
+            Associativity::Left | Associativity::None => operator_fixity.priority() + 1,
+        };
+        let rhs = reduce_operand(negation, rhs, pairs, rhs_priority, fixity)?;
+        let rhs = reduce_operations_with_priority(
+            rhs,
+            pairs,
+            Some(operator_fixity),
+            rhs_priority,
+            fixity,
+        )?;
+
+        lhs = create_operation(&operator, lhs, rhs, source_information);
+        previous_fixity = Some(operator_fixity);
     }
+
+    Some(lhs)
 }
 
-fn operator_priority(operator: Operator) -> u8 {
-    match operator {
-        Operator::Add => 1,
-        Operator::Subtract => 1,
-        Operator::Multiply => 2,
-        Operator::Divide => 2,
+pub fn create_operation(
+    operator: &str,
+    lhs: Expression,
+    rhs: Expression,
+    source_information: SourceInformation,
+) -> Expression {
+    let source_information = Rc::new(source_information);
+
+    match built_in_operator(operator) {
+        Some(operator) => Operation::new(operator, lhs, rhs, source_information).into(),
+        None => Application::new(
+            Application::new(
+                if operator == CONS_CONSTRUCTOR_NAME {
+                    Expression::from(Constructor::new(operator, source_information.clone()))
+                } else {
+                    Variable::new(operator, source_information.clone()).into()
+                },
+                lhs,
+                source_information.clone(),
+            ),
+            rhs,
+            source_information,
+        )
+        .into(),
     }
 }
+
+pub fn create_list(elements: Vec<Expression>, source_information: SourceInformation) -> Expression {
+    elements.into_iter().rev().fold(
+        Constructor::new(NIL_CONSTRUCTOR_NAME, source_information.clone()).into(),
+        |list, element| {
+            create_operation(
+                CONS_CONSTRUCTOR_NAME,
+                element,
+                list,
+                source_information.clone(),
+            )
+        },
+    )
+}
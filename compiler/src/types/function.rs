@@ -49,7 +49,7 @@ impl Function {
 
     pub fn last_result(&self) -> &Type {
         match self.result.as_ref() {
-            Type::Function(function) => function.result(),
+            Type::Function(function) => function.last_result(),
             _ => &self.result,
         }
     }
@@ -61,4 +61,12 @@ impl Function {
             self.source_information.clone(),
         )
     }
+
+    pub fn convert_types(&self, convert: &mut impl FnMut(&Type) -> Type) -> Self {
+        Self::new(
+            self.argument.convert_types(convert),
+            self.result.convert_types(convert),
+            self.source_information.clone(),
+        )
+    }
 }
@@ -0,0 +1,44 @@
+use super::Type;
+use crate::debug::SourceInformation;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct List {
+    element: Rc<Type>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl List {
+    pub fn new(
+        element: impl Into<Type>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            element: Rc::new(element.into()),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn element(&self) -> &Type {
+        &self.element
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.element.substitute_variables(substitutions),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_types(&self, convert: &mut impl FnMut(&Type) -> Type) -> Self {
+        Self::new(
+            self.element.convert_types(convert),
+            self.source_information.clone(),
+        )
+    }
+}
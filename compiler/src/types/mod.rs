@@ -1,26 +1,58 @@
+mod boolean;
+mod char_;
 mod function;
+mod int;
+mod list;
 mod number;
+mod record;
+mod reference;
+mod string;
+mod tuple;
 mod variable;
 
 use crate::debug::SourceInformation;
+pub use boolean::*;
+pub use char_::*;
 pub use function::*;
+pub use int::*;
+pub use list::*;
 pub use number::*;
+pub use record::*;
+pub use reference::*;
 use std::collections::HashMap;
 use std::rc::Rc;
+pub use string::*;
+pub use tuple::*;
 pub use variable::*;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Type {
+    Boolean(Boolean),
+    Char(Char),
     Function(Function),
+    Int(Int),
+    List(List),
     Number(Number),
+    Record(Record),
+    Reference(Reference),
+    String(String),
+    Tuple(Tuple),
     Variable(Variable),
 }
 
 impl Type {
     pub fn source_information(&self) -> &Rc<SourceInformation> {
         match self {
+            Type::Boolean(boolean) => boolean.source_information(),
+            Type::Char(char_) => char_.source_information(),
             Type::Function(function) => function.source_information(),
+            Type::Int(int) => int.source_information(),
+            Type::List(list) => list.source_information(),
             Type::Number(number) => number.source_information(),
+            Type::Record(record) => record.source_information(),
+            Type::Reference(reference) => reference.source_information(),
+            Type::String(string) => string.source_information(),
+            Type::Tuple(tuple) => tuple.source_information(),
             Type::Variable(variable) => variable.source_information(),
         }
     }
@@ -32,7 +64,15 @@ impl Type {
     pub fn substitute_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
         match self {
             Type::Function(function) => function.substitute_variables(substitutions).into(),
-            Type::Number(_) => self.clone(),
+            Type::List(list) => list.substitute_variables(substitutions).into(),
+            Type::Record(record) => record.substitute_variables(substitutions).into(),
+            Type::Tuple(tuple) => tuple.substitute_variables(substitutions).into(),
+            Type::Boolean(_)
+            | Type::Char(_)
+            | Type::Int(_)
+            | Type::Number(_)
+            | Type::Reference(_)
+            | Type::String(_) => self.clone(),
             Type::Variable(variable) => match substitutions.get(&variable.id()) {
                 Some(type_) => type_.clone(),
                 None => self.clone(),
@@ -40,6 +80,53 @@ impl Type {
         }
     }
 
+    pub fn convert_types(&self, convert: &mut impl FnMut(&Type) -> Type) -> Self {
+        let type_ = match self {
+            Type::Function(function) => function.convert_types(convert).into(),
+            Type::List(list) => list.convert_types(convert).into(),
+            Type::Record(record) => record.convert_types(convert).into(),
+            Type::Tuple(tuple) => tuple.convert_types(convert).into(),
+            Type::Boolean(_)
+            | Type::Char(_)
+            | Type::Int(_)
+            | Type::Number(_)
+            | Type::Reference(_)
+            | Type::String(_)
+            | Type::Variable(_) => self.clone(),
+        };
+
+        convert(&type_)
+    }
+
+    pub fn variables(&self) -> Vec<&Variable> {
+        match self {
+            Type::Function(function) => function
+                .argument()
+                .variables()
+                .into_iter()
+                .chain(function.result().variables())
+                .collect(),
+            Type::List(list) => list.element().variables(),
+            Type::Record(record) => record
+                .elements()
+                .values()
+                .flat_map(|type_| type_.variables())
+                .collect(),
+            Type::Tuple(tuple) => tuple
+                .elements()
+                .iter()
+                .flat_map(|type_| type_.variables())
+                .collect(),
+            Type::Boolean(_)
+            | Type::Char(_)
+            | Type::Int(_)
+            | Type::Number(_)
+            | Type::Reference(_)
+            | Type::String(_) => vec![],
+            Type::Variable(variable) => vec![variable],
+        }
+    }
+
     pub fn to_function(&self) -> Option<&Function> {
         if let Type::Function(function) = self {
             Some(&function)
@@ -47,6 +134,26 @@ impl Type {
             None
         }
     }
+
+    pub fn to_record(&self) -> Option<&Record> {
+        if let Type::Record(record) = self {
+            Some(record)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Boolean> for Type {
+    fn from(boolean: Boolean) -> Self {
+        Type::Boolean(boolean)
+    }
+}
+
+impl From<Char> for Type {
+    fn from(char_: Char) -> Self {
+        Type::Char(char_)
+    }
 }
 
 impl From<Function> for Type {
@@ -55,12 +162,48 @@ impl From<Function> for Type {
     }
 }
 
+impl From<Int> for Type {
+    fn from(int: Int) -> Self {
+        Type::Int(int)
+    }
+}
+
+impl From<List> for Type {
+    fn from(list: List) -> Self {
+        Type::List(list)
+    }
+}
+
 impl From<Number> for Type {
     fn from(number: Number) -> Self {
         Type::Number(number)
     }
 }
 
+impl From<Record> for Type {
+    fn from(record: Record) -> Self {
+        Type::Record(record)
+    }
+}
+
+impl From<Reference> for Type {
+    fn from(reference: Reference) -> Self {
+        Type::Reference(reference)
+    }
+}
+
+impl From<String> for Type {
+    fn from(string: String) -> Self {
+        Type::String(string)
+    }
+}
+
+impl From<Tuple> for Type {
+    fn from(tuple: Tuple) -> Self {
+        Type::Tuple(tuple)
+    }
+}
+
 impl From<Variable> for Type {
     fn from(variable: Variable) -> Self {
         Type::Variable(variable)
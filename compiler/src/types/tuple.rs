@@ -0,0 +1,47 @@
+use super::Type;
+use crate::debug::SourceInformation;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuple {
+    elements: Vec<Type>,
+    source_information: Rc<SourceInformation>,
+}
+
+impl Tuple {
+    pub fn new(elements: Vec<Type>, source_information: impl Into<Rc<SourceInformation>>) -> Self {
+        Self {
+            elements,
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn elements(&self) -> &[Type] {
+        &self.elements
+    }
+
+    pub fn source_information(&self) -> &Rc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn substitute_variables(&self, substitutions: &HashMap<usize, Type>) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|type_| type_.substitute_variables(substitutions))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+
+    pub fn convert_types(&self, convert: &mut impl FnMut(&Type) -> Type) -> Self {
+        Self::new(
+            self.elements
+                .iter()
+                .map(|type_| type_.convert_types(convert))
+                .collect(),
+            self.source_information.clone(),
+        )
+    }
+}
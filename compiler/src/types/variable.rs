@@ -7,6 +7,7 @@ static GLOBAL_ID: AtomicUsize = AtomicUsize::new(0);
 #[derive(Clone, Debug)]
 pub struct Variable {
     id: usize,
+    name: Option<String>,
     source_information: Rc<SourceInformation>,
 }
 
@@ -14,14 +15,29 @@ impl Variable {
     pub fn new(source_information: impl Into<Rc<SourceInformation>>) -> Self {
         Self {
             id: GLOBAL_ID.fetch_add(1, Ordering::SeqCst),
+            name: None,
             source_information: source_information.into(),
         }
     }
 
+    pub fn with_name(
+        name: impl Into<String>,
+        source_information: impl Into<Rc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new(source_information)
+        }
+    }
+
     pub fn id(&self) -> usize {
         self.id
     }
 
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     pub fn source_information(&self) -> &Rc<SourceInformation> {
         &self.source_information
     }
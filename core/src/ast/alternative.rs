@@ -0,0 +1,35 @@
+use super::constructor::Constructor;
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alternative {
+    constructor: Constructor,
+    element_names: Vec<String>,
+    expression: Expression,
+}
+
+impl Alternative {
+    pub fn new(
+        constructor: Constructor,
+        element_names: Vec<String>,
+        expression: impl Into<Expression>,
+    ) -> Self {
+        Self {
+            constructor,
+            element_names,
+            expression: expression.into(),
+        }
+    }
+
+    pub fn constructor(&self) -> &Constructor {
+        &self.constructor
+    }
+
+    pub fn element_names(&self) -> &[String] {
+        &self.element_names
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
@@ -1,21 +1,21 @@
 use super::expression::Expression;
-use super::variable::Variable;
+use std::rc::Rc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Application {
-    function: Variable,
+    function: Rc<Expression>,
     arguments: Vec<Expression>,
 }
 
 impl Application {
-    pub fn new(function: Variable, arguments: Vec<Expression>) -> Self {
+    pub fn new(function: impl Into<Expression>, arguments: Vec<Expression>) -> Self {
         Self {
-            function,
+            function: Rc::new(function.into()),
             arguments,
         }
     }
 
-    pub fn function(&self) -> &Variable {
+    pub fn function(&self) -> &Expression {
         &self.function
     }
 
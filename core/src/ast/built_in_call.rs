@@ -0,0 +1,25 @@
+use super::built_in_function::BuiltInFunction;
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuiltInCall {
+    function: BuiltInFunction,
+    arguments: Vec<Expression>,
+}
+
+impl BuiltInCall {
+    pub fn new(function: BuiltInFunction, arguments: Vec<Expression>) -> Self {
+        Self {
+            function,
+            arguments,
+        }
+    }
+
+    pub fn function(&self) -> BuiltInFunction {
+        self.function
+    }
+
+    pub fn arguments(&self) -> &[Expression] {
+        &self.arguments
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BuiltInFunction {
+    Absolute,
+    Ceiling,
+    Cosine,
+    Exponential,
+    Floor,
+    Logarithm,
+    PopCount,
+    Sine,
+    SquareRoot,
+    StringLength,
+    StringSlice,
+}
@@ -0,0 +1,35 @@
+use super::alternative::Alternative;
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+    argument: Box<Expression>,
+    alternatives: Vec<Alternative>,
+    default_alternative: Option<Box<Expression>>,
+}
+
+impl Case {
+    pub fn new(
+        argument: impl Into<Expression>,
+        alternatives: Vec<Alternative>,
+        default_alternative: Option<Expression>,
+    ) -> Self {
+        Self {
+            argument: Box::new(argument.into()),
+            alternatives,
+            default_alternative: default_alternative.map(Box::new),
+        }
+    }
+
+    pub fn argument(&self) -> &Expression {
+        &self.argument
+    }
+
+    pub fn alternatives(&self) -> &[Alternative] {
+        &self.alternatives
+    }
+
+    pub fn default_alternative(&self) -> Option<&Expression> {
+        self.default_alternative.as_deref()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::types;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constructor {
+    algebraic_type: types::Algebraic,
+    index: usize,
+}
+
+impl Constructor {
+    pub fn new(algebraic_type: types::Algebraic, index: usize) -> Self {
+        Self {
+            algebraic_type,
+            index,
+        }
+    }
+
+    pub fn algebraic_type(&self) -> &types::Algebraic {
+        &self.algebraic_type
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
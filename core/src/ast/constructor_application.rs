@@ -0,0 +1,25 @@
+use super::constructor::Constructor;
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorApplication {
+    constructor: Constructor,
+    arguments: Vec<Expression>,
+}
+
+impl ConstructorApplication {
+    pub fn new(constructor: Constructor, arguments: Vec<Expression>) -> Self {
+        Self {
+            constructor,
+            arguments,
+        }
+    }
+
+    pub fn constructor(&self) -> &Constructor {
+        &self.constructor
+    }
+
+    pub fn arguments(&self) -> &[Expression] {
+        &self.arguments
+    }
+}
@@ -0,0 +1,25 @@
+use super::expression::Expression;
+use crate::types;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conversion {
+    type_: types::Value,
+    expression: Box<Expression>,
+}
+
+impl Conversion {
+    pub fn new(type_: types::Value, expression: impl Into<Expression>) -> Self {
+        Self {
+            type_,
+            expression: Box::new(expression.into()),
+        }
+    }
+
+    pub fn type_(&self) -> &types::Value {
+        &self.type_
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
@@ -0,0 +1,18 @@
+use crate::types;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmptyListFailure {
+    type_: types::Type,
+}
+
+impl EmptyListFailure {
+    pub fn new(type_: impl Into<types::Type>) -> Self {
+        Self {
+            type_: type_.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &types::Type {
+        &self.type_
+    }
+}
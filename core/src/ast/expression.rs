@@ -1,16 +1,44 @@
 use super::application::Application;
+use super::built_in_call::BuiltInCall;
+use super::case::Case;
+use super::constructor_application::ConstructorApplication;
+use super::conversion::Conversion;
+use super::empty_list_failure::EmptyListFailure;
+use super::if_::If;
 use super::let_functions::LetFunctions;
 use super::let_values::LetValues;
+use super::match_failure::MatchFailure;
+use super::negation::Negation;
 use super::operation::Operation;
+use super::record::Record;
+use super::record_element::RecordElement;
+use super::tuple::Tuple;
+use super::tuple_element::TupleElement;
 use super::variable::Variable;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
     Application(Application),
+    Boolean(bool),
+    BuiltInCall(BuiltInCall),
+    Case(Case),
+    Char(char),
+    ConstructorApplication(ConstructorApplication),
+    Conversion(Conversion),
+    EmptyListFailure(EmptyListFailure),
+    If(If),
+    Int(i64),
     LetFunctions(LetFunctions),
     LetValues(LetValues),
+    MatchFailure(MatchFailure),
+    Negation(Negation),
     Number(f64),
     Operation(Operation),
+    Record(Record),
+    RecordElement(RecordElement),
+    String(String),
+    Tuple(Tuple),
+    TupleElement(TupleElement),
     Variable(Variable),
 }
 
@@ -23,18 +51,78 @@ impl Expression {
     }
 }
 
+impl From<bool> for Expression {
+    fn from(boolean: bool) -> Expression {
+        Expression::Boolean(boolean)
+    }
+}
+
+impl From<char> for Expression {
+    fn from(character: char) -> Expression {
+        Expression::Char(character)
+    }
+}
+
+impl From<i64> for Expression {
+    fn from(number: i64) -> Expression {
+        Expression::Int(number)
+    }
+}
+
 impl From<f64> for Expression {
     fn from(number: f64) -> Expression {
         Expression::Number(number)
     }
 }
 
+impl From<&str> for Expression {
+    fn from(string: &str) -> Expression {
+        Expression::String(string.into())
+    }
+}
+
 impl From<Application> for Expression {
     fn from(application: Application) -> Expression {
         Expression::Application(application)
     }
 }
 
+impl From<Case> for Expression {
+    fn from(case: Case) -> Expression {
+        Expression::Case(case)
+    }
+}
+
+impl From<ConstructorApplication> for Expression {
+    fn from(constructor_application: ConstructorApplication) -> Expression {
+        Expression::ConstructorApplication(constructor_application)
+    }
+}
+
+impl From<BuiltInCall> for Expression {
+    fn from(built_in_call: BuiltInCall) -> Expression {
+        Expression::BuiltInCall(built_in_call)
+    }
+}
+
+impl From<Conversion> for Expression {
+    fn from(conversion: Conversion) -> Expression {
+        Expression::Conversion(conversion)
+    }
+}
+
+impl From<EmptyListFailure> for Expression {
+    fn from(empty_list_failure: EmptyListFailure) -> Expression {
+        Expression::EmptyListFailure(empty_list_failure)
+    }
+}
+
+impl From<If> for Expression {
+    fn from(if_: If) -> Expression {
+        Expression::If(if_)
+    }
+}
+
 impl From<LetFunctions> for Expression {
     fn from(let_functions: LetFunctions) -> Expression {
         Expression::LetFunctions(let_functions)
@@ -47,12 +135,48 @@ impl From<LetValues> for Expression {
     }
 }
 
+impl From<MatchFailure> for Expression {
+    fn from(match_failure: MatchFailure) -> Expression {
+        Expression::MatchFailure(match_failure)
+    }
+}
+
+impl From<Negation> for Expression {
+    fn from(negation: Negation) -> Expression {
+        Expression::Negation(negation)
+    }
+}
+
 impl From<Operation> for Expression {
     fn from(operation: Operation) -> Expression {
         Expression::Operation(operation)
     }
 }
 
+impl From<Record> for Expression {
+    fn from(record: Record) -> Expression {
+        Expression::Record(record)
+    }
+}
+
+impl From<RecordElement> for Expression {
+    fn from(record_element: RecordElement) -> Expression {
+        Expression::RecordElement(record_element)
+    }
+}
+
+impl From<Tuple> for Expression {
+    fn from(tuple: Tuple) -> Expression {
+        Expression::Tuple(tuple)
+    }
+}
+
+impl From<TupleElement> for Expression {
+    fn from(tuple_element: TupleElement) -> Expression {
+        Expression::TupleElement(tuple_element)
+    }
+}
+
 impl From<Variable> for Expression {
     fn from(variable: Variable) -> Expression {
         Expression::Variable(variable)
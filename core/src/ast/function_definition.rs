@@ -8,7 +8,7 @@ pub struct FunctionDefinition {
     environment: Vec<Argument>,
     arguments: Vec<Argument>,
     body: Expression,
-    result_type: types::Value,
+    result_type: types::Type,
     type_: types::Function,
 }
 
@@ -18,8 +18,9 @@ impl FunctionDefinition {
         environment: Vec<Argument>,
         arguments: Vec<Argument>,
         body: impl Into<Expression>,
-        result_type: types::Value,
+        result_type: impl Into<types::Type>,
     ) -> Self {
+        let result_type = result_type.into();
         let type_ = types::Function::new(
             arguments
                 .iter()
@@ -54,7 +55,7 @@ impl FunctionDefinition {
         &self.body
     }
 
-    pub fn result_type(&self) -> &types::Value {
+    pub fn result_type(&self) -> &types::Type {
         &self.result_type
     }
 
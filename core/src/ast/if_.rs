@@ -0,0 +1,34 @@
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct If {
+    condition: Box<Expression>,
+    then: Box<Expression>,
+    else_: Box<Expression>,
+}
+
+impl If {
+    pub fn new(
+        condition: impl Into<Expression>,
+        then: impl Into<Expression>,
+        else_: impl Into<Expression>,
+    ) -> Self {
+        Self {
+            condition: Box::new(condition.into()),
+            then: Box::new(then.into()),
+            else_: Box::new(else_.into()),
+        }
+    }
+
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    pub fn then(&self) -> &Expression {
+        &self.then
+    }
+
+    pub fn else_(&self) -> &Expression {
+        &self.else_
+    }
+}
@@ -0,0 +1,18 @@
+use crate::types;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchFailure {
+    type_: types::Type,
+}
+
+impl MatchFailure {
+    pub fn new(type_: impl Into<types::Type>) -> Self {
+        Self {
+            type_: type_.into(),
+        }
+    }
+
+    pub fn type_(&self) -> &types::Type {
+        &self.type_
+    }
+}
@@ -1,25 +1,55 @@
+mod alternative;
 mod application;
 mod argument;
+mod built_in_call;
+mod built_in_function;
+mod case;
+mod constructor;
+mod constructor_application;
+mod conversion;
 mod definition;
+mod empty_list_failure;
 mod expression;
 mod function_definition;
+mod if_;
 mod let_functions;
 mod let_values;
+mod match_failure;
 mod module;
+mod negation;
 mod operation;
 mod operator;
+mod record;
+mod record_element;
+mod tuple;
+mod tuple_element;
 mod value_definition;
 mod variable;
 
+pub use alternative::*;
 pub use application::*;
 pub use argument::*;
+pub use built_in_call::*;
+pub use built_in_function::*;
+pub use case::*;
+pub use constructor::*;
+pub use constructor_application::*;
+pub use conversion::*;
 pub use definition::*;
+pub use empty_list_failure::*;
 pub use expression::*;
 pub use function_definition::*;
+pub use if_::*;
 pub use let_functions::*;
 pub use let_values::*;
+pub use match_failure::*;
 pub use module::*;
+pub use negation::*;
 pub use operation::*;
 pub use operator::*;
+pub use record::*;
+pub use record_element::*;
+pub use tuple::*;
+pub use tuple_element::*;
 pub use value_definition::*;
 pub use variable::*;
@@ -0,0 +1,18 @@
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Negation {
+    expression: Box<Expression>,
+}
+
+impl Negation {
+    pub fn new(expression: impl Into<Expression>) -> Self {
+        Self {
+            expression: Box::new(expression.into()),
+        }
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
@@ -4,4 +4,20 @@ pub enum Operator {
     Subtract,
     Multiply,
     Divide,
+    Remainder,
+    Power,
+    FloorDivide,
+    Modulo,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
+    Concatenate,
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
 }
@@ -0,0 +1,24 @@
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordElement {
+    record: Box<Expression>,
+    index: usize,
+}
+
+impl RecordElement {
+    pub fn new(record: impl Into<Expression>, index: usize) -> Self {
+        Self {
+            record: Box::new(record.into()),
+            index,
+        }
+    }
+
+    pub fn record(&self) -> &Expression {
+        &self.record
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
@@ -0,0 +1,16 @@
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuple {
+    elements: Vec<Expression>,
+}
+
+impl Tuple {
+    pub fn new(elements: Vec<Expression>) -> Self {
+        Self { elements }
+    }
+
+    pub fn elements(&self) -> &[Expression] {
+        &self.elements
+    }
+}
@@ -0,0 +1,24 @@
+use super::expression::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TupleElement {
+    tuple: Box<Expression>,
+    index: usize,
+}
+
+impl TupleElement {
+    pub fn new(tuple: impl Into<Expression>, index: usize) -> Self {
+        Self {
+            tuple: Box::new(tuple.into()),
+            index,
+        }
+    }
+
+    pub fn tuple(&self) -> &Expression {
+        &self.tuple
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
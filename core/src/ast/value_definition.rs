@@ -5,15 +5,19 @@ use crate::types;
 pub struct ValueDefinition {
     name: String,
     body: Expression,
-    type_: types::Value,
+    type_: types::Type,
 }
 
 impl ValueDefinition {
-    pub fn new(name: impl Into<String>, body: impl Into<Expression>, type_: types::Value) -> Self {
+    pub fn new(
+        name: impl Into<String>,
+        body: impl Into<Expression>,
+        type_: impl Into<types::Type>,
+    ) -> Self {
         Self {
             name: name.into(),
             body: body.into(),
-            type_,
+            type_: type_.into(),
         }
     }
 
@@ -25,7 +29,7 @@ impl ValueDefinition {
         &self.body
     }
 
-    pub fn type_(&self) -> &types::Value {
+    pub fn type_(&self) -> &types::Type {
         &self.type_
     }
 }
@@ -6,6 +6,7 @@ use crate::ast;
 use std::collections::HashMap;
 
 pub struct ExpressionCompiler<'a> {
+    module: llvm::Module,
     builder: &'a llvm::Builder,
     function_compiler: &'a FunctionCompiler<'a>,
     type_compiler: &'a TypeCompiler,
@@ -13,11 +14,13 @@ pub struct ExpressionCompiler<'a> {
 
 impl<'a> ExpressionCompiler<'a> {
     pub fn new(
+        module: llvm::Module,
         builder: &'a llvm::Builder,
         function_compiler: &'a FunctionCompiler,
         type_compiler: &'a TypeCompiler,
     ) -> Self {
         Self {
+            module,
             builder,
             function_compiler,
             type_compiler,
@@ -31,31 +34,144 @@ impl<'a> ExpressionCompiler<'a> {
     ) -> Result<llvm::Value, CompileError> {
         match expression {
             ast::Expression::Application(application) => {
-                let closure = self.compile_variable(application.function(), variables)?;
+                let closure = self.compile(application.function(), variables)?;
+                let arguments = application
+                    .arguments()
+                    .iter()
+                    .map(|argument| self.compile(argument, variables))
+                    .collect::<Result<Vec<_>, _>>()?;
 
-                let mut arguments = vec![self.builder.build_gep(
-                    closure,
-                    &[
-                        llvm::const_int(llvm::Type::i32(), 0),
-                        llvm::const_int(llvm::Type::i32(), 1),
-                    ],
-                )];
+                Ok(self.compile_application(closure, &arguments))
+            }
+            ast::Expression::Boolean(boolean) => {
+                Ok(llvm::const_int(llvm::Type::i1(), *boolean as u64))
+            }
+            ast::Expression::Case(case) => self.compile_case(case, variables),
+            ast::Expression::ConstructorApplication(constructor_application) => {
+                let constructor = constructor_application.constructor();
+                let constructor_type = self.type_compiler.compile_constructor(
+                    &constructor.algebraic_type().constructors()[constructor.index()],
+                );
 
-                for argument in application.arguments() {
-                    arguments.push(self.compile(argument, variables)?);
-                }
+                let pointer = self.builder.build_bit_cast(
+                    self.builder.build_malloc(constructor_type.size()),
+                    llvm::Type::pointer(constructor_type),
+                );
 
-                Ok(self.builder.build_call(
-                    self.builder.build_load(self.builder.build_gep(
-                        closure,
+                self.builder.build_store(
+                    llvm::const_int(self.type_compiler.compile_tag(), constructor.index() as u64),
+                    self.builder.build_gep(
+                        pointer,
                         &[
                             llvm::const_int(llvm::Type::i32(), 0),
                             llvm::const_int(llvm::Type::i32(), 0),
                         ],
-                    )),
+                    ),
+                );
+
+                for (index, argument) in constructor_application.arguments().iter().enumerate() {
+                    self.builder.build_store(
+                        self.compile(argument, variables)?,
+                        self.builder.build_gep(
+                            pointer,
+                            &[
+                                llvm::const_int(llvm::Type::i32(), 0),
+                                llvm::const_int(llvm::Type::i32(), index as u64 + 1),
+                            ],
+                        ),
+                    );
+                }
+
+                Ok(self
+                    .builder
+                    .build_bit_cast(pointer, self.type_compiler.compile_algebraic()))
+            }
+            ast::Expression::BuiltInCall(built_in_call) => {
+                let arguments = built_in_call
+                    .arguments()
+                    .iter()
+                    .map(|argument| self.compile(argument, variables))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(self.builder.build_call_with_name(
+                    match built_in_call.function() {
+                        ast::BuiltInFunction::Absolute => "llvm.fabs.f64",
+                        ast::BuiltInFunction::Ceiling => "llvm.ceil.f64",
+                        ast::BuiltInFunction::Cosine => "llvm.cos.f64",
+                        ast::BuiltInFunction::Exponential => "llvm.exp.f64",
+                        ast::BuiltInFunction::Floor => "llvm.floor.f64",
+                        ast::BuiltInFunction::Logarithm => "llvm.log.f64",
+                        ast::BuiltInFunction::PopCount => "llvm.ctpop.i64",
+                        ast::BuiltInFunction::Sine => "llvm.sin.f64",
+                        ast::BuiltInFunction::SquareRoot => "llvm.sqrt.f64",
+                        ast::BuiltInFunction::StringLength => "sloth_string_length",
+                        ast::BuiltInFunction::StringSlice => "sloth_string_slice",
+                    },
                     &arguments,
                 ))
             }
+            ast::Expression::Conversion(conversion) => {
+                let value = self.compile(conversion.expression(), variables)?;
+                let type_ = self.type_compiler.compile_value(conversion.type_());
+
+                Ok(match (value.type_().kind(), type_.kind()) {
+                    (llvm::TypeKind::Double, llvm::TypeKind::Pointer) => self
+                        .builder
+                        .build_call_with_name("sloth_number_to_string", &[value]),
+                    (llvm::TypeKind::Integer, llvm::TypeKind::Pointer) => {
+                        self.builder.build_call_with_name(
+                            if value.type_().int_width()
+                                == self.type_compiler.compile_char().int_width()
+                            {
+                                "sloth_char_to_string"
+                            } else {
+                                "sloth_int_to_string"
+                            },
+                            &[value],
+                        )
+                    }
+                    (llvm::TypeKind::Double, llvm::TypeKind::Integer) => {
+                        self.compile_float_to_int(value, type_)
+                    }
+                    (llvm::TypeKind::Integer, llvm::TypeKind::Double) => {
+                        self.builder.build_si_to_fp(value, type_)
+                    }
+                    (llvm::TypeKind::Integer, llvm::TypeKind::Integer) => {
+                        self.builder.build_int_cast(value, type_)
+                    }
+                    _ => value,
+                })
+            }
+            ast::Expression::If(if_) => {
+                let then = self.builder.append_basic_block("then");
+                let else_ = self.builder.append_basic_block("else");
+                let phi = self.builder.append_basic_block("phi");
+
+                self.builder
+                    .build_cond_br(self.compile(if_.condition(), variables)?, then, else_);
+
+                self.builder.position_at_end(then);
+                let then_value = self.compile(if_.then(), variables)?;
+                let then = self.builder.get_insert_block();
+                self.builder.build_br(phi);
+
+                self.builder.position_at_end(else_);
+                let else_value = self.compile(if_.else_(), variables)?;
+                let else_ = self.builder.get_insert_block();
+                self.builder.build_br(phi);
+
+                self.builder.position_at_end(phi);
+                Ok(self.builder.build_phi(
+                    then_value.type_(),
+                    &[then_value, else_value],
+                    &[then, else_],
+                ))
+            }
+            ast::Expression::Char(character) => Ok(llvm::const_int(
+                self.type_compiler.compile_char(),
+                *character as u64,
+            )),
+            ast::Expression::Int(number) => Ok(llvm::const_int(llvm::Type::i64(), *number as u64)),
             ast::Expression::LetFunctions(let_functions) => {
                 let mut variables = variables.clone();
                 let mut closures = HashMap::<&str, llvm::Value>::new();
@@ -132,22 +248,505 @@ impl<'a> ExpressionCompiler<'a> {
 
                 self.compile(let_values.expression(), &variables)
             }
+            ast::Expression::EmptyListFailure(empty_list_failure) => {
+                self.builder.build_empty_list_failure();
+
+                Ok(llvm::get_undef(
+                    self.type_compiler.compile(empty_list_failure.type_()),
+                ))
+            }
+            ast::Expression::MatchFailure(match_failure) => {
+                self.builder.build_match_failure();
+
+                Ok(llvm::get_undef(
+                    self.type_compiler.compile(match_failure.type_()),
+                ))
+            }
+            ast::Expression::Negation(negation) => {
+                let value = self.compile(negation.expression(), variables)?;
+
+                Ok(match value.type_().kind() {
+                    llvm::TypeKind::Double => self.builder.build_fneg(value),
+                    _ => self.builder.build_neg(value),
+                })
+            }
             ast::Expression::Number(number) => Ok(llvm::const_real(llvm::Type::double(), *number)),
+            ast::Expression::String(string) => Ok(self.compile_string(string)),
             ast::Expression::Operation(operation) => {
                 let lhs = self.compile(operation.lhs(), variables)?;
                 let rhs = self.compile(operation.rhs(), variables)?;
+                let is_real = lhs.type_().kind() == llvm::TypeKind::Double;
 
                 Ok(match operation.operator() {
-                    ast::Operator::Add => self.builder.build_fadd(lhs, rhs),
-                    ast::Operator::Subtract => self.builder.build_fsub(lhs, rhs),
-                    ast::Operator::Multiply => self.builder.build_fmul(lhs, rhs),
-                    ast::Operator::Divide => self.builder.build_fdiv(lhs, rhs),
+                    ast::Operator::Add if is_real => self.builder.build_fadd(lhs, rhs),
+                    ast::Operator::Add => self.builder.build_add(lhs, rhs),
+                    ast::Operator::Subtract if is_real => self.builder.build_fsub(lhs, rhs),
+                    ast::Operator::Subtract => self.builder.build_sub(lhs, rhs),
+                    ast::Operator::Multiply if is_real => self.builder.build_fmul(lhs, rhs),
+                    ast::Operator::Multiply => self.builder.build_mul(lhs, rhs),
+                    ast::Operator::Divide if is_real => self.builder.build_fdiv(lhs, rhs),
+                    ast::Operator::Divide => {
+                        let divisor = self.compile_divisor(rhs);
+
+                        self.compile_quotient(lhs, rhs, divisor)
+                    }
+                    ast::Operator::Remainder if is_real => self.builder.build_frem(lhs, rhs),
+                    ast::Operator::Remainder => {
+                        self.builder.build_srem(lhs, self.compile_divisor(rhs))
+                    }
+                    ast::Operator::Power => self
+                        .builder
+                        .build_call_with_name("llvm.pow.f64", &[lhs, rhs]),
+                    ast::Operator::FloorDivide => {
+                        let divisor = self.compile_divisor(rhs);
+                        let quotient = self.compile_quotient(lhs, rhs, divisor);
+                        let remainder = self.builder.build_srem(lhs, divisor);
+
+                        self.builder.build_sub(
+                            quotient,
+                            self.builder.build_select(
+                                self.compile_floor_adjustment(remainder, lhs, rhs),
+                                llvm::const_int(lhs.type_(), 1),
+                                llvm::const_int(lhs.type_(), 0),
+                            ),
+                        )
+                    }
+                    ast::Operator::Modulo => {
+                        let remainder = self.builder.build_srem(lhs, self.compile_divisor(rhs));
+
+                        self.builder.build_add(
+                            remainder,
+                            self.builder.build_select(
+                                self.compile_floor_adjustment(remainder, lhs, rhs),
+                                rhs,
+                                llvm::const_int(lhs.type_(), 0),
+                            ),
+                        )
+                    }
+                    ast::Operator::BitwiseAnd => self.builder.build_and(lhs, rhs),
+                    ast::Operator::BitwiseOr => self.builder.build_or(lhs, rhs),
+                    ast::Operator::BitwiseXor => self.builder.build_xor(lhs, rhs),
+                    ast::Operator::ShiftLeft => {
+                        self.builder.build_shl(lhs, self.compile_shift_amount(rhs))
+                    }
+                    ast::Operator::ShiftRight => {
+                        self.builder.build_ashr(lhs, self.compile_shift_amount(rhs))
+                    }
+                    ast::Operator::Concatenate => self
+                        .builder
+                        .build_call_with_name("sloth_string_concatenate", &[lhs, rhs]),
+                    ast::Operator::Equal => self.compile_comparison(
+                        llvm::LLVMRealPredicate::LLVMRealOEQ,
+                        llvm::LLVMIntPredicate::LLVMIntEQ,
+                        lhs,
+                        rhs,
+                    ),
+                    ast::Operator::NotEqual => self.compile_comparison(
+                        llvm::LLVMRealPredicate::LLVMRealUNE,
+                        llvm::LLVMIntPredicate::LLVMIntNE,
+                        lhs,
+                        rhs,
+                    ),
+                    ast::Operator::LessThan => self.compile_comparison(
+                        llvm::LLVMRealPredicate::LLVMRealOLT,
+                        llvm::LLVMIntPredicate::LLVMIntSLT,
+                        lhs,
+                        rhs,
+                    ),
+                    ast::Operator::LessThanOrEqual => self.compile_comparison(
+                        llvm::LLVMRealPredicate::LLVMRealOLE,
+                        llvm::LLVMIntPredicate::LLVMIntSLE,
+                        lhs,
+                        rhs,
+                    ),
+                    ast::Operator::GreaterThan => self.compile_comparison(
+                        llvm::LLVMRealPredicate::LLVMRealOGT,
+                        llvm::LLVMIntPredicate::LLVMIntSGT,
+                        lhs,
+                        rhs,
+                    ),
+                    ast::Operator::GreaterThanOrEqual => self.compile_comparison(
+                        llvm::LLVMRealPredicate::LLVMRealOGE,
+                        llvm::LLVMIntPredicate::LLVMIntSGE,
+                        lhs,
+                        rhs,
+                    ),
                 })
             }
+            ast::Expression::Record(record) => {
+                let record_type = self.type_compiler.compile_unboxed_record(record.type_());
+                let pointer = self.builder.build_bit_cast(
+                    self.builder.build_malloc(record_type.size()),
+                    llvm::Type::pointer(record_type),
+                );
+
+                for (index, element) in record.elements().iter().enumerate() {
+                    self.builder.build_store(
+                        self.compile(element, variables)?,
+                        self.builder.build_gep(
+                            pointer,
+                            &[
+                                llvm::const_int(llvm::Type::i32(), 0),
+                                llvm::const_int(llvm::Type::i32(), index as u64),
+                            ],
+                        ),
+                    );
+                }
+
+                Ok(pointer)
+            }
+            ast::Expression::RecordElement(record_element) => {
+                Ok(self.builder.build_load(self.builder.build_gep(
+                    self.compile(record_element.record(), variables)?,
+                    &[
+                        llvm::const_int(llvm::Type::i32(), 0),
+                        llvm::const_int(llvm::Type::i32(), record_element.index() as u64),
+                    ],
+                )))
+            }
+            ast::Expression::Tuple(tuple) => {
+                let elements = tuple
+                    .elements()
+                    .iter()
+                    .map(|element| self.compile(element, variables))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(elements.iter().enumerate().fold(
+                    llvm::get_undef(llvm::Type::struct_(
+                        &elements
+                            .iter()
+                            .map(|element| element.type_())
+                            .collect::<Vec<_>>(),
+                    )),
+                    |tuple, (index, element)| {
+                        self.builder
+                            .build_insert_value(tuple, *element, index as u32)
+                    },
+                ))
+            }
+            ast::Expression::TupleElement(tuple_element) => Ok(self.builder.build_extract_value(
+                self.compile(tuple_element.tuple(), variables)?,
+                tuple_element.index() as u32,
+            )),
             ast::Expression::Variable(variable) => self.compile_variable(variable, variables),
         }
     }
 
+    fn compile_application(&self, closure: llvm::Value, arguments: &[llvm::Value]) -> llvm::Value {
+        let arity = closure.type_().element().struct_elements()[0]
+            .element()
+            .function_arguments()
+            .len()
+            - 1;
+
+        if arguments.len() < arity {
+            return self.compile_partial_application(closure, arguments);
+        }
+
+        let mut all_arguments = vec![self.builder.build_gep(
+            closure,
+            &[
+                llvm::const_int(llvm::Type::i32(), 0),
+                llvm::const_int(llvm::Type::i32(), 1),
+            ],
+        )];
+        all_arguments.extend_from_slice(&arguments[..arity]);
+
+        let result = self.builder.build_call(
+            self.builder.build_load(self.builder.build_gep(
+                closure,
+                &[
+                    llvm::const_int(llvm::Type::i32(), 0),
+                    llvm::const_int(llvm::Type::i32(), 0),
+                ],
+            )),
+            &all_arguments,
+        );
+
+        if arguments.len() == arity {
+            result
+        } else {
+            self.compile_application(result, &arguments[arity..])
+        }
+    }
+
+    fn compile_partial_application(
+        &self,
+        closure: llvm::Value,
+        arguments: &[llvm::Value],
+    ) -> llvm::Value {
+        let environment_type = llvm::Type::struct_(
+            &[closure]
+                .iter()
+                .chain(arguments)
+                .map(|value| value.type_())
+                .collect::<Vec<_>>(),
+        );
+        let entry_function = self
+            .function_compiler
+            .compile_partial_application(closure.type_(), environment_type);
+        let closure_type = llvm::Type::struct_(&[entry_function.type_(), environment_type]);
+        let pointer = self.builder.build_bit_cast(
+            self.builder.build_malloc(closure_type.size()),
+            llvm::Type::pointer(closure_type),
+        );
+
+        self.builder.build_store(
+            entry_function,
+            self.builder.build_gep(
+                pointer,
+                &[
+                    llvm::const_int(llvm::Type::i32(), 0),
+                    llvm::const_int(llvm::Type::i32(), 0),
+                ],
+            ),
+        );
+
+        for (index, value) in [closure].iter().chain(arguments).enumerate() {
+            self.builder.build_store(
+                *value,
+                self.builder.build_gep(
+                    pointer,
+                    &[
+                        llvm::const_int(llvm::Type::i32(), 0),
+                        llvm::const_int(llvm::Type::i32(), 1),
+                        llvm::const_int(llvm::Type::i32(), index as u64),
+                    ],
+                ),
+            );
+        }
+
+        self.builder.build_bit_cast(
+            pointer,
+            llvm::Type::pointer(llvm::Type::struct_(&[
+                entry_function.type_(),
+                self.type_compiler.compile_unsized_environment(),
+            ])),
+        )
+    }
+
+    fn compile_case(
+        &self,
+        case: &ast::Case,
+        variables: &HashMap<String, llvm::Value>,
+    ) -> Result<llvm::Value, CompileError> {
+        let argument = self.compile(case.argument(), variables)?;
+        let tag = self.builder.build_load(self.builder.build_gep(
+            argument,
+            &[
+                llvm::const_int(llvm::Type::i32(), 0),
+                llvm::const_int(llvm::Type::i32(), 0),
+            ],
+        ));
+
+        let default = self.builder.append_basic_block("default");
+        let phi = self.builder.append_basic_block("phi");
+        let switch = self.builder.get_insert_block();
+
+        let mut cases = vec![];
+        let mut values = vec![];
+        let mut blocks = vec![];
+
+        for alternative in case.alternatives() {
+            let constructor = alternative.constructor();
+            let block = self.builder.append_basic_block("alternative");
+
+            cases.push((
+                llvm::const_int(self.type_compiler.compile_tag(), constructor.index() as u64),
+                block,
+            ));
+            self.builder.position_at_end(block);
+
+            let pointer = self.builder.build_bit_cast(
+                argument,
+                llvm::Type::pointer(self.type_compiler.compile_constructor(
+                    &constructor.algebraic_type().constructors()[constructor.index()],
+                )),
+            );
+            let mut variables = variables.clone();
+
+            for (index, name) in alternative.element_names().iter().enumerate() {
+                variables.insert(
+                    name.clone(),
+                    self.builder.build_load(self.builder.build_gep(
+                        pointer,
+                        &[
+                            llvm::const_int(llvm::Type::i32(), 0),
+                            llvm::const_int(llvm::Type::i32(), index as u64 + 1),
+                        ],
+                    )),
+                );
+            }
+
+            values.push(self.compile(alternative.expression(), &variables)?);
+            blocks.push(self.builder.get_insert_block());
+            self.builder.build_br(phi);
+        }
+
+        self.builder.position_at_end(default);
+
+        match case.default_alternative() {
+            Some(expression) => {
+                values.push(self.compile(expression, variables)?);
+                blocks.push(self.builder.get_insert_block());
+                self.builder.build_br(phi);
+            }
+            None => self.builder.build_unreachable(),
+        }
+
+        self.builder.position_at_end(switch);
+        self.builder.build_switch(tag, default, &cases);
+
+        self.builder.position_at_end(phi);
+        Ok(self.builder.build_phi(values[0].type_(), &values, &blocks))
+    }
+
+    fn compile_string(&self, string: &str) -> llvm::Value {
+        let buffer = llvm::const_struct(&[
+            llvm::const_int(llvm::Type::i64(), string.len() as u64),
+            llvm::const_string(string),
+        ]);
+        let global = self.module.add_global("", buffer.type_());
+
+        global.set_initializer(buffer);
+        global.set_global_constant();
+        global.set_linkage(llvm::LLVMLinkage::LLVMPrivateLinkage);
+
+        llvm::const_bit_cast(global, self.type_compiler.compile_string())
+    }
+
+    fn compile_comparison(
+        &self,
+        real_predicate: llvm::LLVMRealPredicate,
+        int_predicate: llvm::LLVMIntPredicate,
+        lhs: llvm::Value,
+        rhs: llvm::Value,
+    ) -> llvm::Value {
+        match lhs.type_().kind() {
+            llvm::TypeKind::Double => self.builder.build_fcmp(real_predicate, lhs, rhs),
+            llvm::TypeKind::Pointer => self.builder.build_icmp(
+                int_predicate,
+                self.builder
+                    .build_call_with_name("sloth_string_equal", &[lhs, rhs]),
+                llvm::const_int(llvm::Type::i8(), 1),
+            ),
+            _ => self.builder.build_icmp(int_predicate, lhs, rhs),
+        }
+    }
+
+    fn compile_floor_adjustment(
+        &self,
+        remainder: llvm::Value,
+        lhs: llvm::Value,
+        rhs: llvm::Value,
+    ) -> llvm::Value {
+        let zero = llvm::const_int(lhs.type_(), 0);
+
+        self.builder.build_and(
+            self.builder
+                .build_icmp(llvm::LLVMIntPredicate::LLVMIntNE, remainder, zero),
+            self.builder.build_icmp(
+                llvm::LLVMIntPredicate::LLVMIntSLT,
+                self.builder.build_xor(lhs, rhs),
+                zero,
+            ),
+        )
+    }
+
+    fn compile_divisor(&self, divisor: llvm::Value) -> llvm::Value {
+        let zero = self.builder.append_basic_block("zero");
+        let non_zero = self.builder.append_basic_block("non_zero");
+
+        self.builder.build_cond_br(
+            self.builder.build_icmp(
+                llvm::LLVMIntPredicate::LLVMIntEQ,
+                divisor,
+                llvm::const_int(divisor.type_(), 0),
+            ),
+            zero,
+            non_zero,
+        );
+
+        self.builder.position_at_end(zero);
+        self.builder.build_division_by_zero();
+        self.builder.build_unreachable();
+
+        self.builder.position_at_end(non_zero);
+        self.builder.build_select(
+            self.compile_minus_one_check(divisor),
+            llvm::const_int(divisor.type_(), 1),
+            divisor,
+        )
+    }
+
+    fn compile_quotient(
+        &self,
+        lhs: llvm::Value,
+        rhs: llvm::Value,
+        divisor: llvm::Value,
+    ) -> llvm::Value {
+        self.builder.build_select(
+            self.compile_minus_one_check(rhs),
+            self.builder.build_neg(lhs),
+            self.builder.build_sdiv(lhs, divisor),
+        )
+    }
+
+    fn compile_minus_one_check(&self, value: llvm::Value) -> llvm::Value {
+        self.builder.build_icmp(
+            llvm::LLVMIntPredicate::LLVMIntEQ,
+            value,
+            llvm::const_int(value.type_(), -1i64 as u64),
+        )
+    }
+
+    fn compile_float_to_int(&self, value: llvm::Value, type_: llvm::Type) -> llvm::Value {
+        let bound = 2f64.powi(type_.int_width() as i32 - 1);
+        let is_in_range = self.builder.build_and(
+            self.builder.build_fcmp(
+                llvm::LLVMRealPredicate::LLVMRealOGE,
+                value,
+                llvm::const_real(value.type_(), -bound),
+            ),
+            self.builder.build_fcmp(
+                llvm::LLVMRealPredicate::LLVMRealOLT,
+                value,
+                llvm::const_real(value.type_(), bound),
+            ),
+        );
+
+        self.builder.build_select(
+            is_in_range,
+            self.builder.build_fp_to_si(
+                self.builder
+                    .build_select(is_in_range, value, llvm::const_real(value.type_(), 0.0)),
+                type_,
+            ),
+            self.builder.build_select(
+                self.builder.build_fcmp(
+                    llvm::LLVMRealPredicate::LLVMRealOGT,
+                    value,
+                    llvm::const_real(value.type_(), 0.0),
+                ),
+                llvm::const_int(type_, u64::MAX >> (65 - type_.int_width())),
+                self.builder.build_select(
+                    self.builder.build_fcmp(
+                        llvm::LLVMRealPredicate::LLVMRealOLT,
+                        value,
+                        llvm::const_real(value.type_(), 0.0),
+                    ),
+                    llvm::const_int(type_, 1 << (type_.int_width() - 1)),
+                    llvm::const_int(type_, 0),
+                ),
+            ),
+        )
+    }
+
+    fn compile_shift_amount(&self, amount: llvm::Value) -> llvm::Value {
+        self.builder
+            .build_and(amount, llvm::const_int(amount.type_(), 63))
+    }
+
     fn compile_variable(
         &self,
         variable: &ast::Variable,
@@ -159,10 +758,27 @@ impl<'a> ExpressionCompiler<'a> {
         }
     }
 
+    fn is_closure(&self, type_: llvm::Type) -> bool {
+        match type_.struct_elements().first() {
+            Some(element) => {
+                element.kind() == llvm::TypeKind::Pointer
+                    && element.element().kind() == llvm::TypeKind::Function
+            }
+            None => false,
+        }
+    }
+
     fn unwrap_value(&self, value: llvm::Value) -> llvm::Value {
         if value.type_().kind() == llvm::TypeKind::Pointer {
             match value.type_().element().kind() {
-                llvm::TypeKind::Double => self.builder.build_load(value),
+                llvm::TypeKind::Double | llvm::TypeKind::Integer | llvm::TypeKind::Pointer => {
+                    self.builder.build_load(value)
+                }
+                llvm::TypeKind::Struct
+                    if value.is_global_variable() && !self.is_closure(value.type_().element()) =>
+                {
+                    self.builder.build_load(value)
+                }
                 _ => value,
             }
         } else {
@@ -5,6 +5,8 @@ use super::type_compiler::TypeCompiler;
 use crate::ast;
 use std::collections::HashMap;
 
+const PARTIAL_APPLICATION_NAME: &str = "sloth_partial_application";
+
 pub struct FunctionCompiler<'a> {
     module: llvm::Module,
     type_compiler: &'a TypeCompiler,
@@ -68,7 +70,7 @@ impl<'a> FunctionCompiler<'a> {
         }
 
         builder.build_ret(
-            ExpressionCompiler::new(&builder, &self, self.type_compiler)
+            ExpressionCompiler::new(self.module, &builder, &self, self.type_compiler)
                 .compile(&function_definition.body(), &variables)?,
         );
 
@@ -77,6 +79,76 @@ impl<'a> FunctionCompiler<'a> {
         Ok(entry_function)
     }
 
+    pub fn compile_partial_application(
+        &self,
+        closure_type: llvm::Type,
+        environment_type: llvm::Type,
+    ) -> llvm::Value {
+        let function_type = closure_type.element().struct_elements()[0].element();
+        let argument_types = function_type.function_arguments();
+        let environment_types = environment_type.struct_elements();
+
+        let entry_function = self.module.add_function(
+            &Self::generate_closure_entry_name(PARTIAL_APPLICATION_NAME),
+            llvm::Type::function(
+                function_type.function_result(),
+                &[argument_types[0]]
+                    .iter()
+                    .chain(&argument_types[environment_types.len()..])
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            ),
+        );
+
+        let builder = llvm::Builder::new(entry_function);
+        builder.position_at_end(builder.append_basic_block("entry"));
+
+        let environment = builder.build_bit_cast(
+            llvm::get_param(entry_function, 0),
+            llvm::Type::pointer(environment_type),
+        );
+        let mut values = (0..environment_types.len())
+            .map(|index| {
+                builder.build_load(builder.build_gep(
+                    environment,
+                    &[
+                        llvm::const_int(llvm::Type::i32(), 0),
+                        llvm::const_int(llvm::Type::i32(), index as u64),
+                    ],
+                ))
+            })
+            .collect::<Vec<_>>();
+        let closure = values.remove(0);
+
+        let mut arguments = vec![builder.build_gep(
+            closure,
+            &[
+                llvm::const_int(llvm::Type::i32(), 0),
+                llvm::const_int(llvm::Type::i32(), 1),
+            ],
+        )];
+        arguments.extend(values);
+        arguments.extend(
+            (1..(argument_types.len() - environment_types.len() + 1))
+                .map(|index| llvm::get_param(entry_function, index as u32)),
+        );
+
+        builder.build_ret(builder.build_call(
+            builder.build_load(builder.build_gep(
+                closure,
+                &[
+                    llvm::const_int(llvm::Type::i32(), 0),
+                    llvm::const_int(llvm::Type::i32(), 0),
+                ],
+            )),
+            &arguments,
+        ));
+
+        llvm::verify_function(entry_function);
+
+        entry_function
+    }
+
     fn generate_closure_entry_name(name: &str) -> String {
         [name, ".$entry"].concat()
     }
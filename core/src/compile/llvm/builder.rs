@@ -5,6 +5,7 @@ use super::utilities::c_string;
 use super::value::*;
 use llvm_sys::core::*;
 use llvm_sys::prelude::*;
+use llvm_sys::{LLVMIntPredicate, LLVMRealPredicate};
 
 pub struct Builder {
     module: LLVMModuleRef,
@@ -41,12 +42,31 @@ impl Builder {
         unsafe { LLVMBuildCondBr(self.builder, condition.into(), then.into(), els.into()) };
     }
 
+    pub fn build_switch(&self, value: Value, default: BasicBlock, cases: &[(Value, BasicBlock)]) {
+        unsafe {
+            let switch = LLVMBuildSwitch(
+                self.builder,
+                value.into(),
+                default.into(),
+                cases.len() as u32,
+            );
+
+            for (value, block) in cases {
+                LLVMAddCase(switch, value.into(), block.into());
+            }
+        }
+    }
+
+    pub fn build_unreachable(&self) {
+        unsafe { LLVMBuildUnreachable(self.builder) };
+    }
+
     pub fn build_phi(
         &self,
         type_: Type,
         incoming_values: &[Value],
         incoming_blocks: &[BasicBlock],
-    ) {
+    ) -> Value {
         unsafe {
             let phi = LLVMBuildPhi(self.builder, type_.into(), c_string("").as_ptr());
 
@@ -63,8 +83,11 @@ impl Builder {
                     .collect::<Vec<LLVMBasicBlockRef>>()
                     .as_mut_ptr(),
                 incoming_values.len() as u32,
-            )
-        };
+            );
+
+            phi
+        }
+        .into()
     }
 
     pub fn build_bit_cast(&self, value: Value, type_: Type) -> Value {
@@ -96,6 +119,26 @@ impl Builder {
         .into()
     }
 
+    pub fn build_extract_value(&self, aggregate: Value, index: u32) -> Value {
+        unsafe {
+            LLVMBuildExtractValue(self.builder, aggregate.into(), index, c_string("").as_ptr())
+        }
+        .into()
+    }
+
+    pub fn build_insert_value(&self, aggregate: Value, element: Value, index: u32) -> Value {
+        unsafe {
+            LLVMBuildInsertValue(
+                self.builder,
+                aggregate.into(),
+                element.into(),
+                index,
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
     pub fn build_call(&self, function: Value, arguments: &[Value]) -> Value {
         unsafe {
             LLVMBuildCall(
@@ -144,6 +187,134 @@ impl Builder {
         unsafe { LLVMBuildFDiv(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
     }
 
+    pub fn build_frem(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildFRem(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_fneg(&self, value: Value) -> Value {
+        unsafe { LLVMBuildFNeg(self.builder, value.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_add(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildAdd(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_sub(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildSub(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_mul(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildMul(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_sdiv(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildSDiv(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_srem(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildSRem(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_and(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildAnd(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_or(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildOr(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_xor(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildXor(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_shl(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildShl(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_ashr(&self, lhs: Value, rhs: Value) -> Value {
+        unsafe { LLVMBuildAShr(self.builder, lhs.into(), rhs.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_neg(&self, value: Value) -> Value {
+        unsafe { LLVMBuildNeg(self.builder, value.into(), c_string("").as_ptr()) }.into()
+    }
+
+    pub fn build_select(&self, condition: Value, then: Value, els: Value) -> Value {
+        unsafe {
+            LLVMBuildSelect(
+                self.builder,
+                condition.into(),
+                then.into(),
+                els.into(),
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    pub fn build_si_to_fp(&self, value: Value, type_: Type) -> Value {
+        unsafe {
+            LLVMBuildSIToFP(
+                self.builder,
+                value.into(),
+                type_.into(),
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    pub fn build_fp_to_si(&self, value: Value, type_: Type) -> Value {
+        unsafe {
+            LLVMBuildFPToSI(
+                self.builder,
+                value.into(),
+                type_.into(),
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    pub fn build_int_cast(&self, value: Value, type_: Type) -> Value {
+        unsafe {
+            LLVMBuildIntCast2(
+                self.builder,
+                value.into(),
+                type_.into(),
+                0,
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    pub fn build_fcmp(&self, predicate: LLVMRealPredicate, lhs: Value, rhs: Value) -> Value {
+        unsafe {
+            LLVMBuildFCmp(
+                self.builder,
+                predicate,
+                lhs.into(),
+                rhs.into(),
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    pub fn build_icmp(&self, predicate: LLVMIntPredicate, lhs: Value, rhs: Value) -> Value {
+        unsafe {
+            LLVMBuildICmp(
+                self.builder,
+                predicate,
+                lhs.into(),
+                rhs.into(),
+                c_string("").as_ptr(),
+            )
+        }
+        .into()
+    }
+
     pub fn append_basic_block(&self, name: &str) -> BasicBlock {
         unsafe { LLVMAppendBasicBlock(self.function, c_string(name).as_ptr()) }.into()
     }
@@ -152,6 +323,10 @@ impl Builder {
         unsafe { LLVMPositionBuilderAtEnd(self.builder, block.into()) };
     }
 
+    pub fn get_insert_block(&self) -> BasicBlock {
+        unsafe { LLVMGetInsertBlock(self.builder) }.into()
+    }
+
     pub fn build_coro_id(&self, promise: Value) -> Value {
         self.build_call_with_name(
             "llvm.coro.id",
@@ -202,4 +377,16 @@ impl Builder {
     pub fn build_free(&self, pointer: Value) {
         self.build_call_with_name("free", &[pointer]);
     }
+
+    pub fn build_division_by_zero(&self) {
+        self.build_call_with_name("sloth_division_by_zero", &[]);
+    }
+
+    pub fn build_empty_list_failure(&self) {
+        self.build_call_with_name("sloth_empty_list_failure", &[]);
+    }
+
+    pub fn build_match_failure(&self) {
+        self.build_call_with_name("sloth_match_failure", &[]);
+    }
 }
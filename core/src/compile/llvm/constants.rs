@@ -16,6 +16,14 @@ pub fn const_null(pointer_type: Type) -> Value {
     unsafe { LLVMConstNull(pointer_type.into()) }.into()
 }
 
+pub fn const_string(string: &str) -> Value {
+    unsafe { LLVMConstString(string.as_ptr() as *const _, string.len() as c_uint, 1) }.into()
+}
+
+pub fn const_bit_cast(value: Value, type_: Type) -> Value {
+    unsafe { LLVMConstBitCast(value.into(), type_.into()) }.into()
+}
+
 pub fn const_struct(elements: &[Value]) -> Value {
     unsafe {
         LLVMConstStruct(
@@ -13,6 +13,7 @@ pub use basic_block::*;
 pub use bit_code::*;
 pub use builder::*;
 pub use constants::*;
+pub use llvm_sys::{LLVMIntPredicate, LLVMLinkage, LLVMRealPredicate};
 pub use module::*;
 pub use type_::*;
 pub use type_kind::*;
@@ -77,8 +77,32 @@ impl Module {
         );
         self.declare_function("llvm.coro.resume", Type::void(), &[Type::generic_pointer()]);
 
+        for name in &[
+            "llvm.sqrt.f64",
+            "llvm.floor.f64",
+            "llvm.ceil.f64",
+            "llvm.exp.f64",
+            "llvm.log.f64",
+            "llvm.sin.f64",
+            "llvm.cos.f64",
+            "llvm.fabs.f64",
+        ] {
+            self.declare_function(name, Type::double(), &[Type::double()]);
+        }
+
+        self.declare_function(
+            "llvm.pow.f64",
+            Type::double(),
+            &[Type::double(), Type::double()],
+        );
+
+        self.declare_function("llvm.ctpop.i64", Type::i64(), &[Type::i64()]);
+
         self.declare_function("malloc", Type::generic_pointer(), &[Type::i64()]);
         self.declare_function("free", Type::void(), &[Type::generic_pointer()]);
+        self.declare_function("sloth_division_by_zero", Type::void(), &[]);
+        self.declare_function("sloth_empty_list_failure", Type::void(), &[]);
+        self.declare_function("sloth_match_failure", Type::void(), &[]);
     }
 
     #[allow(dead_code)]
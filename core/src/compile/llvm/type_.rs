@@ -39,6 +39,29 @@ impl Type {
             .collect::<Vec<_>>()
     }
 
+    pub fn int_width(self) -> c_uint {
+        assert_eq!(self.kind(), TypeKind::Integer);
+
+        unsafe { LLVMGetIntTypeWidth(self.into()) }
+    }
+
+    pub fn function_arguments(self) -> Vec<Type> {
+        assert_eq!(self.kind(), TypeKind::Function);
+
+        let mut arguments =
+            vec![std::ptr::null_mut(); unsafe { LLVMCountParamTypes(self.into()) } as usize];
+
+        unsafe { LLVMGetParamTypes(self.into(), arguments.as_mut_ptr()) };
+
+        arguments.iter().map(|type_| (*type_).into()).collect()
+    }
+
+    pub fn function_result(self) -> Type {
+        assert_eq!(self.kind(), TypeKind::Function);
+
+        unsafe { LLVMGetReturnType(self.into()) }.into()
+    }
+
     pub fn size(self) -> Value {
         unsafe { LLVMSizeOf(self.into()) }.into()
     }
@@ -84,6 +107,10 @@ impl Type {
         unsafe { LLVMPointerType(content.into(), 0) }.into()
     }
 
+    pub fn array(element: Self, length: c_uint) -> Self {
+        unsafe { LLVMArrayType(element.into(), length) }.into()
+    }
+
     pub fn function(result: Self, arguments: &[Self]) -> Self {
         unsafe {
             LLVMFunctionType(
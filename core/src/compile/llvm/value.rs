@@ -16,12 +16,20 @@ impl Value {
         unsafe { LLVMSetInitializer(self.into(), value.into()) };
     }
 
+    pub fn set_global_constant(self) {
+        unsafe { LLVMSetGlobalConstant(self.into(), 1) };
+    }
+
+    pub fn set_linkage(self, linkage: llvm_sys::LLVMLinkage) {
+        unsafe { LLVMSetLinkage(self.into(), linkage) };
+    }
+
     pub fn type_(self) -> Type {
         unsafe { LLVMTypeOf(self.into()) }.into()
     }
 
-    pub fn is_global_variable(self) -> Value {
-        unsafe { LLVMIsAGlobalVariable(self.into()) }.into()
+    pub fn is_global_variable(self) -> bool {
+        !unsafe { LLVMIsAGlobalVariable(self.into()) }.is_null()
     }
 
     #[allow(dead_code)]
@@ -9,6 +9,7 @@ use super::verify::verify;
 use crate::ast;
 pub use error::CompileError;
 use module_compiler::ModuleCompiler;
+pub use module_compiler::MAIN_FUNCTION_NAME;
 use type_compiler::TypeCompiler;
 
 pub fn compile(ast_module: &ast::Module) -> Result<&'static [u8], CompileError> {
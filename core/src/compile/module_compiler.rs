@@ -6,6 +6,7 @@ use super::llvm;
 use super::type_compiler::TypeCompiler;
 use std::collections::HashMap;
 
+pub const MAIN_FUNCTION_NAME: &str = "sloth_main";
 const GLOBAL_INITIALIZER_NAME: &str = "sloth_init";
 
 pub struct ModuleCompiler<'a> {
@@ -34,6 +35,7 @@ impl<'a> ModuleCompiler<'a> {
     pub fn compile(&mut self) -> Result<(), CompileError> {
         {
             self.module.declare_intrinsics();
+            self.declare_string_functions();
 
             for definition in self.ast_module.definitions() {
                 match definition {
@@ -65,13 +67,44 @@ impl<'a> ModuleCompiler<'a> {
         Ok(())
     }
 
+    fn declare_string_functions(&self) {
+        let string_type = self.type_compiler.compile_string();
+
+        self.module.declare_function(
+            "sloth_string_concatenate",
+            string_type,
+            &[string_type, string_type],
+        );
+        self.module.declare_function(
+            "sloth_string_equal",
+            llvm::Type::i8(),
+            &[string_type, string_type],
+        );
+        self.module
+            .declare_function("sloth_string_length", llvm::Type::i64(), &[string_type]);
+        self.module.declare_function(
+            "sloth_string_slice",
+            string_type,
+            &[string_type, llvm::Type::i64(), llvm::Type::i64()],
+        );
+        self.module.declare_function(
+            "sloth_char_to_string",
+            string_type,
+            &[self.type_compiler.compile_char()],
+        );
+        self.module
+            .declare_function("sloth_int_to_string", string_type, &[llvm::Type::i64()]);
+        self.module.declare_function(
+            "sloth_number_to_string",
+            string_type,
+            &[llvm::Type::double()],
+        );
+    }
+
     fn declare_function(&mut self, function_definition: &ast::FunctionDefinition) {
-        self.global_variables.insert(
-            function_definition.name().into(),
-            self.module.add_global(
-                function_definition.name(),
-                self.type_compiler.compile_closure(function_definition),
-            ),
+        self.declare_global(
+            function_definition.name(),
+            self.type_compiler.compile_closure(function_definition),
         );
     }
 
@@ -89,15 +122,22 @@ impl<'a> ModuleCompiler<'a> {
     }
 
     fn declare_global_variable(&mut self, value_definition: &ast::ValueDefinition) {
-        self.global_variables.insert(
-            value_definition.name().into(),
-            self.module.add_global(
-                value_definition.name(),
-                self.type_compiler.compile_value(value_definition.type_()),
-            ),
+        self.declare_global(
+            value_definition.name(),
+            self.type_compiler.compile(value_definition.type_()),
         );
     }
 
+    fn declare_global(&mut self, name: &str, type_: llvm::Type) {
+        let global = self.module.add_global(name, type_);
+
+        if name != MAIN_FUNCTION_NAME {
+            global.set_linkage(llvm::LLVMLinkage::LLVMPrivateLinkage);
+        }
+
+        self.global_variables.insert(name.into(), global);
+    }
+
     fn compile_global_variable(
         &mut self,
         value_definition: &ast::ValueDefinition,
@@ -114,6 +154,7 @@ impl<'a> ModuleCompiler<'a> {
         builder.position_at_end(builder.append_basic_block("entry"));
         builder.build_store(
             ExpressionCompiler::new(
+                self.module,
                 &builder,
                 &FunctionCompiler::new(self.module, self.type_compiler, &self.global_variables),
                 &self.type_compiler,
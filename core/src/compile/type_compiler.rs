@@ -18,10 +18,74 @@ impl TypeCompiler {
 
     pub fn compile_value(&self, value: &types::Value) -> llvm::Type {
         match value {
+            types::Value::Algebraic(_) | types::Value::Index(_) => self.compile_algebraic(),
+            types::Value::Boolean => llvm::Type::i1(),
+            types::Value::Char => self.compile_char(),
+            types::Value::Int => llvm::Type::i64(),
             types::Value::Number => llvm::Type::double(),
+            types::Value::Record(record) => self.compile_record(record),
+            types::Value::String => self.compile_string(),
+            types::Value::Tuple(tuple) => self.compile_tuple(tuple),
         }
     }
 
+    pub fn compile_char(&self) -> llvm::Type {
+        llvm::Type::i32()
+    }
+
+    pub fn compile_string(&self) -> llvm::Type {
+        llvm::Type::pointer(llvm::Type::struct_(&[
+            llvm::Type::i64(),
+            llvm::Type::array(llvm::Type::i8(), 0),
+        ]))
+    }
+
+    pub fn compile_record(&self, record: &types::Record) -> llvm::Type {
+        llvm::Type::pointer(self.compile_unboxed_record(record))
+    }
+
+    pub fn compile_unboxed_record(&self, record: &types::Record) -> llvm::Type {
+        llvm::Type::struct_(
+            &record
+                .elements()
+                .iter()
+                .map(|type_| self.compile(type_))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn compile_tuple(&self, tuple: &types::Tuple) -> llvm::Type {
+        llvm::Type::struct_(
+            &tuple
+                .elements()
+                .iter()
+                .map(|type_| self.compile(type_))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn compile_algebraic(&self) -> llvm::Type {
+        llvm::Type::pointer(llvm::Type::struct_(&[self.compile_tag()]))
+    }
+
+    pub fn compile_constructor(&self, constructor: &types::Constructor) -> llvm::Type {
+        let mut elements = vec![self.compile_tag()];
+
+        elements.extend_from_slice(
+            &constructor
+                .elements()
+                .iter()
+                .map(|type_| self.compile(type_))
+                .collect::<Vec<_>>(),
+        );
+
+        llvm::Type::struct_(&elements)
+    }
+
+    pub fn compile_tag(&self) -> llvm::Type {
+        llvm::Type::i64()
+    }
+
     pub fn compile_function(&self, function: &types::Function) -> llvm::Type {
         let mut arguments = vec![llvm::Type::pointer(self.compile_unsized_environment())];
 
@@ -33,7 +97,7 @@ impl TypeCompiler {
                 .collect::<Vec<_>>(),
         );
 
-        llvm::Type::function(self.compile_value(function.result()), &arguments)
+        llvm::Type::function(self.compile(function.result()), &arguments)
     }
 
     pub fn compile_closure(&self, function_definition: &ast::FunctionDefinition) -> llvm::Type {
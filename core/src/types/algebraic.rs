@@ -0,0 +1,160 @@
+use super::constructor::Constructor;
+use super::function::Function;
+use super::record::Record;
+use super::tuple::Tuple;
+use super::type_::Type;
+use super::value::Value;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Algebraic {
+    constructors: Vec<Constructor>,
+}
+
+impl Algebraic {
+    pub fn new(constructors: Vec<Constructor>) -> Self {
+        Self { constructors }
+    }
+
+    pub fn constructors(&self) -> &[Constructor] {
+        &self.constructors
+    }
+
+    pub fn unfold(&self) -> Self {
+        Self::new(
+            self.constructors
+                .iter()
+                .map(|constructor| {
+                    Constructor::new(
+                        constructor
+                            .elements()
+                            .iter()
+                            .map(|type_| self.unfold_type(type_, 0))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn unfold_type(&self, type_: &Type, depth: usize) -> Type {
+        match type_ {
+            Type::Function(function) => Function::new(
+                function
+                    .arguments()
+                    .iter()
+                    .map(|type_| self.unfold_type(type_, depth))
+                    .collect(),
+                self.unfold_type(function.result(), depth),
+            )
+            .into(),
+            Type::Value(value) => self.unfold_value(value, depth).into(),
+        }
+    }
+
+    fn unfold_value(&self, value: &Value, depth: usize) -> Value {
+        match value {
+            Value::Algebraic(algebraic) => Self::new(
+                algebraic
+                    .constructors()
+                    .iter()
+                    .map(|constructor| {
+                        Constructor::new(
+                            constructor
+                                .elements()
+                                .iter()
+                                .map(|type_| self.unfold_type(type_, depth + 1))
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            )
+            .into(),
+            Value::Index(index) if *index == depth => self.clone().into(),
+            Value::Record(record) => Record::new(
+                record
+                    .elements()
+                    .iter()
+                    .map(|type_| self.unfold_type(type_, depth))
+                    .collect(),
+            )
+            .into(),
+            Value::Tuple(tuple) => Tuple::new(
+                tuple
+                    .elements()
+                    .iter()
+                    .map(|type_| self.unfold_type(type_, depth))
+                    .collect(),
+            )
+            .into(),
+            _ => value.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unfold_non_recursive_types() {
+        let algebraic = Algebraic::new(vec![Constructor::new(vec![Value::Number.into()])]);
+
+        assert_eq!(algebraic.unfold(), algebraic);
+    }
+
+    #[test]
+    fn unfold_recursive_types() {
+        let algebraic = Algebraic::new(vec![
+            Constructor::new(vec![]),
+            Constructor::new(vec![Value::Number.into(), Value::Index(0).into()]),
+        ]);
+
+        assert_eq!(
+            algebraic.unfold(),
+            Algebraic::new(vec![
+                Constructor::new(vec![]),
+                Constructor::new(vec![Value::Number.into(), algebraic.clone().into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn unfold_recursive_types_in_nested_types() {
+        let algebraic = Algebraic::new(vec![Constructor::new(vec![Algebraic::new(vec![
+            Constructor::new(vec![Value::Index(0).into(), Value::Index(1).into()]),
+        ])
+        .into()])]);
+
+        assert_eq!(
+            algebraic.unfold(),
+            Algebraic::new(vec![Constructor::new(vec![Algebraic::new(vec![
+                Constructor::new(vec![Value::Index(0).into(), algebraic.clone().into()])
+            ])
+            .into()])])
+        );
+    }
+
+    #[test]
+    fn unfold_recursive_types_in_tuples() {
+        let algebraic = Algebraic::new(vec![
+            Constructor::new(vec![]),
+            Constructor::new(vec![Tuple::new(vec![
+                Value::Number.into(),
+                Value::Index(0).into(),
+            ])
+            .into()]),
+        ]);
+
+        assert_eq!(
+            algebraic.unfold(),
+            Algebraic::new(vec![
+                Constructor::new(vec![]),
+                Constructor::new(vec![Tuple::new(vec![
+                    Value::Number.into(),
+                    algebraic.clone().into()
+                ])
+                .into()]),
+            ])
+        );
+    }
+}
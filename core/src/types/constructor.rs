@@ -0,0 +1,16 @@
+use super::type_::Type;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Constructor {
+    elements: Vec<Type>,
+}
+
+impl Constructor {
+    pub fn new(elements: Vec<Type>) -> Self {
+        Self { elements }
+    }
+
+    pub fn elements(&self) -> &[Type] {
+        &self.elements
+    }
+}
@@ -1,18 +1,17 @@
 use super::type_::Type;
-use super::value::Value;
 use std::rc::Rc;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Function {
     arguments: Vec<Type>,
-    result: Rc<Value>,
+    result: Rc<Type>,
 }
 
 impl Function {
-    pub fn new(arguments: Vec<Type>, result: Value) -> Self {
+    pub fn new(arguments: Vec<Type>, result: impl Into<Type>) -> Self {
         Self {
             arguments,
-            result: Rc::new(result),
+            result: Rc::new(result.into()),
         }
     }
 
@@ -20,7 +19,7 @@ impl Function {
         &self.arguments
     }
 
-    pub fn result(&self) -> &Value {
+    pub fn result(&self) -> &Type {
         &self.result
     }
 }
@@ -1,7 +1,15 @@
+mod algebraic;
+mod constructor;
 mod function;
+mod record;
+mod tuple;
 mod type_;
 mod value;
 
+pub use algebraic::*;
+pub use constructor::*;
 pub use function::*;
+pub use record::*;
+pub use tuple::*;
 pub use type_::*;
 pub use value::*;
@@ -1,4 +1,7 @@
+use super::algebraic::Algebraic;
 use super::function::Function;
+use super::record::Record;
+use super::tuple::Tuple;
 use super::value::Value;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,12 +10,30 @@ pub enum Type {
     Value(Value),
 }
 
+impl From<Algebraic> for Type {
+    fn from(algebraic: Algebraic) -> Self {
+        Type::Value(algebraic.into())
+    }
+}
+
 impl From<Function> for Type {
     fn from(function: Function) -> Self {
         Type::Function(function)
     }
 }
 
+impl From<Record> for Type {
+    fn from(record: Record) -> Self {
+        Type::Value(record.into())
+    }
+}
+
+impl From<Tuple> for Type {
+    fn from(tuple: Tuple) -> Self {
+        Type::Value(tuple.into())
+    }
+}
+
 impl From<Value> for Type {
     fn from(value: Value) -> Self {
         Type::Value(value)
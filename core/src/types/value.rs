@@ -1,4 +1,34 @@
+use super::algebraic::Algebraic;
+use super::record::Record;
+use super::tuple::Tuple;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Value {
+    Algebraic(Algebraic),
+    Boolean,
+    Char,
+    Index(usize),
+    Int,
     Number,
+    Record(Record),
+    String,
+    Tuple(Tuple),
+}
+
+impl From<Algebraic> for Value {
+    fn from(algebraic: Algebraic) -> Self {
+        Value::Algebraic(algebraic)
+    }
+}
+
+impl From<Record> for Value {
+    fn from(record: Record) -> Self {
+        Value::Record(record)
+    }
+}
+
+impl From<Tuple> for Value {
+    fn from(tuple: Tuple) -> Self {
+        Value::Tuple(tuple)
+    }
 }
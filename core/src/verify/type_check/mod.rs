@@ -131,6 +131,131 @@ mod test {
         assert_eq!(check_types(&module), Err(TypeCheckError));
     }
 
+    #[test]
+    fn check_types_of_functions_returning_closures() {
+        let closure_type =
+            types::Function::new(vec![types::Value::Number.into()], types::Value::Number);
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![Argument::new("x", types::Value::Number)],
+                LetFunctions::new(
+                    vec![FunctionDefinition::new(
+                        "g",
+                        vec![Argument::new("x", types::Value::Number)],
+                        vec![Argument::new("y", types::Value::Number)],
+                        Variable::new("x"),
+                        types::Value::Number,
+                    )],
+                    Variable::new("g"),
+                ),
+                closure_type.clone(),
+            )
+            .into(),
+            ValueDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(Variable::new("f"), vec![Expression::Number(42.0)]),
+                    vec![Expression::Number(42.0)],
+                ),
+                types::Value::Number,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                Application::new(Variable::new("f"), vec![Expression::Number(42.0)]),
+                closure_type,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn check_types_of_partial_applications() {
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![
+                    Argument::new("x", types::Value::Number),
+                    Argument::new("y", types::Value::Number),
+                ],
+                42.0,
+                types::Value::Number,
+            )
+            .into(),
+            ValueDefinition::new(
+                "x",
+                Application::new(Variable::new("f"), vec![Expression::Number(42.0)]),
+                types::Function::new(vec![types::Value::Number.into()], types::Value::Number),
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn check_types_of_applications_with_extra_arguments() {
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![Argument::new("x", types::Value::Number)],
+                Variable::new("g"),
+                types::Function::new(vec![types::Value::Number.into()], types::Value::Number),
+            )
+            .into(),
+            FunctionDefinition::new(
+                "g",
+                vec![],
+                vec![Argument::new("x", types::Value::Number)],
+                42.0,
+                types::Value::Number,
+            )
+            .into(),
+            ValueDefinition::new(
+                "x",
+                Application::new(
+                    Variable::new("f"),
+                    vec![Expression::Number(42.0), Expression::Number(42.0)],
+                ),
+                types::Value::Number,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_applications_of_non_functions() {
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![Argument::new("x", types::Value::Number)],
+                42.0,
+                types::Value::Number,
+            )
+            .into(),
+            ValueDefinition::new(
+                "x",
+                Application::new(
+                    Application::new(Variable::new("f"), vec![Expression::Number(42.0)]),
+                    vec![Expression::Number(42.0)],
+                ),
+                types::Value::Number,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
     #[test]
     fn fail_to_check_types_because_of_missing_variables() {
         let module = Module::new(vec![ValueDefinition::new(
@@ -189,4 +314,725 @@ mod test {
 
         assert_eq!(check_types(&module), Err(TypeCheckError));
     }
+
+    #[test]
+    fn check_types_of_if() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(true, 42.0, 13.0),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_if_with_non_boolean_conditions() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(42.0, 42.0, 13.0),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_if_with_different_branch_types() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(true, 42.0, false),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_comparisons() {
+        let module = Module::new(vec![
+            ValueDefinition::new(
+                "x",
+                Operation::new(Operator::LessThan, 42.0, 13.0),
+                types::Value::Boolean,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                Operation::new(Operator::Equal, true, false),
+                types::Value::Boolean,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_comparisons() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::LessThan, true, false),
+            types::Value::Boolean,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::Equal, 42.0, false),
+            types::Value::Boolean,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_negations() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Negation::new(Operation::new(Operator::Add, 42.0, 13.0)),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_negations() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Negation::new(true),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_integer_operations() {
+        let module = Module::new(vec![
+            ValueDefinition::new(
+                "x",
+                Negation::new(Operation::new(
+                    Operator::FloorDivide,
+                    Operation::new(Operator::Add, 42, 13),
+                    2,
+                )),
+                types::Value::Int,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                Operation::new(Operator::LessThan, 42, 13),
+                types::Value::Boolean,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_integer_operations() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::Add, 42, 13.0),
+            types::Value::Int,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::Modulo, 42.0, 13.0),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_bitwise_operations() {
+        for operator in &[
+            Operator::BitwiseAnd,
+            Operator::BitwiseOr,
+            Operator::BitwiseXor,
+            Operator::ShiftLeft,
+            Operator::ShiftRight,
+        ] {
+            let module = Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(*operator, 42, 13),
+                types::Value::Int,
+            )
+            .into()]);
+
+            assert_eq!(check_types(&module), Ok(()));
+        }
+    }
+
+    #[test]
+    fn fail_to_check_types_of_bitwise_operations() {
+        for operator in &[
+            Operator::BitwiseAnd,
+            Operator::BitwiseOr,
+            Operator::BitwiseXor,
+            Operator::ShiftLeft,
+            Operator::ShiftRight,
+        ] {
+            let module = Module::new(vec![ValueDefinition::new(
+                "x",
+                Operation::new(*operator, 42.0, 13.0),
+                types::Value::Number,
+            )
+            .into()]);
+
+            assert_eq!(check_types(&module), Err(TypeCheckError));
+        }
+    }
+
+    #[test]
+    fn check_types_of_math_operations() {
+        let module = Module::new(vec![
+            ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::Power,
+                    BuiltInCall::new(BuiltInFunction::SquareRoot, vec![42.0.into()]),
+                    Operation::new(Operator::Remainder, 42.0, 13.0),
+                ),
+                types::Value::Number,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                Operation::new(Operator::Remainder, 42, 13),
+                types::Value::Int,
+            )
+            .into(),
+            ValueDefinition::new(
+                "z",
+                BuiltInCall::new(BuiltInFunction::PopCount, vec![42.into()]),
+                types::Value::Int,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_math_operations() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::Power, 42, 13),
+            types::Value::Int,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            BuiltInCall::new(BuiltInFunction::Floor, vec![42.into()]),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_strings() {
+        let module = Module::new(vec![
+            ValueDefinition::new(
+                "x",
+                BuiltInCall::new(
+                    BuiltInFunction::StringSlice,
+                    vec![
+                        Operation::new(
+                            Operator::Concatenate,
+                            "foo",
+                            Conversion::new(types::Value::String, 42),
+                        )
+                        .into(),
+                        0.into(),
+                        BuiltInCall::new(BuiltInFunction::StringLength, vec!["bar".into()]).into(),
+                    ],
+                ),
+                types::Value::String,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                Operation::new(Operator::Equal, "foo", "bar"),
+                types::Value::Boolean,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_strings() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::Concatenate, "foo", 42.0),
+            types::Value::String,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            BuiltInCall::new(BuiltInFunction::StringSlice, vec!["foo".into(), 0.into()]),
+            types::Value::String,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Conversion::new(types::Value::Number, "foo"),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_chars() {
+        let module = Module::new(vec![
+            ValueDefinition::new(
+                "x",
+                Operation::new(
+                    Operator::LessThan,
+                    'a',
+                    Conversion::new(types::Value::Char, 98),
+                ),
+                types::Value::Boolean,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                Operation::new(
+                    Operator::Concatenate,
+                    Conversion::new(types::Value::String, 'a'),
+                    Conversion::new(
+                        types::Value::String,
+                        Conversion::new(types::Value::Int, 'b'),
+                    ),
+                ),
+                types::Value::String,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_chars() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(Operator::Add, 'a', 'b'),
+            types::Value::Char,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Conversion::new(types::Value::Char, 97.0),
+            types::Value::Char,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_conversions() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Operation::new(
+                Operator::Add,
+                Conversion::new(types::Value::Number, 42),
+                13.0,
+            ),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_conversions() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Conversion::new(types::Value::Int, true),
+            types::Value::Int,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_tuples() {
+        let tuple_type = types::Tuple::new(vec![
+            types::Value::Number.into(),
+            types::Value::Boolean.into(),
+        ]);
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![Argument::new("x", types::Value::Number)],
+                Tuple::new(vec![Variable::new("x").into(), true.into()]),
+                tuple_type.clone(),
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                TupleElement::new(Application::new(Variable::new("f"), vec![42.0.into()]), 0),
+                types::Value::Number,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_tuples() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Tuple::new(vec![42.0.into(), true.into()]),
+            types::Tuple::new(vec![
+                types::Value::Boolean.into(),
+                types::Value::Number.into(),
+            ]),
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            TupleElement::new(Tuple::new(vec![42.0.into()]), 1),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            TupleElement::new(42.0, 0),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_records() {
+        let record_type = types::Record::new(vec![
+            types::Value::Number.into(),
+            types::Value::Boolean.into(),
+        ]);
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![Argument::new("x", types::Value::Number)],
+                Record::new(
+                    record_type.clone(),
+                    vec![Variable::new("x").into(), true.into()],
+                ),
+                record_type,
+            )
+            .into(),
+            ValueDefinition::new(
+                "y",
+                RecordElement::new(Application::new(Variable::new("f"), vec![42.0.into()]), 0),
+                types::Value::Number,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_records() {
+        let record_type = types::Record::new(vec![types::Value::Number.into()]);
+        let cases: Vec<(Expression, types::Type)> = vec![
+            (
+                Record::new(record_type.clone(), vec![true.into()]).into(),
+                record_type.clone().into(),
+            ),
+            (
+                Record::new(record_type.clone(), vec![42.0.into(), 42.0.into()]).into(),
+                record_type.clone().into(),
+            ),
+            (
+                RecordElement::new(Record::new(record_type.clone(), vec![42.0.into()]), 1).into(),
+                types::Value::Number.into(),
+            ),
+            (
+                RecordElement::new(Tuple::new(vec![42.0.into()]), 0).into(),
+                types::Value::Number.into(),
+            ),
+        ];
+
+        for (expression, type_) in cases {
+            let module = Module::new(vec![ValueDefinition::new("x", expression, type_).into()]);
+
+            assert_eq!(check_types(&module), Err(TypeCheckError));
+        }
+    }
+
+    #[test]
+    fn check_types_of_match_failures() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(true, 42.0, MatchFailure::new(types::Value::Number)),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_match_failures() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(true, 42.0, MatchFailure::new(types::Value::Boolean)),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_empty_list_failures() {
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            If::new(true, 42.0, EmptyListFailure::new(types::Value::Number)),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn check_types_of_constructor_applications() {
+        let algebraic_type = types::Algebraic::new(vec![
+            types::Constructor::new(vec![types::Value::Number.into()]),
+            types::Constructor::new(vec![]),
+        ]);
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            ConstructorApplication::new(
+                Constructor::new(algebraic_type.clone(), 0),
+                vec![42.0.into()],
+            ),
+            algebraic_type,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_constructor_applications() {
+        let algebraic_type = types::Algebraic::new(vec![types::Constructor::new(vec![
+            types::Value::Number.into(),
+        ])]);
+
+        for arguments in [vec![], vec![true.into()], vec![42.0.into(), 42.0.into()]] {
+            let module = Module::new(vec![ValueDefinition::new(
+                "x",
+                ConstructorApplication::new(Constructor::new(algebraic_type.clone(), 0), arguments),
+                algebraic_type.clone(),
+            )
+            .into()]);
+
+            assert_eq!(check_types(&module), Err(TypeCheckError));
+        }
+    }
+
+    #[test]
+    fn check_types_of_case_expressions() {
+        let algebraic_type = types::Algebraic::new(vec![
+            types::Constructor::new(vec![types::Value::Number.into()]),
+            types::Constructor::new(vec![]),
+        ]);
+        let module = Module::new(vec![FunctionDefinition::new(
+            "f",
+            vec![],
+            vec![Argument::new("x", algebraic_type.clone())],
+            Case::new(
+                Variable::new("x"),
+                vec![Alternative::new(
+                    Constructor::new(algebraic_type.clone(), 0),
+                    vec!["y".into()],
+                    Variable::new("y"),
+                )],
+                Some(42.0.into()),
+            ),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_case_expressions_with_different_alternative_types() {
+        let algebraic_type = types::Algebraic::new(vec![
+            types::Constructor::new(vec![types::Value::Number.into()]),
+            types::Constructor::new(vec![]),
+        ]);
+        let module = Module::new(vec![FunctionDefinition::new(
+            "f",
+            vec![],
+            vec![Argument::new("x", algebraic_type.clone())],
+            Case::new(
+                Variable::new("x"),
+                vec![Alternative::new(
+                    Constructor::new(algebraic_type.clone(), 0),
+                    vec!["y".into()],
+                    Variable::new("y"),
+                )],
+                Some(true.into()),
+            ),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn fail_to_check_types_of_case_expressions_with_non_algebraic_arguments() {
+        let algebraic_type = types::Algebraic::new(vec![types::Constructor::new(vec![])]);
+        let module = Module::new(vec![ValueDefinition::new(
+            "x",
+            Case::new(
+                42.0,
+                vec![Alternative::new(
+                    Constructor::new(algebraic_type, 0),
+                    vec![],
+                    42.0,
+                )],
+                None,
+            ),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Err(TypeCheckError));
+    }
+
+    #[test]
+    fn check_types_of_recursive_types() {
+        let algebraic_type = types::Algebraic::new(vec![
+            types::Constructor::new(vec![]),
+            types::Constructor::new(vec![
+                types::Value::Number.into(),
+                types::Value::Index(0).into(),
+            ]),
+        ]);
+        let module = Module::new(vec![FunctionDefinition::new(
+            "f",
+            vec![],
+            vec![Argument::new("x", algebraic_type.clone())],
+            Case::new(
+                Variable::new("x"),
+                vec![
+                    Alternative::new(Constructor::new(algebraic_type.clone(), 0), vec![], 0.5),
+                    Alternative::new(
+                        Constructor::new(algebraic_type.clone(), 1),
+                        vec!["y".into(), "z".into()],
+                        Application::new(Variable::new("f"), vec![Variable::new("z").into()]),
+                    ),
+                ],
+                None,
+            ),
+            types::Value::Number,
+        )
+        .into()]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
+
+    #[test]
+    fn check_types_of_mutually_recursive_types() {
+        let a_type = types::Algebraic::new(vec![
+            types::Constructor::new(vec![]),
+            types::Constructor::new(vec![types::Algebraic::new(vec![types::Constructor::new(
+                vec![types::Value::Index(1).into()],
+            )])
+            .into()]),
+        ]);
+        let b_type =
+            types::Algebraic::new(vec![types::Constructor::new(vec![types::Algebraic::new(
+                vec![
+                    types::Constructor::new(vec![]),
+                    types::Constructor::new(vec![types::Value::Index(1).into()]),
+                ],
+            )
+            .into()])]);
+        let module = Module::new(vec![
+            FunctionDefinition::new(
+                "f",
+                vec![],
+                vec![Argument::new("x", a_type.clone())],
+                Case::new(
+                    Variable::new("x"),
+                    vec![Alternative::new(
+                        Constructor::new(a_type.clone(), 1),
+                        vec!["y".into()],
+                        Application::new(Variable::new("g"), vec![Variable::new("y").into()]),
+                    )],
+                    Some(42.0.into()),
+                ),
+                types::Value::Number,
+            )
+            .into(),
+            FunctionDefinition::new(
+                "g",
+                vec![],
+                vec![Argument::new("x", b_type)],
+                42.0,
+                types::Value::Number,
+            )
+            .into(),
+        ]);
+
+        assert_eq!(check_types(&module), Ok(()));
+    }
 }
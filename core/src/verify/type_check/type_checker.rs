@@ -23,10 +23,7 @@ impl TypeChecker {
                     );
                 }
                 Definition::ValueDefinition(value_definition) => {
-                    variables.insert(
-                        value_definition.name(),
-                        value_definition.type_().clone().into(),
-                    );
+                    variables.insert(value_definition.name(), value_definition.type_().clone());
                 }
             }
         }
@@ -60,9 +57,9 @@ impl TypeChecker {
             variables.insert(argument.name(), argument.type_().clone());
         }
 
-        if self.check_expression(function_definition.body(), &variables)?
-            == function_definition.result_type().clone().into()
-        {
+        let type_ = self.check_expression(function_definition.body(), &variables)?;
+
+        if self.equal(&type_, function_definition.result_type()) {
             Ok(())
         } else {
             Err(TypeCheckError)
@@ -74,9 +71,9 @@ impl TypeChecker {
         value_definition: &ValueDefinition,
         variables: &HashMap<&str, Type>,
     ) -> Result<(), TypeCheckError> {
-        if self.check_expression(value_definition.body(), &variables)?
-            == value_definition.type_().clone().into()
-        {
+        let type_ = self.check_expression(value_definition.body(), &variables)?;
+
+        if self.equal(&type_, value_definition.type_()) {
             Ok(())
         } else {
             Err(TypeCheckError)
@@ -90,26 +87,9 @@ impl TypeChecker {
     ) -> Result<Type, TypeCheckError> {
         match expression {
             Expression::Application(application) => {
-                match self.check_variable(application.function(), variables)? {
-                    Type::Function(function_type) => {
-                        if function_type.arguments().len() != application.arguments().len() {
-                            return Err(TypeCheckError);
-                        }
+                let type_ = self.check_expression(application.function(), variables)?;
 
-                        for (argument, expected_type) in application
-                            .arguments()
-                            .iter()
-                            .zip(function_type.arguments())
-                        {
-                            if self.check_expression(argument, variables)? != *expected_type {
-                                return Err(TypeCheckError);
-                            }
-                        }
-
-                        Ok(function_type.result().clone().into())
-                    }
-                    Type::Value(_) => Err(TypeCheckError),
-                }
+                self.check_application(type_, application.arguments(), variables)
             }
             Expression::LetFunctions(let_functions) => {
                 let mut variables = variables.clone();
@@ -129,24 +109,309 @@ impl TypeChecker {
 
                 for definition in let_values.definitions() {
                     self.check_value_definition(definition, &variables)?;
-                    variables.insert(definition.name(), definition.type_().clone().into());
+                    variables.insert(definition.name(), definition.type_().clone());
                 }
 
                 self.check_expression(let_values.expression(), &variables)
             }
+            Expression::Boolean(_) => Ok(types::Value::Boolean.into()),
+            Expression::Case(case) => {
+                let algebraic_type = match self.check_expression(case.argument(), variables)? {
+                    Type::Value(types::Value::Algebraic(algebraic_type)) => algebraic_type,
+                    _ => return Err(TypeCheckError),
+                };
+                let mut types = vec![];
+
+                for alternative in case.alternatives() {
+                    let constructor = alternative.constructor();
+                    let element_types = self.check_constructor(constructor)?;
+
+                    if !self.equal(
+                        &algebraic_type.clone().into(),
+                        &constructor.algebraic_type().clone().into(),
+                    ) || element_types.len() != alternative.element_names().len()
+                    {
+                        return Err(TypeCheckError);
+                    }
+
+                    let mut variables = variables.clone();
+
+                    for (name, type_) in alternative.element_names().iter().zip(element_types) {
+                        variables.insert(name, type_);
+                    }
+
+                    types.push(self.check_expression(alternative.expression(), &variables)?);
+                }
+
+                if let Some(expression) = case.default_alternative() {
+                    types.push(self.check_expression(expression, variables)?);
+                }
+
+                match types.split_first() {
+                    Some((type_, types)) => {
+                        if types
+                            .iter()
+                            .any(|another_type| !self.equal(type_, another_type))
+                        {
+                            Err(TypeCheckError)
+                        } else {
+                            Ok(type_.clone())
+                        }
+                    }
+                    None => Err(TypeCheckError),
+                }
+            }
+            Expression::ConstructorApplication(constructor_application) => {
+                let constructor = constructor_application.constructor();
+                let element_types = self.check_constructor(constructor)?;
+
+                if element_types.len() != constructor_application.arguments().len() {
+                    return Err(TypeCheckError);
+                }
+
+                for (argument, element_type) in constructor_application
+                    .arguments()
+                    .iter()
+                    .zip(&element_types)
+                {
+                    let type_ = self.check_expression(argument, variables)?;
+
+                    if !self.equal(&type_, element_type) {
+                        return Err(TypeCheckError);
+                    }
+                }
+
+                Ok(constructor.algebraic_type().clone().into())
+            }
+            Expression::BuiltInCall(built_in_call) => {
+                let (argument_types, result_type) =
+                    self.check_built_in_function(built_in_call.function());
+
+                if built_in_call.arguments().len() != argument_types.len() {
+                    return Err(TypeCheckError);
+                }
+
+                for (argument, type_) in built_in_call.arguments().iter().zip(argument_types) {
+                    if self.check_expression(argument, variables)? != type_.into() {
+                        return Err(TypeCheckError);
+                    }
+                }
+
+                Ok(result_type.into())
+            }
+            Expression::Conversion(conversion) => {
+                let type_ = self.check_expression(conversion.expression(), variables)?;
+                let converted_type = conversion.type_().clone().into();
+
+                if !match converted_type {
+                    Type::Value(types::Value::Char) => type_ == types::Value::Int.into(),
+                    Type::Value(types::Value::String) => {
+                        self.is_ordered(&type_) || type_ == converted_type
+                    }
+                    _ => self.is_ordered(&type_) && self.is_numeric(&converted_type),
+                } {
+                    return Err(TypeCheckError);
+                }
+
+                Ok(converted_type)
+            }
+            Expression::If(if_) => {
+                if self.check_expression(if_.condition(), variables)?
+                    != types::Value::Boolean.into()
+                {
+                    return Err(TypeCheckError);
+                }
+
+                let type_ = self.check_expression(if_.then(), variables)?;
+
+                let else_type = self.check_expression(if_.else_(), variables)?;
+
+                if !self.equal(&else_type, &type_) {
+                    return Err(TypeCheckError);
+                }
+
+                Ok(type_)
+            }
+            Expression::Char(_) => Ok(types::Value::Char.into()),
+            Expression::Int(_) => Ok(types::Value::Int.into()),
+            Expression::EmptyListFailure(empty_list_failure) => {
+                Ok(empty_list_failure.type_().clone())
+            }
+            Expression::MatchFailure(match_failure) => Ok(match_failure.type_().clone()),
+            Expression::Negation(negation) => {
+                let type_ = self.check_expression(negation.expression(), variables)?;
+
+                if !self.is_numeric(&type_) {
+                    return Err(TypeCheckError);
+                }
+
+                Ok(type_)
+            }
             Expression::Number(_) => Ok(types::Value::Number.into()),
+            Expression::String(_) => Ok(types::Value::String.into()),
             Expression::Operation(operation) => {
-                if self.check_expression(operation.lhs(), variables)? != types::Value::Number.into()
-                {
+                let lhs = self.check_expression(operation.lhs(), variables)?;
+                let rhs = self.check_expression(operation.rhs(), variables)?;
+
+                if lhs != rhs {
                     return Err(TypeCheckError);
                 }
 
-                Ok(types::Value::Number.into())
+                match operation.operator() {
+                    Operator::Add
+                    | Operator::Subtract
+                    | Operator::Multiply
+                    | Operator::Divide
+                    | Operator::Remainder => {
+                        if !self.is_numeric(&lhs) {
+                            return Err(TypeCheckError);
+                        }
+
+                        Ok(lhs)
+                    }
+                    Operator::Power => {
+                        if lhs != types::Value::Number.into() {
+                            return Err(TypeCheckError);
+                        }
+
+                        Ok(lhs)
+                    }
+                    Operator::FloorDivide
+                    | Operator::Modulo
+                    | Operator::BitwiseAnd
+                    | Operator::BitwiseOr
+                    | Operator::BitwiseXor
+                    | Operator::ShiftLeft
+                    | Operator::ShiftRight => {
+                        if lhs != types::Value::Int.into() {
+                            return Err(TypeCheckError);
+                        }
+
+                        Ok(lhs)
+                    }
+                    Operator::Concatenate => {
+                        if lhs != types::Value::String.into() {
+                            return Err(TypeCheckError);
+                        }
+
+                        Ok(lhs)
+                    }
+                    Operator::Equal | Operator::NotEqual => match lhs {
+                        Type::Value(types::Value::Boolean) | Type::Value(types::Value::String) => {
+                            Ok(types::Value::Boolean.into())
+                        }
+                        _ if self.is_ordered(&lhs) => Ok(types::Value::Boolean.into()),
+                        _ => Err(TypeCheckError),
+                    },
+                    Operator::LessThan
+                    | Operator::LessThanOrEqual
+                    | Operator::GreaterThan
+                    | Operator::GreaterThanOrEqual => {
+                        if !self.is_ordered(&lhs) {
+                            return Err(TypeCheckError);
+                        }
+
+                        Ok(types::Value::Boolean.into())
+                    }
+                }
+            }
+            Expression::Record(record) => {
+                if record.type_().elements().len() != record.elements().len() {
+                    return Err(TypeCheckError);
+                }
+
+                for (element, element_type) in
+                    record.elements().iter().zip(record.type_().elements())
+                {
+                    let type_ = self.check_expression(element, variables)?;
+
+                    if !self.equal(&type_, element_type) {
+                        return Err(TypeCheckError);
+                    }
+                }
+
+                Ok(record.type_().clone().into())
+            }
+            Expression::RecordElement(record_element) => {
+                match self.check_expression(record_element.record(), variables)? {
+                    Type::Value(types::Value::Record(record)) => record
+                        .elements()
+                        .get(record_element.index())
+                        .cloned()
+                        .ok_or(TypeCheckError),
+                    _ => Err(TypeCheckError),
+                }
+            }
+            Expression::Tuple(tuple) => Ok(types::Tuple::new(
+                tuple
+                    .elements()
+                    .iter()
+                    .map(|element| self.check_expression(element, variables))
+                    .collect::<Result<_, _>>()?,
+            )
+            .into()),
+            Expression::TupleElement(tuple_element) => {
+                match self.check_expression(tuple_element.tuple(), variables)? {
+                    Type::Value(types::Value::Tuple(tuple)) => tuple
+                        .elements()
+                        .get(tuple_element.index())
+                        .cloned()
+                        .ok_or(TypeCheckError),
+                    _ => Err(TypeCheckError),
+                }
             }
             Expression::Variable(variable) => self.check_variable(variable, variables),
         }
     }
 
+    fn check_application(
+        &mut self,
+        type_: Type,
+        arguments: &[Expression],
+        variables: &HashMap<&str, Type>,
+    ) -> Result<Type, TypeCheckError> {
+        match type_ {
+            Type::Function(function_type) => {
+                let arity = function_type.arguments().len();
+
+                for (argument, expected_type) in arguments.iter().zip(function_type.arguments()) {
+                    let type_ = self.check_expression(argument, variables)?;
+
+                    if !self.equal(&type_, expected_type) {
+                        return Err(TypeCheckError);
+                    }
+                }
+
+                if arguments.len() < arity {
+                    Ok(types::Function::new(
+                        function_type.arguments()[arguments.len()..].to_vec(),
+                        function_type.result().clone(),
+                    )
+                    .into())
+                } else if arguments.len() == arity {
+                    Ok(function_type.result().clone())
+                } else {
+                    self.check_application(
+                        function_type.result().clone(),
+                        &arguments[arity..],
+                        variables,
+                    )
+                }
+            }
+            Type::Value(_) => Err(TypeCheckError),
+        }
+    }
+
+    fn check_constructor(&self, constructor: &Constructor) -> Result<Vec<Type>, TypeCheckError> {
+        constructor
+            .algebraic_type()
+            .unfold()
+            .constructors()
+            .get(constructor.index())
+            .map(|constructor| constructor.elements().to_vec())
+            .ok_or(TypeCheckError)
+    }
+
     fn check_variable(
         &self,
         variable: &Variable,
@@ -157,4 +422,103 @@ impl TypeChecker {
             .cloned()
             .ok_or(TypeCheckError)
     }
+
+    fn check_built_in_function(
+        &self,
+        function: BuiltInFunction,
+    ) -> (Vec<types::Value>, types::Value) {
+        match function {
+            BuiltInFunction::Absolute
+            | BuiltInFunction::Ceiling
+            | BuiltInFunction::Cosine
+            | BuiltInFunction::Exponential
+            | BuiltInFunction::Floor
+            | BuiltInFunction::Logarithm
+            | BuiltInFunction::Sine
+            | BuiltInFunction::SquareRoot => (vec![types::Value::Number], types::Value::Number),
+            BuiltInFunction::PopCount => (vec![types::Value::Int], types::Value::Int),
+            BuiltInFunction::StringLength => (vec![types::Value::String], types::Value::Int),
+            BuiltInFunction::StringSlice => (
+                vec![types::Value::String, types::Value::Int, types::Value::Int],
+                types::Value::String,
+            ),
+        }
+    }
+
+    fn is_numeric(&self, type_: &Type) -> bool {
+        matches!(
+            type_,
+            Type::Value(types::Value::Int) | Type::Value(types::Value::Number)
+        )
+    }
+
+    fn is_ordered(&self, type_: &Type) -> bool {
+        self.is_numeric(type_) || type_ == &types::Value::Char.into()
+    }
+
+    fn equal(&self, one: &Type, other: &Type) -> bool {
+        self.equal_types(one, other, &mut vec![])
+    }
+
+    fn equal_types(
+        &self,
+        one: &Type,
+        other: &Type,
+        assumptions: &mut Vec<(types::Algebraic, types::Algebraic)>,
+    ) -> bool {
+        match (one, other) {
+            (Type::Function(one), Type::Function(other)) => {
+                one.arguments().len() == other.arguments().len()
+                    && one
+                        .arguments()
+                        .iter()
+                        .zip(other.arguments())
+                        .all(|(one, other)| self.equal_types(one, other, assumptions))
+                    && self.equal_types(one.result(), other.result(), assumptions)
+            }
+            (
+                Type::Value(types::Value::Algebraic(one)),
+                Type::Value(types::Value::Algebraic(other)),
+            ) => {
+                if one == other || assumptions.contains(&(one.clone(), other.clone())) {
+                    return true;
+                }
+
+                assumptions.push((one.clone(), other.clone()));
+
+                let (one, other) = (one.unfold(), other.unfold());
+
+                one.constructors().len() == other.constructors().len()
+                    && one
+                        .constructors()
+                        .iter()
+                        .zip(other.constructors())
+                        .all(|(one, other)| {
+                            one.elements().len() == other.elements().len()
+                                && one
+                                    .elements()
+                                    .iter()
+                                    .zip(other.elements())
+                                    .all(|(one, other)| self.equal_types(one, other, assumptions))
+                        })
+            }
+            (Type::Value(types::Value::Record(one)), Type::Value(types::Value::Record(other))) => {
+                one.elements().len() == other.elements().len()
+                    && one
+                        .elements()
+                        .iter()
+                        .zip(other.elements())
+                        .all(|(one, other)| self.equal_types(one, other, assumptions))
+            }
+            (Type::Value(types::Value::Tuple(one)), Type::Value(types::Value::Tuple(other))) => {
+                one.elements().len() == other.elements().len()
+                    && one
+                        .elements()
+                        .iter()
+                        .zip(other.elements())
+                        .all(|(one, other)| self.equal_types(one, other, assumptions))
+            }
+            _ => one == other,
+        }
+    }
 }
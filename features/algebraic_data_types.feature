@@ -0,0 +1,113 @@
+Feature: Algebraic data types
+  Scenario: Match constructors in case expressions
+    Given a file named "main.sl" with:
+    """
+    data Shape = Circle Number | Rect Number Number
+
+    area : Shape -> Number
+    area s = case s of
+      Circle r -> 3 * r * r
+      Rect w h -> w * h
+
+    main : Number -> Number
+    main x = area (Rect 6 7)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Match nested patterns of recursive types
+    Given a file named "main.sl" with:
+    """
+    data List = Cons Number List | Nil
+
+    sum : List -> Number
+    sum l = case l of
+      Cons x Nil -> x
+      Cons 1 xs -> sum xs
+      Cons x (Cons y ys) -> x + sum (Cons y ys)
+      Nil -> 1
+
+    main : Number -> Number
+    main x = sum (Cons 1 (Cons 2 (Cons 4 (Cons 8 Nil))))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "14"
+    And the exit status should be 0
+
+  Scenario: Use constructors as functions
+    Given a file named "main.sl" with:
+    """
+    data Shape = Circle Number | Rect Number Number
+
+    apply : (Number -> Shape) -> Number -> Shape
+    apply f x = f x
+
+    width : Shape -> Number
+    width s = case s of
+      Circle r -> 2 * r
+      Rect w _ -> w
+
+    main : Number -> Number
+    main x = width (apply Circle 3) + width (apply (Rect 6) 7)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "12"
+    And the exit status should be 0
+
+  Scenario: Fail to build non-exhaustive case expressions
+    Given a file named "main.sl" with:
+    """
+    data Shape = Circle Number | Rect Number Number
+
+    area : Shape -> Number
+    area s = case s of
+      Circle r -> 3 * r * r
+
+    main : Number -> Number
+    main x = area (Circle 1)
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "missing Rect _ _"
+    And the exit status should not be 0
+
+  Scenario: Fail to build redundant case expressions
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = case x < 1 of
+      True -> 1
+      False -> 2
+      _ -> 3
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "Pattern is redundant"
+    And the exit status should not be 0
+
+  Scenario: Fail to build equality of algebraic data types
+    Given a file named "main.sl" with:
+    """
+    data Nat = Zero | Succ Nat
+
+    main : Number -> Number
+    main x = if Zero == Zero then 1 else 2
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "Type must be Boolean, Char, Int, Number or String"
+    And the exit status should not be 0
+
+  Scenario: Fail to build duplicate constructors
+    Given a file named "main.sl" with:
+    """
+    data Foo = A Number
+    data Bar = A Boolean
+
+    main : Number -> Number
+    main x = 42
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "is defined more than once"
+    And the exit status should not be 0
@@ -0,0 +1,46 @@
+Feature: Bitwise operations
+  Scenario: Combine flags with bitwise operators
+    Given a file named "main.sl" with:
+    """
+    mask : Int -> Int
+    mask = (.&. 0xff)
+
+    main : Number -> Number
+    main x = toNumber (mask (0x1200 .|. 0b100000) + xor 12 6)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Shift integers
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = toNumber (shiftL 21 1 + shiftR (-8) 2 + shiftR 16 67)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Count set bits
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = toNumber (popCount 0b101010 + popCount (-1) - 64 + 39)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to use bitwise operators on numbers
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = x .&. 1
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError: Types do not match"
+    And the exit status should not be 0
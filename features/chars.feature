@@ -0,0 +1,56 @@
+Feature: Chars
+  Scenario: Return a char from a main function
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Char
+    main x = 'a'
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "a"
+    And the exit status should be 0
+
+  Scenario: Interpolate chars with escape sequences
+    Given a file named "main.sl" with:
+    """
+    main x = toString '\u{1F9A5}' ++ "${'\\'}${'\''}"
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "🦥\'"
+    And the exit status should be 0
+
+  Scenario: Compare chars
+    Given a file named "main.sl" with:
+    """
+    max : Char -> Char -> Char
+    max x y = if x < y then y else x
+
+    main x = if 'a' == 'a' && 'b' /= 'c' then max 'x' 'y' else 'n'
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "y"
+    And the exit status should be 0
+
+  Scenario: Convert chars from and to code points
+    Given a file named "main.sl" with:
+    """
+    next : Char -> Char
+    next c = fromCodePoint (toCodePoint c + 1)
+
+    main x = "${toCodePoint 'A'} ${next 'a'}${next 'y'}"
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "65 bz"
+    And the exit status should be 0
+
+  Scenario: Fail to add chars
+    Given a file named "main.sl" with:
+    """
+    main x = 'a' + 'b'
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError"
+    And the exit status should not be 0
@@ -0,0 +1,82 @@
+Feature: Function clauses
+  Scenario: Define a function with multiple clauses
+    Given a file named "main.sl" with:
+    """
+    data List = Nil | Cons Number List
+
+    sum : List -> Number
+    sum Nil = 0
+    sum (Cons x xs) = x + sum xs
+
+    factorial : Number -> Number
+    factorial 0 = 1
+    factorial n = n * factorial (n - 1)
+
+    main : Number -> Number
+    main x = sum (Cons (factorial 3) (Cons 36 Nil))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Use guards in clauses
+    Given a file named "main.sl" with:
+    """
+    clamp : Number -> Number -> Number
+    clamp 0 x = 0
+    clamp m x
+      | x < 0 = 0
+      | x > limit = limit
+      | otherwise = x
+      where
+        limit = m
+
+    main : Number -> Number
+    main x = clamp 42 (x * 2) + clamp 0 x + clamp 42 (0 - x)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Warn about missing and overlapped clauses
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f 0 = 1
+    f 0 = 2
+
+    main : Number -> Number
+    main x = f x
+    """
+    When I successfully run `builder`
+    Then stderr from "builder" should contain "PatternCheckWarning: Clause is overlapped by previous clauses"
+    And stderr from "builder" should contain "PatternCheckWarning: Clauses are not exhaustive; missing _"
+
+  Scenario: Warn about unreachable guards
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f x | x > 0 = 1 | otherwise = 2 | x < 0 = 3
+
+    main : Number -> Number
+    main x = f x
+    """
+    When I successfully run `builder`
+    Then stderr from "builder" should contain "PatternCheckWarning: Guards after otherwise are unreachable"
+
+  Scenario: Fail when no clause matches
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f x
+      | x < 0 = x
+
+    main : Number -> Number
+    main x = f x
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stderr from "sh -c ./a.out" should contain "MatchError: No clause matched arguments of a function"
+    And the exit status should not be 0
@@ -0,0 +1,33 @@
+Feature: Comments
+  Scenario: Write line comments
+    Given a file named "main.sl" with:
+    """
+    # The entry point
+    main : Number -> Number # A signature
+    main x = x # The answer
+    # The end
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Write nested block comments
+    Given a file named "main.sl" with:
+    """
+    #| The entry point
+       #| is nested |#
+    |#
+    main : Number -> Number
+    main x = (
+      let
+        # The answer
+        y = x #| (unbalanced |#
+      in
+        y
+    )
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
@@ -0,0 +1,53 @@
+Feature: Conditionals
+  Scenario: Use an if expression
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = if x == 42 then 1 else 2
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "1"
+    And the exit status should be 0
+
+  Scenario: Use boolean operators
+    Given a file named "main.sl" with:
+    """
+    f : Boolean -> Boolean -> Number
+    f x y = if x && y || False then 1 else 2
+
+    main : Number -> Number
+    main x = f (x >= 42) (x /= 13)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "1"
+    And the exit status should be 0
+
+  Scenario: Use the Bool type name
+    Given a file named "main.sl" with:
+    """
+    f : Bool -> Number
+    f x = if x then 1 else 2
+
+    main : Number -> Number
+    main x = f (x < 42)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "2"
+    And the exit status should be 0
+
+  Scenario: Define a recursive function
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f x = if x < 1 then 1 else x * f (x - 1)
+
+    main : Number -> Number
+    main x = f 4
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "24"
+    And the exit status should be 0
@@ -18,3 +18,81 @@ Feature: Expressions
     When I run `sh -c ./a.out`
     Then stdout from "sh -c ./a.out" should contain exactly "42"
     And the exit status should be 0
+
+  Scenario: Pass a lambda expression to a function
+    Given a file named "main.sl" with:
+    """
+    apply : (Number -> Number) -> Number -> Number
+    apply f x = f x
+
+    main : Number -> Number
+    main x = apply (\y -> y + x) 1
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "43"
+    And the exit status should be 0
+
+  Scenario: Apply a lambda expression capturing global functions
+    Given a file named "main.sl" with:
+    """
+    double : Number -> Number
+    double x = 2 * x
+
+    main : Number -> Number
+    main x = (\a b -> double a + b) x 1
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "85"
+    And the exit status should be 0
+
+  Scenario: Use number literals
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = 0x10 + 0b10 + 1_0 + 1e1 + 0.5 * 8 + 0 * 100
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to build an out-of-range number literal
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = 1e400
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "Number literal is out of range"
+    And the exit status should not be 0
+
+  Scenario: Use left-associative operators
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = 100 - 50 - 8 + 168 / 4 / 2 - 21
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Negate expressions
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f x = -x
+
+    main : Number -> Number
+    main x = (
+      case -x of
+        -42 -> -f 40 - -2
+        _ -> 13
+    )
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
@@ -70,3 +70,131 @@ Feature: Functions
     When I run `sh -c ./a.out`
     Then stdout from "sh -c ./a.out" should contain exactly "42"
     And the exit status should be 0
+
+  Scenario: Define a global closure with a partial application
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number -> Number
+    f x y = x + y
+
+    g : Number -> Number
+    g = f 21
+
+    main : Number -> Number
+    main x = g (x - 21)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Define a global closure with a conditional
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f x = x
+
+    g : Number -> Number
+    g x = x + 1
+
+    h : Number -> Number
+    h = if True then f else g
+
+    main : Number -> Number
+    main x = h x
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Apply a function returned by a conditional
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number
+    f x = x
+
+    g : Number -> Number
+    g x = x + 1
+
+    main : Number -> Number
+    main x = (if x > 1 then f else g) x
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Pass a partially applied function
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number -> Number -> Number
+    f x y z = x + y + z
+
+    apply : (Number -> Number) -> Number -> Number
+    apply g x = g x
+
+    main : Number -> Number
+    main x = apply (f 1 1) (x - 2)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Partially apply a partially applied function
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number -> Number -> Number
+    f x y z = x + y + z
+
+    apply : (Number -> Number) -> Number -> Number
+    apply g x = g x
+
+    main : Number -> Number
+    main x = (
+      let
+        g = f 1
+      in
+        apply (g 1) (x - 2)
+    )
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Define local functions in where blocks
+    Given a file named "main.sl" with:
+    """
+    f : Number -> Number -> Number
+    f x y = g y + z
+      where
+        g a = a + x
+        z = id 2
+        id b = b
+
+    main : Number -> Number
+    main x = f 10 x - y where y = 12
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Define local values in where blocks
+    Given a file named "main.sl" with:
+    """
+    x : Number
+    x = y * 2
+      where
+        y : Number
+        y = 21
+
+    main : Number -> Number
+    main y = x
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
@@ -0,0 +1,100 @@
+Feature: Integers
+  Scenario: Use integer arithmetic
+    Given a file named "main.sl" with:
+    """
+    count : Int -> Int -> Int
+    count 0 acc = acc
+    count n acc = count (n - 1) (acc + 2)
+
+    main : Number -> Number
+    main x = toNumber (count 21 0)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Divide integers with flooring
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = toNumber (div 7 2 * 10 + div (-7) 2 + mod (-7) 2 * 10 + mod 7 (-2))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "35"
+    And the exit status should be 0
+
+  Scenario: Convert integers and numbers
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = toNumber (toInt (x / 4)) + toNumber (toInt (-2.5)) + 0.5
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "8.5"
+    And the exit status should be 0
+
+  Scenario: Convert special and out-of-range numbers to integers
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = toNumber (toInt (0 / 0) + toInt (1 / 0) + toInt (-1e300) + 1)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "0"
+    And the exit status should be 0
+
+  Scenario: Keep large integer literals exact
+    Given a file named "main.sl" with:
+    """
+    n : Int
+    n = 9007199254740993
+
+    main : Number -> String
+    main x = toString n
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "9007199254740993"
+    And the exit status should be 0
+
+  Scenario: Fail to divide integers by zero
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = toNumber (div 1 (toInt x - 42))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stderr from "sh -c ./a.out" should contain "ArithmeticError: Division by zero"
+    And the exit status should not be 0
+
+  Scenario: Infer types of number literals
+    Given a file named "main.sl" with:
+    """
+    half : Int -> Int
+    half n = div n 2
+
+    main : Number -> Number
+    main x = toNumber (half 85 - 1) + 1 + 0 * x
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to mix integers and numbers
+    Given a file named "main.sl" with:
+    """
+    n : Int
+    n = 42
+
+    main : Number -> Number
+    main x = x + n
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError: Types do not match"
+    And the exit status should not be 0
@@ -0,0 +1,62 @@
+Feature: Layout
+  Scenario: Continue definitions on indented lines
+    Given a file named "main.sl" with:
+    """
+    data Shape = Circle Number
+      | Rect Number Number
+
+    area : Shape -> Number
+    area s =
+      case s of
+        Circle r -> 3 * r * r
+        Rect w h ->
+          w
+            * h
+
+    main : Number -> Number
+    main x =
+      if x > 0
+        then area (Rect 6 7)
+        else 0
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Use nested blocks
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x =
+      let
+        f y = case y of
+          0 -> 1
+          _ -> y
+        g =
+          let
+            z = 2
+          in
+            z
+      in
+        f x - g
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "40"
+    And the exit status should be 0
+
+  Scenario: Fail to build misindented definitions
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x =
+      let
+        y = x
+       z = 2
+      in
+        y + z
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "main.sl:5:4:"
+    And the exit status should not be 0
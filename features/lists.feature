@@ -0,0 +1,92 @@
+Feature: Lists
+  Scenario: Sum elements of a list literal
+    Given a file named "main.sl" with:
+    """
+    sum : List Number -> Number
+    sum xs = if isEmpty xs then 0 else head xs + sum (tail xs)
+
+    main x = sum [1, 2, 3]
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "6"
+    And the exit status should be 0
+
+  Scenario: Build lists with a cons operator
+    Given a file named "main.sl" with:
+    """
+    range : Int -> List Int
+    range n = if n == 0 then [] else n :: range (n - 1)
+
+    main x = length (range 10)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "10"
+    And the exit status should be 0
+
+  Scenario: Match lists with patterns
+    Given a file named "main.sl" with:
+    """
+    second : List Number -> Number
+    second [] = 0
+    second [_] = 0
+    second (_ :: y :: _) = y
+
+    main x = second [
+      1,
+      2,
+      3
+    ]
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "2"
+    And the exit status should be 0
+
+  Scenario: Map lists with polymorphic functions
+    Given a file named "main.sl" with:
+    """
+    map : (a -> b) -> List a -> List b
+    map f [] = []
+    map f (x :: xs) = f x :: map f xs
+
+    main x = head (tail (map (\c -> toCodePoint c) ['a', 'b']))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "98"
+    And the exit status should be 0
+
+  Scenario: Use empty list literals
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = if isEmpty [] then toNumber (length []) + x else 0
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to get a head of an empty list
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = head (tail [x])
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stderr from "sh -c ./a.out" should contain "ListError: Head or tail of an empty list"
+    And the exit status should not be 0
+
+  Scenario: Fail to get a tail of an empty list
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = head (tail (tail [x]))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stderr from "sh -c ./a.out" should contain "ListError: Head or tail of an empty list"
+    And the exit status should not be 0
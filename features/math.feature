@@ -0,0 +1,74 @@
+Feature: Math
+  Scenario: Use remainders and powers
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = 2 ** 3 ** 2 % 100 + 7.5 % 2 - toNumber (7 % (-3)) * 0.5
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "13"
+    And the exit status should be 0
+
+  Scenario: Negate powers
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = -x ** 2 / 42 + 100
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "58"
+    And the exit status should be 0
+
+  Scenario: Use math functions
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = sqrt 1764 + floor (-0.5) + ceil 0.5 + abs (-1) - exp 0 + log 1 + sin 0 + cos 0 - 1
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Pass math functions as arguments
+    Given a file named "main.sl" with:
+    """
+    twice : (Number -> Number) -> Number -> Number
+    twice f x = f (f x)
+
+    main : Number -> Number
+    main x = twice sqrt 3111696 + twice floor 0.5
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Define functions named like C library functions
+    Given a file named "main.sl" with:
+    """
+    free : Number -> Number
+    free x = x
+
+    main : Number -> Number
+    main x = free (floor (x + 0.5))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to raise integers to powers
+    Given a file named "main.sl" with:
+    """
+    n : Int
+    n = 2 ** 3
+
+    main : Number -> Number
+    main x = x
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError: Types do not match"
+    And the exit status should not be 0
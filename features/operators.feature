@@ -0,0 +1,104 @@
+Feature: Operators
+  Scenario: Define infix operators
+    Given a file named "main.sl" with:
+    """
+    infixl 1 |>
+    infixr 5 <>
+
+    (|>) : a -> (a -> b) -> b
+    x |> f = f x
+
+    (<>) : Number -> Number -> Number
+    (<>) x y = x * 10 + y
+
+    main : Number -> Number
+    main x = 1 <> 2 <> 1 - 200 |> (\y -> y + 171) |> (<>) 4
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Use infix operators without fixity declarations
+    Given a file named "main.sl" with:
+    """
+    x <+> y = x + y
+
+    main : Number -> Number
+    main x = 2 * 1 <+> 20
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to mix operators with conflicting fixities
+    Given a file named "main.sl" with:
+    """
+    infixr 5 <>
+
+    x <> y = x * 10 + y
+
+    main : Number -> Number
+    main x = 1 <> 2 * 3
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "Fixities of operators conflict"
+    And the exit status should not be 0
+
+  Scenario: Fail to change fixities of built-in operators
+    Given a file named "main.sl" with:
+    """
+    infixl 6 +
+
+    main : Number -> Number
+    main x = 1 + 2 * 3
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "Fixity of built-in operator cannot be changed"
+    And the exit status should not be 0
+
+  Scenario: Fail to declare fixities after use
+    Given a file named "main.sl" with:
+    """
+    x <> y = x - y
+
+    main : Number -> Number
+    main x = 10 <> 4 <> 3
+
+    infixr 5 <>
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "Fixity of operator is declared after its use"
+    And the exit status should not be 0
+
+  Scenario: Pass operators as functions
+    Given a file named "main.sl" with:
+    """
+    apply : (Number -> Number -> Number) -> Number
+    apply f = f 21 2
+
+    add : Number -> Number -> Number
+    add = (+)
+
+    main : Number -> Number
+    main x = apply add + apply (-)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Use operator sections
+    Given a file named "main.sl" with:
+    """
+    twice : (a -> a) -> a -> a
+    twice f x = f (f x)
+
+    main : Number -> Number
+    main x = if (x ==) 42 && (> 1) x then twice (2 *) 11 - twice (/ 2) 8 else 13
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
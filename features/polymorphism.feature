@@ -0,0 +1,129 @@
+Feature: Polymorphism
+  Scenario: Use polymorphic functions
+    Given a file named "main.sl" with:
+    """
+    id : a -> a
+    id x = x
+
+    twice : (a -> a) -> a -> a
+    twice f x = f (f x)
+
+    inc : Number -> Number
+    inc x = x + 1
+
+    main : Number -> Number
+    main x = if id True then twice inc (id x) else 13
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "44"
+    And the exit status should be 0
+
+  Scenario: Use polymorphic let-functions
+    Given a file named "main.sl" with:
+    """
+    choose : Boolean -> a -> a -> a
+    choose b x y = (
+      let
+        pick z = if b then x else z
+      in
+        pick y
+    )
+
+    main : Number -> Number
+    main x = (
+      let
+        f y = y
+      in
+        if choose False (f False) True then choose True (f x) 1 else 2
+    )
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to define a polymorphic function with a concrete body
+    Given a file named "main.sl" with:
+    """
+    f : a -> a
+    f x = 42
+
+    main : Number -> Number
+    main x = f x
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError"
+    And the exit status should not be 0
+
+  Scenario: Use definitions without type signatures
+    Given a file named "main.sl" with:
+    """
+    id x = x
+
+    twice f x = f (f x)
+
+    y = twice (\z -> z + 1) (id 13)
+
+    main : Number -> Number
+    main x = if id True then y + 27 else x
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Use a main function without a type signature
+    Given a file named "main.sl" with:
+    """
+    main x = 42
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to build an ambiguous definition without a type signature
+    Given a file named "main.sl" with:
+    """
+    id x = x
+
+    y = id
+
+    main : Number -> Number
+    main x = x
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "Type is ambiguous"
+    And the exit status should not be 0
+
+  Scenario: Fail to build polymorphic recursion with growing types
+    Given a file named "main.sl" with:
+    """
+    f : a -> Number
+    f x = if True then 0 else f (x, x)
+
+    main : Number -> Number
+    main x = f x
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "MonomorphizationError: Polymorphic recursion"
+    And the exit status should not be 0
+
+  Scenario: Use polymorphic lambdas without type signatures
+    Given a file named "main.sl" with:
+    """
+    id = \x -> x
+
+    main : Number -> Number
+    main x = (
+      let
+        const = \y z -> y
+      in
+        if id True then const (id x) False else 13
+    )
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
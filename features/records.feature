@@ -0,0 +1,114 @@
+Feature: Records
+  Scenario: Access elements of records
+    Given a file named "main.sl" with:
+    """
+    type Point = { x : Number, y : Number }
+
+    origin : Point
+    origin = { x = 3, y = 4 }
+
+    main x = origin.x * 10 + origin.y
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "34"
+    And the exit status should be 0
+
+  Scenario: Update records
+    Given a file named "main.sl" with:
+    """
+    type Point = { x : Number, y : Number }
+
+    move : Number -> Point -> Point
+    move d p = { p | x = p.x + d }
+
+    main x =
+      let p = move 2 {
+            x = 1,
+            y = 5
+          }
+       in p.x * 10 + p.y
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "35"
+    And the exit status should be 0
+
+  Scenario: Store records in data constructors
+    Given a file named "main.sl" with:
+    """
+    data Tree = Leaf | Node { left : Tree, value : Number, right : Tree }
+
+    sum : Tree -> Number
+    sum Leaf = 0
+    sum (Node node) = sum node.left + node.value + sum node.right
+
+    main x = sum (Node { left = Node { left = Leaf, value = 1, right = Leaf }, value = 2, right = Leaf })
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "3"
+    And the exit status should be 0
+
+  Scenario: Use records with polymorphic functions
+    Given a file named "main.sl" with:
+    """
+    first : { first : a, second : b } -> a
+    first p = p.first
+
+    main x = if first { first = True, second = 1 } then first { first = 42, second = False } else 0
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to access missing elements of records
+    Given a file named "main.sl" with:
+    """
+    main x = { x = 1 }.y
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError: Record element"
+    And the exit status should not be 0
+
+  Scenario: Fail to use records with different elements
+    Given a file named "main.sl" with:
+    """
+    type Point = { x : Number, y : Number }
+
+    origin : Point
+    origin = { x = 0 }
+
+    main x = origin.x
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError: Types do not match"
+    And the exit status should not be 0
+
+  Scenario: Use type aliases before their declarations
+    Given a file named "main.sl" with:
+    """
+    origin : Point
+    origin = { x = 3, y = 4 }
+
+    type Point = { x : Coordinate, y : Coordinate }
+    type Coordinate = Number
+
+    main x = origin.x * 10 + origin.y
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "34"
+    And the exit status should be 0
+
+  Scenario: Fail to build circular type aliases
+    Given a file named "main.sl" with:
+    """
+    type Node = { value : Number, next : Node }
+
+    main x = x
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "is circular"
+    And the exit status should not be 0
@@ -0,0 +1,75 @@
+Feature: Strings
+  Scenario: Return a string from a main function
+    Given a file named "main.sl" with:
+    """
+    main : Number -> String
+    main x = "Hello, world!"
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "Hello, world!"
+    And the exit status should be 0
+
+  Scenario: Concatenate strings
+    Given a file named "main.sl" with:
+    """
+    greet : String -> String
+    greet name = "Hello, " ++ name ++ "!"
+
+    main x = greet "sloth" ++ " \u{1F9A5} \$"
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "Hello, sloth! 🦥 $"
+    And the exit status should be 0
+
+  Scenario: Measure and slice strings
+    Given a file named "main.sl" with:
+    """
+    main x =
+      let
+        s = "héllo wörld"
+      in
+        toString (stringLength s) ++ " " ++ toString (stringLength "\"\t\\") ++ " "
+          ++ stringSlice s 1 4 ++ stringSlice s 7 100
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "11 3 éllörld"
+    And the exit status should be 0
+
+  Scenario: Interpolate values into strings
+    Given a file named "main.sl" with:
+    """
+    main x =
+      let
+        n = 7
+        s = "sloth"
+      in
+        "x = ${x}, n = ${n * 6}, y = ${x / 8}, s = ${s}"
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "x = 42, n = 42, y = 5.25, s = sloth"
+    And the exit status should be 0
+
+  Scenario: Compare strings
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = if "foo" ++ "bar" == "foobar" && "foo" /= "bar" then 42 else 13
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Convert numbers to strings
+    Given a file named "main.sl" with:
+    """
+    main x = toString 42 ++ " " ++ toString 4.2 ++ " " ++ toString (toInt x)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42 4.2 42"
+    And the exit status should be 0
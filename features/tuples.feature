@@ -0,0 +1,97 @@
+Feature: Tuples
+  Scenario: Return multiple values from a function
+    Given a file named "main.sl" with:
+    """
+    divMod : Int -> Int -> (Int, Int)
+    divMod x y = (div x y, mod x y)
+
+    main x = case divMod 17 5 of
+      (q, r) -> toNumber (q * 10 + r)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "32"
+    And the exit status should be 0
+
+  Scenario: Destructure tuples in let expressions
+    Given a file named "main.sl" with:
+    """
+    swap : (a, b) -> (b, a)
+    swap (x, y) = (y, x)
+
+    main x =
+      let (a, b) = swap (1, 2)
+          c = a * 10
+       in c + b
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "21"
+    And the exit status should be 0
+
+  Scenario: Destructure tuples in any order of let definitions
+    Given a file named "main.sl" with:
+    """
+    main x =
+      let (a, b) = (c, 1)
+          c = 5
+          d = e + 1
+          (e, f) = (a * 10, b)
+       in d + f
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "52"
+    And the exit status should be 0
+
+  Scenario: Match tuples with patterns in clauses
+    Given a file named "main.sl" with:
+    """
+    and : (Boolean, Boolean) -> Number
+    and (True, True) = 1
+    and (_, _) = 0
+
+    main x = and (True, True) + and (True, False)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "1"
+    And the exit status should be 0
+
+  Scenario: Store tuples in data constructors
+    Given a file named "main.sl" with:
+    """
+    data Segment = Segment (Number, Number) (Number, Number)
+
+    length : Segment -> Number
+    length (Segment (x1, y1) (x2, y2)) = x2 - x1 + y2 - y1
+
+    main x = length (Segment (1, 2) (4, 6))
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "7"
+    And the exit status should be 0
+
+  Scenario: Warn about non-exhaustive tuple patterns
+    Given a file named "main.sl" with:
+    """
+    f : (Boolean, Number) -> Number
+    f (True, y) = y
+
+    main x = f (True, x)
+    """
+    When I successfully run `builder`
+    Then stderr from "builder" should contain "PatternCheckWarning: Clauses are not exhaustive; missing (False, _)"
+
+  Scenario: Fail to use tuples of different lengths
+    Given a file named "main.sl" with:
+    """
+    pair : (Number, Number)
+    pair = (1, 2, 3)
+
+    main x = x
+    """
+    When I run `builder`
+    Then stderr from "builder" should contain "TypeInferenceError: Types do not match"
+    And the exit status should not be 0
@@ -46,6 +46,19 @@ Feature: Variables
     Then stdout from "sh -c ./a.out" should contain exactly "42"
     And the exit status should be 0
 
+  Scenario: Use let-values expression with unindented definitions in parentheses
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = (let y = 40
+    z = 2
+    in y + z)
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
   Scenario: Use nested let-values expression
     Given a file named "main.sl" with:
     """
@@ -116,3 +129,68 @@ Feature: Variables
     When I run `sh -c ./a.out`
     Then stdout from "sh -c ./a.out" should contain exactly "42"
     And the exit status should be 0
+
+  Scenario: Mix value and function definitions in a let expression
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = (
+      let
+        z = f y
+        f a = a + y
+        y = x / 2
+      in
+        z
+    )
+    """
+    And I successfully run `builder`
+    When I run `sh -c ./a.out`
+    Then stdout from "sh -c ./a.out" should contain exactly "42"
+    And the exit status should be 0
+
+  Scenario: Fail to build circular initialization in a let expression
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x = (
+      let
+        f a = a + y
+        y = f x
+      in
+        y
+    )
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "Initialization of value is circular"
+    And the exit status should not be 0
+
+  Scenario: Fail to build circular initialization of values in a let expression
+    Given a file named "main.sl" with:
+    """
+    main : Number -> Number
+    main x =
+      let
+        a = b + 1
+        b = a + 1
+      in
+        a
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "Initialization of value is circular"
+    And the exit status should not be 0
+
+  Scenario: Fail to build duplicate global variables
+    Given a file named "main.sl" with:
+    """
+    y : Number
+    y = 42
+
+    y : Number
+    y = 13
+
+    main : Number -> Number
+    main x = y
+    """
+    And I run `builder`
+    Then stderr from "builder" should contain "is defined more than once"
+    And the exit status should not be 0
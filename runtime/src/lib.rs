@@ -1,15 +1,39 @@
 #![cfg(not(test))]
 
+mod string;
+
+use string::SlothString;
+
 extern "C" {
     fn sloth_init();
-    static sloth_main: extern "C" fn(environment: *const u8, argument: f64) -> f64;
+    static sloth_main: extern "C" fn(environment: *const u8, argument: f64) -> *const SlothString;
 }
 
 #[no_mangle]
 pub extern "C" fn main() -> std::os::raw::c_int {
     unsafe { sloth_init() }
 
-    println!("{}", unsafe { sloth_main(std::ptr::null(), 42.0) });
+    println!("{}", unsafe {
+        SlothString::as_str(sloth_main(std::ptr::null(), 42.0))
+    });
 
     0
 }
+
+#[no_mangle]
+pub extern "C" fn sloth_division_by_zero() {
+    eprintln!("ArithmeticError: Division by zero");
+    std::process::exit(1);
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_empty_list_failure() {
+    eprintln!("ListError: Head or tail of an empty list");
+    std::process::exit(1);
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_match_failure() {
+    eprintln!("MatchError: No clause matched arguments of a function");
+    std::process::exit(1);
+}
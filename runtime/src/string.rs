@@ -0,0 +1,92 @@
+use std::alloc::{alloc, Layout};
+
+#[repr(C)]
+pub struct SlothString {
+    length: i64,
+    bytes: [u8; 0],
+}
+
+impl SlothString {
+    pub unsafe fn as_str<'a>(string: *const Self) -> &'a str {
+        std::str::from_utf8_unchecked(std::slice::from_raw_parts(
+            (*string).bytes.as_ptr(),
+            (*string).length as usize,
+        ))
+    }
+
+    fn allocate(string: &str) -> *const Self {
+        unsafe {
+            let pointer = alloc(
+                Layout::from_size_align(
+                    std::mem::size_of::<Self>() + string.len(),
+                    std::mem::align_of::<Self>(),
+                )
+                .unwrap(),
+            ) as *mut Self;
+
+            (*pointer).length = string.len() as i64;
+            std::ptr::copy_nonoverlapping(
+                string.as_ptr(),
+                (*pointer).bytes.as_mut_ptr(),
+                string.len(),
+            );
+
+            pointer
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_string_concatenate(
+    lhs: *const SlothString,
+    rhs: *const SlothString,
+) -> *const SlothString {
+    SlothString::allocate(&unsafe { [SlothString::as_str(lhs), SlothString::as_str(rhs)] }.concat())
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_string_equal(lhs: *const SlothString, rhs: *const SlothString) -> bool {
+    unsafe { SlothString::as_str(lhs) == SlothString::as_str(rhs) }
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_string_length(string: *const SlothString) -> i64 {
+    unsafe { SlothString::as_str(string) }.chars().count() as i64
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_string_slice(
+    string: *const SlothString,
+    start: i64,
+    end: i64,
+) -> *const SlothString {
+    let start = start.max(0) as usize;
+    let end = end.max(0) as usize;
+
+    SlothString::allocate(
+        &unsafe { SlothString::as_str(string) }
+            .chars()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect::<String>(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_char_to_string(character: u32) -> *const SlothString {
+    SlothString::allocate(
+        std::char::from_u32(character)
+            .unwrap_or(std::char::REPLACEMENT_CHARACTER)
+            .encode_utf8(&mut [0; 4]),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_int_to_string(number: i64) -> *const SlothString {
+    SlothString::allocate(&number.to_string())
+}
+
+#[no_mangle]
+pub extern "C" fn sloth_number_to_string(number: f64) -> *const SlothString {
+    SlothString::allocate(&number.to_string())
+}